@@ -1,4 +1,4 @@
-use cosmwasm_std::{attr, Attribute};
+use cosmwasm_std::{attr, Attribute, Event};
 
 /// Contstruct attributes vector with the given key and value
 /// Ensure that "action" attribute always exists
@@ -12,6 +12,12 @@ pub fn action_attrs<A: Into<Attribute>>(
     res
 }
 
+/// Construct the event of an action forced by chain governance through sudo,
+/// distinct from the `wasm` event of regular actions so that overrides stand out.
+pub fn sudo_event<A: Into<Attribute>>(action: &str, attrs: impl IntoIterator<Item = A>) -> Event {
+    Event::new("sudo_override").add_attributes(action_attrs(action, attrs))
+}
+
 #[cfg(test)]
 pub mod tests {
     use cosmwasm_std::{
@@ -31,6 +37,13 @@ pub mod tests {
         let msg = InstantiateMsg {
             governor: info.sender.to_string(),
             subdenom: subdenom.to_string(),
+            existing_denom: None,
+            member_manager: None,
+            custodian: None,
+            merchants: None,
+            min_burn_amount: None,
+            denom_metadata: None,
+            require_contract_holders: None,
         };
 
         let env = Env {
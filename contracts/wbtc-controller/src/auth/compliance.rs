@@ -0,0 +1,144 @@
+/// `compliance` module provides functionality to manage the compliance officer address.
+use cosmwasm_std::{attr, Addr, Deps, DepsMut, Env, MessageInfo, Response, StdError};
+
+use crate::{attrs::action_attrs, state::auth::COMPLIANCE_OFFICER, ContractError};
+
+use super::{has_no_priviledged_role, history, holder, require_any, Role};
+
+/// Set the compliance officer address.
+pub fn set_compliance_officer(
+    mut deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    address: &str,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::Governor], &info.sender, deps.as_ref(), env)?;
+    let address = deps.api.addr_validate(address)?;
+
+    has_no_priviledged_role(deps.as_ref(), env, &address)?;
+    holder::ensure_holder_kind(deps.as_ref(), Role::ComplianceOfficer, &address)?;
+    let previous = COMPLIANCE_OFFICER.may_load(deps.storage)?;
+    COMPLIANCE_OFFICER.save(deps.storage, &address)?;
+    history::record_role_handover(
+        deps.branch(),
+        env,
+        Role::ComplianceOfficer,
+        previous,
+        &address,
+        Some(&info.sender),
+    )?;
+
+    let attrs = action_attrs("set_compliance_officer", vec![attr("address", address)]);
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Check if the given address is the compliance officer.
+pub fn is_compliance_officer(deps: Deps, address: &Addr) -> Result<bool, StdError> {
+    match COMPLIANCE_OFFICER.may_load(deps.storage)? {
+        Some(compliance_officer) => Ok(compliance_officer == address),
+        None => Ok(false),
+    }
+}
+
+/// Get the compliance officer address.
+pub fn get_compliance_officer(deps: Deps) -> Result<Addr, StdError> {
+    may_get_compliance_officer(deps)?.ok_or_else(|| StdError::not_found("ComplianceOfficer"))
+}
+
+/// Get the compliance officer address if it has been set.
+pub fn may_get_compliance_officer(deps: Deps) -> Result<Option<Addr>, StdError> {
+    COMPLIANCE_OFFICER.may_load(deps.storage)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    use crate::auth::governor::initialize_governor;
+
+    use super::*;
+
+    #[test]
+    fn test_manage_compliance_officer() {
+        let mut deps = mock_dependencies();
+        let governor = "osmo1governor";
+        let non_governor = "osmo1nongovernor";
+        let compliance_officer_address = "osmo1complianceofficer";
+        let non_compliance_officer_address = "osmo1noncomplianceofficer";
+
+        // setup
+        initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
+
+        // check before set will fail
+        assert!(!is_compliance_officer(
+            deps.as_ref(),
+            &Addr::unchecked(compliance_officer_address)
+        )
+        .unwrap());
+
+        let err = get_compliance_officer(deps.as_ref()).unwrap_err();
+        assert_eq!(err, StdError::not_found("ComplianceOfficer"));
+
+        // set compliance officer by non governor should fail
+        let err = set_compliance_officer(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(non_governor, &[]),
+            compliance_officer_address,
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // governor can not be the compliance officer
+        let err = set_compliance_officer(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            governor,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::AlreadyHasPriviledgedRole {
+                address: governor.to_string()
+            }
+        );
+
+        // set compliance officer
+        assert_eq!(
+            set_compliance_officer(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(governor, &[]),
+                compliance_officer_address
+            )
+            .unwrap()
+            .attributes,
+            vec![
+                attr("action", "set_compliance_officer"),
+                attr("address", compliance_officer_address)
+            ]
+        );
+
+        // check after set will pass
+        assert_eq!(
+            get_compliance_officer(deps.as_ref()).unwrap(),
+            compliance_officer_address
+        );
+        assert!(
+            is_compliance_officer(deps.as_ref(), &Addr::unchecked(compliance_officer_address))
+                .unwrap()
+        );
+        assert!(!is_compliance_officer(
+            deps.as_ref(),
+            &Addr::unchecked(non_compliance_officer_address)
+        )
+        .unwrap());
+    }
+}
@@ -1,27 +1,183 @@
 /// `custodian` module provides functionality to manage the custodian address.
-use cosmwasm_std::{attr, Addr, Deps, DepsMut, MessageInfo, Response, StdError};
+use cosmwasm_std::{attr, ensure, Addr, Deps, DepsMut, Env, MessageInfo, Response, StdError};
 
-use crate::{attrs::action_attrs, state::auth::CUSTODIAN, ContractError};
+use crate::{
+    attrs::{action_attrs, sudo_event},
+    state::auth::{CUSTODIAN, CUSTODIAN_CANDIDATE},
+    ContractError,
+};
 
-use super::{allow_only, has_no_priviledged_role, Role};
+use super::{
+    has_no_priviledged_role, has_no_priviledged_role_other_than, history, holder, require_any, Role,
+};
 
-/// Set the custodian address.
+/// Set the custodian address if it is not set yet.
+/// Once set, the role can only be handed over with `propose_custodian` and `claim_custodian`.
 pub fn set_custodian(
-    deps: DepsMut,
+    mut deps: DepsMut,
+    env: &Env,
     info: &MessageInfo,
     address: &str,
 ) -> Result<Response, ContractError> {
-    allow_only(&[Role::MemberManager], &info.sender, deps.as_ref())?;
+    require_any(&[Role::MemberManager], &info.sender, deps.as_ref(), env)?;
 
     let address = deps.api.addr_validate(address)?;
-    has_no_priviledged_role(deps.as_ref(), &address)?;
+    has_no_priviledged_role(deps.as_ref(), env, &address)?;
+    holder::ensure_holder_kind(deps.as_ref(), Role::Custodian, &address)?;
+    ensure!(
+        may_get_custodian(deps.as_ref())?.is_none(),
+        ContractError::RoleAlreadySet {
+            role: "custodian".to_string()
+        }
+    );
 
     CUSTODIAN.save(deps.storage, &address)?;
+    history::record_role_handover(
+        deps.branch(),
+        env,
+        Role::Custodian,
+        None,
+        &address,
+        Some(&info.sender),
+    )?;
 
     let attrs = action_attrs("set_custodian", vec![attr("address", address)]);
     Ok(Response::new().add_attributes(attrs))
 }
 
+/// Initialize the custodian, can only be called at contract instantiation
+pub fn initialize_custodian(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    address: &str,
+) -> Result<(), ContractError> {
+    let address = deps.api.addr_validate(address)?;
+    has_no_priviledged_role(deps.as_ref(), env, &address)?;
+    holder::ensure_holder_kind(deps.as_ref(), Role::Custodian, &address)?;
+    CUSTODIAN.save(deps.storage, &address)?;
+    history::record_role_handover(
+        deps,
+        env,
+        Role::Custodian,
+        None,
+        &address,
+        Some(&info.sender),
+    )
+    .map_err(Into::into)
+}
+
+/// Propose a candidate to take over the custodian role, the candidate has to claim the role.
+/// Proposing a new candidate replaces the pending one. Only the member manager can propose a candidate.
+pub fn propose_custodian(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    address: &str,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::MemberManager], &info.sender, deps.as_ref(), env)?;
+
+    let address = deps.api.addr_validate(address)?;
+    has_no_priviledged_role(deps.as_ref(), env, &address)?;
+    holder::ensure_holder_kind(deps.as_ref(), Role::Custodian, &address)?;
+
+    CUSTODIAN_CANDIDATE.save(deps.storage, &address)?;
+
+    let attrs = action_attrs("propose_custodian", vec![attr("address", address)]);
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Claim the custodian role, only the custodian candidate can call this
+pub fn claim_custodian(
+    mut deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+) -> Result<Response, ContractError> {
+    let candidate =
+        get_custodian_candidate(deps.as_ref())?.ok_or(ContractError::Unauthorized {})?;
+
+    ensure!(info.sender == candidate, ContractError::Unauthorized {});
+    has_no_priviledged_role_other_than(
+        deps.as_ref(),
+        env,
+        &candidate,
+        &[Role::CustodianCandidate],
+    )?;
+    holder::ensure_holder_kind(deps.as_ref(), Role::Custodian, &candidate)?;
+
+    let previous = may_get_custodian(deps.as_ref())?;
+    CUSTODIAN.save(deps.storage, &candidate)?;
+    CUSTODIAN_CANDIDATE.remove(deps.storage);
+    history::record_role_handover(
+        deps.branch(),
+        env,
+        Role::Custodian,
+        previous,
+        &candidate,
+        Some(&info.sender),
+    )?;
+
+    let attrs = action_attrs("claim_custodian", vec![attr("address", candidate)]);
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Cancel the pending custodian candidate. Only the member manager can cancel the proposal.
+pub fn cancel_custodian_proposal(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::MemberManager], &info.sender, deps.as_ref(), env)?;
+
+    let candidate = get_custodian_candidate(deps.as_ref())?.ok_or_else(|| {
+        ContractError::NoPendingCandidate {
+            role: "custodian".to_string(),
+        }
+    })?;
+    CUSTODIAN_CANDIDATE.remove(deps.storage);
+
+    let attrs = action_attrs(
+        "cancel_custodian_proposal",
+        vec![attr("address", candidate)],
+    );
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Set the custodian without the member manager, discarding any pending candidate.
+/// Only reachable through sudo, so that chain governance can replace a compromised custodian.
+pub fn force_set_custodian(
+    mut deps: DepsMut,
+    env: &Env,
+    address: &str,
+) -> Result<Response, ContractError> {
+    let address = deps.api.addr_validate(address)?;
+    has_no_priviledged_role_other_than(
+        deps.as_ref(),
+        env,
+        &address,
+        &[Role::Custodian, Role::CustodianCandidate],
+    )?;
+    holder::ensure_holder_kind(deps.as_ref(), Role::Custodian, &address)?;
+
+    let previous = may_get_custodian(deps.as_ref())?;
+    CUSTODIAN.save(deps.storage, &address)?;
+    CUSTODIAN_CANDIDATE.remove(deps.storage);
+    history::record_role_handover(
+        deps.branch(),
+        env,
+        Role::Custodian,
+        previous.clone(),
+        &address,
+        None,
+    )?;
+
+    let mut attrs = vec![attr("address", address)];
+    if let Some(previous) = previous {
+        attrs.push(attr("previous", previous));
+    }
+    Ok(Response::new().add_event(sudo_event("force_set_custodian", attrs)))
+}
+
 /// Check if the given address is the custodian.
 pub fn is_custodian(deps: Deps, address: &Addr) -> Result<bool, StdError> {
     match CUSTODIAN.may_load(deps.storage)? {
@@ -32,14 +188,27 @@ pub fn is_custodian(deps: Deps, address: &Addr) -> Result<bool, StdError> {
 
 /// Get the custodian address.
 pub fn get_custodian(deps: Deps) -> Result<Addr, StdError> {
-    CUSTODIAN
-        .may_load(deps.storage)?
-        .ok_or_else(|| StdError::not_found("Custodian"))
+    may_get_custodian(deps)?.ok_or_else(|| StdError::not_found("Custodian"))
+}
+
+/// Get the custodian address if it has been set.
+pub fn may_get_custodian(deps: Deps) -> Result<Option<Addr>, StdError> {
+    CUSTODIAN.may_load(deps.storage)
+}
+
+/// Check if the given address is the custodian candidate.
+pub fn is_custodian_candidate(deps: Deps, address: &Addr) -> Result<bool, StdError> {
+    Ok(get_custodian_candidate(deps)?.as_ref() == Some(address))
+}
+
+/// Get the custodian candidate address.
+pub fn get_custodian_candidate(deps: Deps) -> Result<Option<Addr>, StdError> {
+    CUSTODIAN_CANDIDATE.may_load(deps.storage)
 }
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::testing::{mock_dependencies, mock_info};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
 
     use crate::auth::{governor::initialize_governor, member_manager};
 
@@ -55,10 +224,17 @@ mod tests {
         let non_custodian_address = "osmo1noncustodian";
 
         // setup
-        initialize_governor(deps.as_mut(), governor).unwrap();
+        initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
 
         member_manager::set_member_manager(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(governor, &[]),
             member_manager,
         )
@@ -73,6 +249,7 @@ mod tests {
         // set custodian by non governor should fail
         let err = set_custodian(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(non_member_manager, &[]),
             custodian_address,
         )
@@ -83,6 +260,7 @@ mod tests {
         assert_eq!(
             set_custodian(
                 deps.as_mut(),
+                &mock_env(),
                 &mock_info(member_manager, &[]),
                 custodian_address
             )
@@ -99,4 +277,141 @@ mod tests {
         assert!(is_custodian(deps.as_ref(), &Addr::unchecked(custodian_address)).unwrap());
         assert!(!is_custodian(deps.as_ref(), &Addr::unchecked(non_custodian_address)).unwrap());
     }
+
+    #[test]
+    fn test_custodian_handover() {
+        let mut deps = mock_dependencies();
+        let governor = "osmo1governor";
+        let member_manager = "osmo1membermanager";
+        let custodian = "osmo1custodian";
+        let new_custodian = "osmo1newcustodian";
+
+        // setup
+        initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
+        member_manager::set_member_manager(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            member_manager,
+        )
+        .unwrap();
+        set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+
+        // can only be set once
+        let err = set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            new_custodian,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::RoleAlreadySet {
+                role: "custodian".to_string()
+            }
+        );
+
+        // nothing to cancel or claim
+        let err =
+            cancel_custodian_proposal(deps.as_mut(), &mock_env(), &mock_info(member_manager, &[]))
+                .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::NoPendingCandidate {
+                role: "custodian".to_string()
+            }
+        );
+        let err = claim_custodian(deps.as_mut(), &mock_env(), &mock_info(new_custodian, &[]))
+            .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // only member manager can propose
+        let err = propose_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(custodian, &[]),
+            new_custodian,
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // propose and cancel
+        propose_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            new_custodian,
+        )
+        .unwrap();
+        cancel_custodian_proposal(deps.as_mut(), &mock_env(), &mock_info(member_manager, &[]))
+            .unwrap();
+        assert_eq!(get_custodian_candidate(deps.as_ref()).unwrap(), None);
+
+        // propose
+        assert_eq!(
+            propose_custodian(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(member_manager, &[]),
+                new_custodian
+            )
+            .unwrap()
+            .attributes,
+            vec![
+                attr("action", "propose_custodian"),
+                attr("address", new_custodian)
+            ]
+        );
+        assert!(is_custodian_candidate(deps.as_ref(), &Addr::unchecked(new_custodian)).unwrap());
+
+        // custodian remains unchanged until the candidate claims the role
+        assert_eq!(get_custodian(deps.as_ref()).unwrap(), custodian);
+
+        // candidate can not be assigned another role
+        let err = crate::auth::merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            new_custodian,
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::AlreadyHasPriviledgedRole {
+                address: new_custodian.to_string()
+            }
+        );
+
+        // only the candidate can claim
+        let err =
+            claim_custodian(deps.as_mut(), &mock_env(), &mock_info(custodian, &[])).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        assert_eq!(
+            claim_custodian(deps.as_mut(), &mock_env(), &mock_info(new_custodian, &[]))
+                .unwrap()
+                .attributes,
+            vec![
+                attr("action", "claim_custodian"),
+                attr("address", new_custodian)
+            ]
+        );
+        assert_eq!(get_custodian(deps.as_ref()).unwrap(), new_custodian);
+        assert_eq!(get_custodian_candidate(deps.as_ref()).unwrap(), None);
+        assert!(!is_custodian(deps.as_ref(), &Addr::unchecked(custodian)).unwrap());
+    }
 }
@@ -1,16 +1,32 @@
 use cosmwasm_schema::cw_serde;
 /// `governor` module provides governor management functionality.
-use cosmwasm_std::{attr, ensure, Addr, Deps, DepsMut, MessageInfo, Response, StdError};
+use cosmwasm_std::{
+    attr, ensure, Addr, Deps, DepsMut, Env, MessageInfo, Response, StdError, Timestamp,
+};
 
-use crate::{attrs::action_attrs, state::auth::GOVERNOR, ContractError};
+use crate::{
+    attrs::{action_attrs, sudo_event},
+    constants::GOVERNORSHIP_TRANSFER_EXPIRY_SECONDS,
+    state::auth::GOVERNOR,
+    ContractError,
+};
 
-use super::{allow_only, has_no_priviledged_role, Role};
+use super::{
+    has_no_priviledged_role, has_no_priviledged_role_other_than, history, holder, require_any, Role,
+};
 
 /// State of the governor to be stored in the contract storage
 #[cw_serde]
 pub enum GovernorState {
     Claimed(Addr),
-    Transferring { current: Addr, candidate: Addr },
+    Transferring {
+        current: Addr,
+        candidate: Addr,
+        /// Time after which the candidate can no longer claim the governorship.
+        /// Transfers stored before expiry was introduced default to the epoch, so they are expired.
+        #[serde(default)]
+        expires_at: Timestamp,
+    },
 }
 
 impl GovernorState {
@@ -18,10 +34,11 @@ impl GovernorState {
         Self::Claimed(address)
     }
 
-    fn transferring(current: Addr, target: Addr) -> Self {
+    fn transferring(current: Addr, target: Addr, expires_at: Timestamp) -> Self {
         Self::Transferring {
             current,
             candidate: target,
+            expires_at,
         }
     }
 
@@ -38,52 +55,176 @@ impl GovernorState {
             Self::Transferring { candidate, .. } => Some(candidate),
         }
     }
+
+    fn expires_at(self) -> Option<Timestamp> {
+        match self {
+            Self::Claimed(_) => None,
+            Self::Transferring { expires_at, .. } => Some(expires_at),
+        }
+    }
+
+    /// Candidate which can still claim the governorship at the given time.
+    fn unexpired_candidate(self, now: Timestamp) -> Option<Addr> {
+        match self {
+            Self::Transferring {
+                candidate,
+                expires_at,
+                ..
+            } if now < expires_at => Some(candidate),
+            _ => None,
+        }
+    }
 }
 
 /// Initialize the governor, can only be called once at contract instantiation
-pub fn initialize_governor(deps: DepsMut, address: &str) -> Result<(), ContractError> {
+pub fn initialize_governor(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    address: &str,
+) -> Result<(), ContractError> {
     let address = deps.api.addr_validate(address)?;
-    has_no_priviledged_role(deps.as_ref(), &address)?;
-    GOVERNOR
-        .save(deps.storage, &GovernorState::claimed(address))
-        .map_err(Into::into)
+    has_no_priviledged_role(deps.as_ref(), env, &address)?;
+    holder::ensure_holder_kind(deps.as_ref(), Role::Governor, &address)?;
+    GOVERNOR.save(deps.storage, &GovernorState::claimed(address.clone()))?;
+    history::record_role_handover(
+        deps,
+        env,
+        Role::Governor,
+        None,
+        &address,
+        Some(&info.sender),
+    )
+    .map_err(Into::into)
 }
 
-/// Transfer the governorship to another address, only the governor can call this
+/// Transfer the governorship to another address, only the governor can call this.
+/// The candidate has to claim the governorship before the transfer expires.
 pub fn transfer_governorship(
     deps: DepsMut,
+    env: &Env,
     info: &MessageInfo,
     address: &str,
 ) -> Result<Response, ContractError> {
-    allow_only(&[Role::Governor], &info.sender, deps.as_ref())?;
+    require_any(&[Role::Governor], &info.sender, deps.as_ref(), env)?;
 
     let validated_address = deps.api.addr_validate(address)?;
-    has_no_priviledged_role(deps.as_ref(), &validated_address)?;
+    has_no_priviledged_role(deps.as_ref(), env, &validated_address)?;
+    holder::ensure_holder_kind(deps.as_ref(), Role::Governor, &validated_address)?;
 
     let current = get_governor(deps.as_ref())?;
+    let expires_at = env
+        .block
+        .time
+        .plus_seconds(GOVERNORSHIP_TRANSFER_EXPIRY_SECONDS);
     GOVERNOR.save(
         deps.storage,
-        &GovernorState::transferring(current, validated_address),
+        &GovernorState::transferring(current, validated_address, expires_at),
     )?;
 
-    let attrs = action_attrs("transfer_governorship", vec![attr("address", address)]);
+    let attrs = action_attrs(
+        "transfer_governorship",
+        vec![
+            attr("address", address),
+            attr("expires_at", expires_at.to_string()),
+        ],
+    );
     Ok(Response::new().add_attributes(attrs))
 }
 
-/// Claim the governorship, only the target governor can call this
-pub fn claim_governorship(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+/// Cancel the pending governorship transfer, only the governor can call this
+pub fn cancel_governorship_transfer(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::Governor], &info.sender, deps.as_ref(), env)?;
+
+    // an expired transfer can be cancelled as well
+    let candidate = GOVERNOR.load(deps.storage)?.candidate().ok_or_else(|| {
+        ContractError::NoPendingCandidate {
+            role: "governor".to_string(),
+        }
+    })?;
+
+    GOVERNOR.save(deps.storage, &GovernorState::claimed(info.sender.clone()))?;
+
+    let attrs = action_attrs(
+        "cancel_governorship_transfer",
+        vec![attr("address", candidate)],
+    );
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Claim the governorship before the transfer expires, only the target governor can call this
+pub fn claim_governorship(
+    deps: DepsMut,
+    env: &Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
     let governor = GOVERNOR.load(deps.storage)?;
+    let expires_at = governor.clone().expires_at();
+    let previous = governor.clone().current();
     let candidate = governor.candidate().ok_or(ContractError::Unauthorized {})?;
 
     ensure!(info.sender == candidate, ContractError::Unauthorized {});
-    has_no_priviledged_role(deps.as_ref(), &candidate)?;
-
-    GOVERNOR.save(deps.storage, &GovernorState::claimed(info.sender))?;
+    if let Some(expires_at) = expires_at {
+        ensure!(
+            env.block.time < expires_at,
+            ContractError::GovernorshipTransferExpired { expires_at }
+        );
+    }
+    has_no_priviledged_role_other_than(deps.as_ref(), env, &candidate, &[Role::GovernorCandidate])?;
+    holder::ensure_holder_kind(deps.as_ref(), Role::Governor, &candidate)?;
+
+    GOVERNOR.save(deps.storage, &GovernorState::claimed(info.sender.clone()))?;
+    history::record_role_handover(
+        deps,
+        env,
+        Role::Governor,
+        Some(previous),
+        &candidate,
+        Some(&info.sender),
+    )?;
 
     let attrs = action_attrs("claim_governorship", vec![attr("address", candidate)]);
     Ok(Response::new().add_attributes(attrs))
 }
 
+/// Set the governor without the current governor's consent, discarding any pending transfer.
+/// Only reachable through sudo, so that chain governance can recover a lost governor key.
+pub fn force_set_governor(
+    mut deps: DepsMut,
+    env: &Env,
+    address: &str,
+) -> Result<Response, ContractError> {
+    let address = deps.api.addr_validate(address)?;
+    has_no_priviledged_role_other_than(
+        deps.as_ref(),
+        env,
+        &address,
+        &[Role::Governor, Role::GovernorCandidate],
+    )?;
+    holder::ensure_holder_kind(deps.as_ref(), Role::Governor, &address)?;
+
+    let previous = get_governor(deps.as_ref())?;
+    GOVERNOR.save(deps.storage, &GovernorState::claimed(address.clone()))?;
+    history::record_role_handover(
+        deps.branch(),
+        env,
+        Role::Governor,
+        Some(previous.clone()),
+        &address,
+        None,
+    )?;
+
+    let event = sudo_event(
+        "force_set_governor",
+        vec![attr("address", address), attr("previous", previous)],
+    );
+    Ok(Response::new().add_event(event))
+}
+
 /// Check if the given address is the governor
 pub fn is_governor(deps: Deps, address: &Addr) -> Result<bool, StdError> {
     Ok(GOVERNOR
@@ -92,13 +233,9 @@ pub fn is_governor(deps: Deps, address: &Addr) -> Result<bool, StdError> {
         .unwrap_or(false))
 }
 
-/// Check if the given address is the governor candidate
-pub fn is_governor_candidate(deps: Deps, address: &Addr) -> Result<bool, StdError> {
-    let candidate = GOVERNOR
-        .may_load(deps.storage)?
-        .and_then(|governor| governor.candidate());
-
-    Ok(candidate.as_ref() == Some(address))
+/// Check if the given address is the governor candidate, an expired transfer has no candidate
+pub fn is_governor_candidate(deps: Deps, env: &Env, address: &Addr) -> Result<bool, StdError> {
+    Ok(get_governor_candidate(deps, env)?.as_ref() == Some(address))
 }
 
 /// Get the governor address
@@ -109,18 +246,28 @@ pub fn get_governor(deps: Deps) -> Result<Addr, StdError> {
         .ok_or(StdError::not_found("Governor"))
 }
 
-// Get the governor candidate address
-pub fn get_governor_candidate(deps: Deps) -> Result<Option<Addr>, StdError> {
+// Get the governor candidate address, `None` if the transfer has expired
+pub fn get_governor_candidate(deps: Deps, env: &Env) -> Result<Option<Addr>, StdError> {
     Ok(GOVERNOR
         .may_load(deps.storage)?
-        .and_then(|governor| governor.candidate()))
+        .and_then(|governor| governor.unexpired_candidate(env.block.time)))
+}
+
+/// Get the time after which the governor candidate can no longer claim the governorship
+pub fn get_governorship_transfer_expires_at(deps: Deps) -> Result<Option<Timestamp>, StdError> {
+    Ok(GOVERNOR
+        .may_load(deps.storage)?
+        .and_then(|governor| governor.expires_at()))
 }
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::testing::{mock_dependencies, mock_info};
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Storage,
+    };
 
-    use crate::auth::member_manager;
+    use crate::auth::{has_role, member_manager, roles_of};
 
     use super::*;
 
@@ -139,10 +286,17 @@ mod tests {
         assert_eq!(err, StdError::not_found("Governor"));
 
         // initialize governor
-        initialize_governor(deps.as_mut(), governor_address).unwrap();
+        initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor_address,
+        )
+        .unwrap();
 
         member_manager::set_member_manager(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(governor_address, &[]),
             member_manager_address,
         )
@@ -156,6 +310,7 @@ mod tests {
         // transfer governor right should fail if not called by governor
         let err = transfer_governorship(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(new_governor_address, &[]),
             non_governor_address,
         )
@@ -163,13 +318,17 @@ mod tests {
         assert_eq!(err, ContractError::Unauthorized {});
 
         assert_eq!(get_governor(deps.as_ref()).unwrap(), governor_address);
-        assert_eq!(get_governor_candidate(deps.as_ref()).unwrap(), None);
+        assert_eq!(
+            get_governor_candidate(deps.as_ref(), &mock_env()).unwrap(),
+            None
+        );
         assert!(is_governor(deps.as_ref(), &Addr::unchecked(governor_address)).unwrap(),);
         assert!(!is_governor(deps.as_ref(), &Addr::unchecked(non_governor_address)).unwrap(),);
 
         // transfer governor right should fail if called by governor but the candidate address is a priviledged address
         let err = transfer_governorship(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(governor_address, &[]),
             member_manager_address,
         )
@@ -185,6 +344,7 @@ mod tests {
         assert_eq!(
             transfer_governorship(
                 deps.as_mut(),
+                &mock_env(),
                 &mock_info(governor_address, &[]),
                 new_governor_address,
             )
@@ -192,43 +352,62 @@ mod tests {
             .attributes,
             vec![
                 attr("action", "transfer_governorship"),
-                attr("address", new_governor_address)
+                attr("address", new_governor_address),
+                attr(
+                    "expires_at",
+                    mock_env()
+                        .block
+                        .time
+                        .plus_seconds(GOVERNORSHIP_TRANSFER_EXPIRY_SECONDS)
+                        .to_string()
+                )
             ]
         );
 
         // governor remain unchanged until the new governor claims the governorship
         assert_eq!(get_governor(deps.as_ref()).unwrap(), governor_address);
         assert_eq!(
-            get_governor_candidate(deps.as_ref()).unwrap(),
+            get_governor_candidate(deps.as_ref(), &mock_env()).unwrap(),
             Some(new_governor_address).map(Addr::unchecked)
         );
 
         assert!(is_governor(deps.as_ref(), &Addr::unchecked(governor_address)).unwrap());
-        assert!(!is_governor_candidate(deps.as_ref(), &Addr::unchecked(governor_address)).unwrap());
+        assert!(!is_governor_candidate(
+            deps.as_ref(),
+            &mock_env(),
+            &Addr::unchecked(governor_address)
+        )
+        .unwrap());
 
         assert!(!is_governor(deps.as_ref(), &Addr::unchecked(new_governor_address)).unwrap());
-        assert!(
-            is_governor_candidate(deps.as_ref(), &Addr::unchecked(new_governor_address)).unwrap()
-        );
+        assert!(is_governor_candidate(
+            deps.as_ref(),
+            &mock_env(),
+            &Addr::unchecked(new_governor_address)
+        )
+        .unwrap());
 
         // claim governorship should fail if not called by governor candidate
-        let err =
-            claim_governorship(deps.as_mut(), mock_info(non_governor_address, &[])).unwrap_err();
+        let err = claim_governorship(
+            deps.as_mut(),
+            &mock_env(),
+            mock_info(non_governor_address, &[]),
+        )
+        .unwrap_err();
         assert_eq!(err, ContractError::Unauthorized {});
 
-        let err = claim_governorship(deps.as_mut(), mock_info(governor_address, &[])).unwrap_err();
+        let err = claim_governorship(deps.as_mut(), &mock_env(), mock_info(governor_address, &[]))
+            .unwrap_err();
         assert_eq!(err, ContractError::Unauthorized {});
 
-        // claim governorship should fail if called by governor candidate but the candidate address is a priviledged address
-        member_manager::set_member_manager(
+        // governor candidate can not be assigned another priviledged role
+        let err = member_manager::propose_member_manager(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(governor_address, &[]),
             new_governor_address,
         )
-        .unwrap();
-
-        let err =
-            claim_governorship(deps.as_mut(), mock_info(new_governor_address, &[])).unwrap_err();
+        .unwrap_err();
         assert_eq!(
             err,
             ContractError::AlreadyHasPriviledgedRole {
@@ -236,19 +415,15 @@ mod tests {
             }
         );
 
-        // remove member manager role from the candidate address
-        member_manager::set_member_manager(
-            deps.as_mut(),
-            &mock_info(governor_address, &[]),
-            member_manager_address,
-        )
-        .unwrap();
-
         // claim governorship should pass if called by governor candidate
         assert_eq!(
-            claim_governorship(deps.as_mut(), mock_info(new_governor_address, &[]))
-                .unwrap()
-                .attributes,
+            claim_governorship(
+                deps.as_mut(),
+                &mock_env(),
+                mock_info(new_governor_address, &[])
+            )
+            .unwrap()
+            .attributes,
             vec![
                 attr("action", "claim_governorship"),
                 attr("address", new_governor_address)
@@ -256,9 +431,168 @@ mod tests {
         );
 
         assert_eq!(get_governor(deps.as_ref()).unwrap(), new_governor_address);
-        assert_eq!(get_governor_candidate(deps.as_ref()).unwrap(), None);
+        assert_eq!(
+            get_governor_candidate(deps.as_ref(), &mock_env()).unwrap(),
+            None
+        );
         assert!(!is_governor(deps.as_ref(), &Addr::unchecked(governor_address)).unwrap(),);
         assert!(!is_governor(deps.as_ref(), &Addr::unchecked(non_governor_address)).unwrap(),);
         assert!(is_governor(deps.as_ref(), &Addr::unchecked(new_governor_address)).unwrap(),);
     }
+
+    #[test]
+    fn test_cancel_and_expire_governorship_transfer() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let governor_address = "osmo1governor";
+        let new_governor_address = "osmo1newgovernor";
+
+        initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor_address,
+        )
+        .unwrap();
+
+        // nothing to cancel
+        let err = cancel_governorship_transfer(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor_address, &[]),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::NoPendingCandidate {
+                role: "governor".to_string()
+            }
+        );
+
+        // only governor can cancel
+        transfer_governorship(
+            deps.as_mut(),
+            &env,
+            &mock_info(governor_address, &[]),
+            new_governor_address,
+        )
+        .unwrap();
+        let err = cancel_governorship_transfer(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(new_governor_address, &[]),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        assert_eq!(
+            cancel_governorship_transfer(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(governor_address, &[])
+            )
+            .unwrap()
+            .attributes,
+            vec![
+                attr("action", "cancel_governorship_transfer"),
+                attr("address", new_governor_address)
+            ]
+        );
+        assert_eq!(get_governor(deps.as_ref()).unwrap(), governor_address);
+        assert_eq!(get_governor_candidate(deps.as_ref(), &env).unwrap(), None);
+        assert_eq!(
+            get_governorship_transfer_expires_at(deps.as_ref()).unwrap(),
+            None
+        );
+
+        let err = claim_governorship(deps.as_mut(), &env, mock_info(new_governor_address, &[]))
+            .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // transfer expires
+        transfer_governorship(
+            deps.as_mut(),
+            &env,
+            &mock_info(governor_address, &[]),
+            new_governor_address,
+        )
+        .unwrap();
+        let expires_at = env
+            .block
+            .time
+            .plus_seconds(GOVERNORSHIP_TRANSFER_EXPIRY_SECONDS);
+        assert_eq!(
+            get_governorship_transfer_expires_at(deps.as_ref()).unwrap(),
+            Some(expires_at)
+        );
+
+        env.block.time = expires_at;
+        let err = claim_governorship(deps.as_mut(), &env, mock_info(new_governor_address, &[]))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::GovernorshipTransferExpired { expires_at }
+        );
+        assert_eq!(get_governor(deps.as_ref()).unwrap(), governor_address);
+
+        // expired candidate is no longer a candidate and can be assigned another role
+        let candidate = Addr::unchecked(new_governor_address);
+        assert_eq!(get_governor_candidate(deps.as_ref(), &env).unwrap(), None);
+        assert!(!is_governor_candidate(deps.as_ref(), &env, &candidate).unwrap());
+        assert!(!has_role(deps.as_ref(), &env, Role::GovernorCandidate, &candidate).unwrap());
+        assert_eq!(roles_of(deps.as_ref(), &env, &candidate).unwrap(), vec![]);
+        has_no_priviledged_role(deps.as_ref(), &env, &candidate).unwrap();
+
+        // claimable right before expiry
+        env.block.time = expires_at.minus_seconds(1);
+        assert_eq!(
+            get_governor_candidate(deps.as_ref(), &env).unwrap(),
+            Some(candidate.clone())
+        );
+        assert_eq!(
+            has_no_priviledged_role(deps.as_ref(), &env, &candidate).unwrap_err(),
+            ContractError::AlreadyHasPriviledgedRole {
+                address: new_governor_address.to_string()
+            }
+        );
+        claim_governorship(deps.as_mut(), &env, mock_info(new_governor_address, &[])).unwrap();
+        assert_eq!(get_governor(deps.as_ref()).unwrap(), new_governor_address);
+    }
+
+    #[test]
+    fn test_load_transferring_state_without_expiry() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let governor_address = "osmo1governor";
+        let candidate_address = "osmo1candidate";
+
+        // state stored before the transfer expiry was introduced
+        deps.storage.set(
+            GOVERNOR.as_slice(),
+            format!(
+                r#"{{"transferring":{{"current":"{}","candidate":"{}"}}}}"#,
+                governor_address, candidate_address
+            )
+            .as_bytes(),
+        );
+
+        let candidate = Addr::unchecked(candidate_address);
+        assert_eq!(get_governor(deps.as_ref()).unwrap(), governor_address);
+        assert_eq!(
+            get_governorship_transfer_expires_at(deps.as_ref()).unwrap(),
+            Some(Timestamp::from_seconds(0))
+        );
+        assert!(!has_role(deps.as_ref(), &env, Role::GovernorCandidate, &candidate).unwrap());
+        assert_eq!(
+            claim_governorship(deps.as_mut(), &env, mock_info(candidate_address, &[])).unwrap_err(),
+            ContractError::GovernorshipTransferExpired {
+                expires_at: Timestamp::from_seconds(0)
+            }
+        );
+
+        // the stale transfer can still be cancelled
+        cancel_governorship_transfer(deps.as_mut(), &env, &mock_info(governor_address, &[]))
+            .unwrap();
+        assert_eq!(get_governor(deps.as_ref()).unwrap(), governor_address);
+    }
 }
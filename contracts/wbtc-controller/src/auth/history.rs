@@ -0,0 +1,583 @@
+/// `history` module keeps an append-only history of role assignments and permission grants,
+/// so that past role holders can be looked up from contract state rather than from events.
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, Order, StdResult, Timestamp, Uint128};
+use cw_storage_plus::{Bound, Index, IndexList, MultiIndex};
+
+use crate::{
+    constants::{DEFAULT_LIMIT, MAX_LIMIT},
+    state::auth::{role_history, ROLE_HISTORY_NONCE},
+};
+
+use super::{permission::Permission, Role};
+
+#[cw_serde]
+#[derive(Copy)]
+pub enum RoleChange {
+    Granted,
+    Revoked,
+}
+
+#[cw_serde]
+pub struct RoleHistoryEntry {
+    pub role: Role,
+    pub address: Addr,
+    pub change: RoleChange,
+    /// Address which caused the change, `None` if forced by chain governance through sudo.
+    pub actor: Option<Addr>,
+    pub timestamp: Timestamp,
+    /// Permission explicitly granted or revoked, in which case `role` is its default role.
+    /// `None` if the role itself changed.
+    pub permission: Option<Permission>,
+}
+
+#[cw_serde]
+pub struct RoleHistoryEntryWithId {
+    pub id: Uint128,
+    pub entry: RoleHistoryEntry,
+}
+
+/// `RoleHistoryIndexes` contains indexes for `RoleHistoryEntry` storage.
+pub struct RoleHistoryIndexes<'a> {
+    /// `role` index is for listing the history of a role
+    pub role: MultiIndex<'a, String, RoleHistoryEntry, u128>,
+
+    /// `address` index is for listing the history of an address
+    pub address: MultiIndex<'a, Addr, RoleHistoryEntry, u128>,
+}
+
+/// Boilerplate code for setting `RoleHistoryIndexes` as compatible `IndexList` for `IndexedMap`
+impl<'a> IndexList<RoleHistoryEntry> for RoleHistoryIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<RoleHistoryEntry>> + '_> {
+        let v: Vec<&dyn Index<RoleHistoryEntry>> = vec![&self.role, &self.address];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Key of the role in the `role` index.
+pub fn role_index_key(role: Role) -> String {
+    format!("{role:?}")
+}
+
+/// Key of the entry in the `role` index, permission changes are kept apart from the changes of their default role.
+pub fn entry_role_index_key(entry: &RoleHistoryEntry) -> String {
+    match entry.permission {
+        Some(permission) => format!("{}:{}", role_index_key(entry.role), permission.key()),
+        None => role_index_key(entry.role),
+    }
+}
+
+/// Append a role change to the history.
+pub fn record_role_change(
+    deps: DepsMut,
+    env: &Env,
+    role: Role,
+    address: &Addr,
+    change: RoleChange,
+    actor: Option<&Addr>,
+) -> StdResult<()> {
+    save_entry(
+        deps,
+        RoleHistoryEntry {
+            role,
+            address: address.clone(),
+            change,
+            actor: actor.cloned(),
+            timestamp: env.block.time,
+            permission: None,
+        },
+    )
+}
+
+/// Append an explicit grant or revocation of a permission to the history.
+pub fn record_permission_change(
+    deps: DepsMut,
+    env: &Env,
+    permission: Permission,
+    address: &Addr,
+    change: RoleChange,
+    actor: &Addr,
+) -> StdResult<()> {
+    save_entry(
+        deps,
+        RoleHistoryEntry {
+            role: permission.default_role(),
+            address: address.clone(),
+            change,
+            actor: Some(actor.clone()),
+            timestamp: env.block.time,
+            permission: Some(permission),
+        },
+    )
+}
+
+fn save_entry(mut deps: DepsMut, entry: RoleHistoryEntry) -> StdResult<()> {
+    let id = ROLE_HISTORY_NONCE.get_then_increase(deps.branch())?;
+    role_history().save(deps.storage, id.u128(), &entry)
+}
+
+/// Append the handover of a role held by a single address to the history,
+/// revoking it from the previous holder if any. Nothing is recorded if the holder is unchanged.
+pub fn record_role_handover(
+    mut deps: DepsMut,
+    env: &Env,
+    role: Role,
+    previous: Option<Addr>,
+    address: &Addr,
+    actor: Option<&Addr>,
+) -> StdResult<()> {
+    if previous.as_ref() == Some(address) {
+        return Ok(());
+    }
+
+    if let Some(previous) = previous {
+        record_role_change(
+            deps.branch(),
+            env,
+            role,
+            &previous,
+            RoleChange::Revoked,
+            actor,
+        )?;
+    }
+
+    record_role_change(deps, env, role, address, RoleChange::Granted, actor)
+}
+
+/// List the role history in chronological order, optionally only the changes of a role and/or an address.
+/// Permission changes are only listed when not filtering by role.
+pub fn list_role_history(
+    deps: Deps,
+    role: Option<Role>,
+    address: Option<String>,
+    start_after: Option<Uint128>,
+    limit: Option<u32>,
+) -> StdResult<Vec<RoleHistoryEntryWithId>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(|id| Bound::exclusive(id.u128()));
+    let address = address
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+
+    let history = role_history();
+    let entries = match (role, address) {
+        (_, Some(address)) => {
+            history
+                .idx
+                .address
+                .prefix(address)
+                .range(deps.storage, start, None, Order::Ascending)
+        }
+        (Some(role), None) => history.idx.role.prefix(role_index_key(role)).range(
+            deps.storage,
+            start,
+            None,
+            Order::Ascending,
+        ),
+        (None, None) => history.range(deps.storage, start, None, Order::Ascending),
+    };
+
+    entries
+        .filter(|item| match (item, role) {
+            (Ok((_, entry)), Some(role)) => entry.role == role && entry.permission.is_none(),
+            _ => true,
+        })
+        .take(limit)
+        .map(|item| {
+            let (id, entry) = item?;
+            Ok(RoleHistoryEntryWithId {
+                id: Uint128::new(id),
+                entry,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    use crate::auth::{custodian, governor, member_manager, merchant, operator, permission};
+
+    use super::*;
+
+    #[test]
+    fn test_role_history() {
+        let mut deps = mock_dependencies();
+        let governor = "osmo1governor";
+        let member_manager = "osmo1membermanager";
+        let custodian = "osmo1custodian";
+        let new_custodian = "osmo1newcustodian";
+        let merchant = "osmo1merchant";
+
+        let mut env = mock_env();
+        let t0 = env.block.time;
+        let t1 = t0.plus_seconds(100);
+        let t2 = t0.plus_seconds(200);
+
+        governor::initialize_governor(
+            deps.as_mut(),
+            &env,
+            &mock_info("osmo1creator", &[]),
+            governor,
+        )
+        .unwrap();
+        member_manager::set_member_manager(
+            deps.as_mut(),
+            &env,
+            &mock_info(governor, &[]),
+            member_manager,
+        )
+        .unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &env,
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &env,
+            &mock_info(member_manager, &[]),
+            merchant,
+            None,
+        )
+        .unwrap();
+
+        // hand over the custodian role
+        env.block.time = t1;
+        custodian::propose_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            new_custodian,
+        )
+        .unwrap();
+        custodian::claim_custodian(deps.as_mut(), &env, &mock_info(new_custodian, &[])).unwrap();
+
+        // offboard the merchant and recover the governor through chain governance
+        env.block.time = t2;
+        merchant::remove_merchant(
+            deps.as_mut(),
+            &env,
+            &mock_info(member_manager, &[]),
+            merchant,
+        )
+        .unwrap();
+        governor::force_set_governor(deps.as_mut(), &env, "osmo1newgovernor").unwrap();
+
+        let entry =
+            |role, address: &str, change, actor: Option<&str>, timestamp| RoleHistoryEntry {
+                role,
+                address: Addr::unchecked(address),
+                change,
+                actor: actor.map(Addr::unchecked),
+                timestamp,
+                permission: None,
+            };
+        let with_ids = |entries: Vec<(u128, RoleHistoryEntry)>| {
+            entries
+                .into_iter()
+                .map(|(id, entry)| RoleHistoryEntryWithId {
+                    id: Uint128::new(id),
+                    entry,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let custodian_history = with_ids(vec![
+            (
+                2,
+                entry(
+                    Role::Custodian,
+                    custodian,
+                    RoleChange::Granted,
+                    Some(member_manager),
+                    t0,
+                ),
+            ),
+            (
+                4,
+                entry(
+                    Role::Custodian,
+                    custodian,
+                    RoleChange::Revoked,
+                    Some(new_custodian),
+                    t1,
+                ),
+            ),
+            (
+                5,
+                entry(
+                    Role::Custodian,
+                    new_custodian,
+                    RoleChange::Granted,
+                    Some(new_custodian),
+                    t1,
+                ),
+            ),
+        ]);
+
+        // by role
+        assert_eq!(
+            list_role_history(deps.as_ref(), Some(Role::Custodian), None, None, None).unwrap(),
+            custodian_history
+        );
+
+        // by role with pagination
+        assert_eq!(
+            list_role_history(
+                deps.as_ref(),
+                Some(Role::Custodian),
+                None,
+                Some(Uint128::new(2)),
+                Some(1)
+            )
+            .unwrap(),
+            custodian_history[1..2].to_vec()
+        );
+
+        // by address
+        assert_eq!(
+            list_role_history(deps.as_ref(), None, Some(merchant.to_string()), None, None).unwrap(),
+            with_ids(vec![
+                (
+                    3,
+                    entry(
+                        Role::Merchant,
+                        merchant,
+                        RoleChange::Granted,
+                        Some(member_manager),
+                        t0
+                    )
+                ),
+                (
+                    6,
+                    entry(
+                        Role::Merchant,
+                        merchant,
+                        RoleChange::Revoked,
+                        Some(member_manager),
+                        t2
+                    )
+                ),
+            ])
+        );
+
+        // by role and address, changes forced by chain governance have no actor
+        assert_eq!(
+            list_role_history(
+                deps.as_ref(),
+                Some(Role::Governor),
+                Some(governor.to_string()),
+                None,
+                None
+            )
+            .unwrap(),
+            with_ids(vec![
+                (
+                    0,
+                    entry(
+                        Role::Governor,
+                        governor,
+                        RoleChange::Granted,
+                        Some("osmo1creator"),
+                        t0
+                    )
+                ),
+                (
+                    7,
+                    entry(Role::Governor, governor, RoleChange::Revoked, None, t2)
+                ),
+            ])
+        );
+        assert_eq!(
+            list_role_history(
+                deps.as_ref(),
+                Some(Role::Custodian),
+                Some(governor.to_string()),
+                None,
+                None
+            )
+            .unwrap(),
+            vec![]
+        );
+
+        // all changes
+        let all = list_role_history(deps.as_ref(), None, None, None, None).unwrap();
+        assert_eq!(
+            all.iter().map(|item| item.id.u128()).collect::<Vec<_>>(),
+            (0..9).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            all[8].entry,
+            entry(
+                Role::Governor,
+                "osmo1newgovernor",
+                RoleChange::Granted,
+                None,
+                t2
+            )
+        );
+    }
+
+    #[test]
+    fn test_operator_and_permission_history() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let governor = "osmo1governor";
+        let member_manager = "osmo1membermanager";
+        let merchant = "osmo1merchant";
+        let approver = "osmo1approver";
+
+        governor::initialize_governor(
+            deps.as_mut(),
+            &env,
+            &mock_info("osmo1creator", &[]),
+            governor,
+        )
+        .unwrap();
+        member_manager::set_member_manager(
+            deps.as_mut(),
+            &env,
+            &mock_info(governor, &[]),
+            member_manager,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &env,
+            &mock_info(member_manager, &[]),
+            merchant,
+            None,
+        )
+        .unwrap();
+
+        // updating an operator grant is not recorded
+        for _ in 0..2 {
+            for operator in ["osmo1operator1", "osmo1operator2"] {
+                operator::set_operator(
+                    deps.as_mut(),
+                    &env,
+                    &mock_info(merchant, &[]),
+                    operator,
+                    vec![operator::OperatorPermission::Burn],
+                    None,
+                )
+                .unwrap();
+            }
+        }
+        operator::remove_operator(
+            deps.as_mut(),
+            &env,
+            &mock_info(merchant, &[]),
+            "osmo1operator1",
+        )
+        .unwrap();
+
+        // removing the merchant revokes its remaining operators
+        merchant::remove_merchant(
+            deps.as_mut(),
+            &env,
+            &mock_info(member_manager, &[]),
+            merchant,
+        )
+        .unwrap();
+
+        // granting twice or revoking what is not granted is not recorded
+        for _ in 0..2 {
+            permission::grant_permission(
+                deps.as_mut(),
+                &env,
+                &mock_info(governor, &[]),
+                permission::Permission::MintApprove,
+                approver,
+            )
+            .unwrap();
+        }
+        for _ in 0..2 {
+            permission::revoke_permission(
+                deps.as_mut(),
+                &env,
+                &mock_info(governor, &[]),
+                permission::Permission::MintApprove,
+                approver,
+            )
+            .unwrap();
+        }
+
+        let entry = |role, address: &str, change, actor: &str, permission| RoleHistoryEntry {
+            role,
+            address: Addr::unchecked(address),
+            change,
+            actor: Some(Addr::unchecked(actor)),
+            timestamp: env.block.time,
+            permission,
+        };
+        let entries = |role, address: Option<&str>| {
+            list_role_history(deps.as_ref(), role, address.map(String::from), None, None)
+                .unwrap()
+                .into_iter()
+                .map(|item| item.entry)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            entries(Some(Role::MerchantOperator), None),
+            vec![
+                entry(
+                    Role::MerchantOperator,
+                    "osmo1operator1",
+                    RoleChange::Granted,
+                    merchant,
+                    None
+                ),
+                entry(
+                    Role::MerchantOperator,
+                    "osmo1operator2",
+                    RoleChange::Granted,
+                    merchant,
+                    None
+                ),
+                entry(
+                    Role::MerchantOperator,
+                    "osmo1operator1",
+                    RoleChange::Revoked,
+                    merchant,
+                    None
+                ),
+                entry(
+                    Role::MerchantOperator,
+                    "osmo1operator2",
+                    RoleChange::Revoked,
+                    member_manager,
+                    None
+                ),
+            ]
+        );
+
+        // permission changes are listed by address, but not as changes of their default role
+        assert_eq!(
+            entries(None, Some(approver)),
+            vec![
+                entry(
+                    Role::Custodian,
+                    approver,
+                    RoleChange::Granted,
+                    governor,
+                    Some(permission::Permission::MintApprove)
+                ),
+                entry(
+                    Role::Custodian,
+                    approver,
+                    RoleChange::Revoked,
+                    governor,
+                    Some(permission::Permission::MintApprove)
+                ),
+            ]
+        );
+        assert_eq!(entries(Some(Role::Custodian), None), vec![]);
+        assert_eq!(entries(Some(Role::Custodian), Some(approver)), vec![]);
+    }
+}
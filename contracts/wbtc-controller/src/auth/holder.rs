@@ -0,0 +1,524 @@
+/// `holder` module provides functionality to declare whether a role must be held by an account
+/// or by a contract, e.g. a cw3 multisig or a DAO, and to inspect contract role holders.
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    attr, ensure, to_vec, Addr, ContractResult, Deps, DepsMut, Empty, Env, MessageInfo,
+    QueryRequest, Response, StdError, StdResult, SystemError, SystemResult, WasmQuery,
+};
+
+use crate::{
+    attrs::action_attrs,
+    state::auth::{CONTRACT_HOLDERS_REQUIRED, ROLE_HOLDER_KINDS},
+    ContractError,
+};
+
+use super::{compliance, custodian, governor, member_manager, require_any, Role};
+
+#[cw_serde]
+#[derive(Copy)]
+pub enum HolderKind {
+    /// Externally owned account, controlled by a private key.
+    Account,
+    /// Contract, e.g. a cw3 multisig or a DAO.
+    Contract,
+}
+
+#[cw_serde]
+pub struct RoleHolderKind {
+    pub role: Role,
+    pub kind: HolderKind,
+}
+
+/// Roles that must be held by contracts when contract holders are required.
+const CONTRACT_REQUIRED_ROLES: [Role; 2] = [Role::Governor, Role::Custodian];
+
+/// Roles which holder kind can be declared, all of them are held by a single address.
+const DECLARABLE_ROLES: [Role; 4] = [
+    Role::Governor,
+    Role::MemberManager,
+    Role::Custodian,
+    Role::ComplianceOfficer,
+];
+
+fn role_key(role: Role) -> StdResult<&'static str> {
+    match role {
+        Role::Governor => Ok("governor"),
+        Role::MemberManager => Ok("member_manager"),
+        Role::Custodian => Ok("custodian"),
+        Role::ComplianceOfficer => Ok("compliance_officer"),
+        _ => Err(StdError::generic_err(format!(
+            "Role `{role:?}` is not held by a single address"
+        ))),
+    }
+}
+
+/// Require the governor and the custodian to be contracts, can only be called at contract instantiation
+/// before the roles are initialized. This should be enabled on mainnet deployments.
+pub fn initialize_contract_holders_required(deps: DepsMut) -> Result<(), ContractError> {
+    CONTRACT_HOLDERS_REQUIRED.save(deps.storage, &true)?;
+    for role in CONTRACT_REQUIRED_ROLES {
+        ROLE_HOLDER_KINDS.save(deps.storage, role_key(role)?, &HolderKind::Contract)?;
+    }
+
+    Ok(())
+}
+
+/// Declare the kind of address that must hold the role, or remove the declaration with `None`.
+/// The current holder of the role must be of the declared kind. Only the governor can declare holder kinds.
+pub fn set_role_holder_kind(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    role: Role,
+    kind: Option<HolderKind>,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::Governor], &info.sender, deps.as_ref(), env)?;
+
+    let key = role_key(role)?;
+    ensure!(
+        !(is_contract_holders_required(deps.as_ref())?
+            && CONTRACT_REQUIRED_ROLES.contains(&role)
+            && kind != Some(HolderKind::Contract)),
+        ContractError::ContractHolderRequired {
+            role: key.to_string()
+        }
+    );
+
+    let mut attrs = vec![attr("role", key)];
+    match kind {
+        Some(kind) => {
+            if let Some(holder) = may_get_holder(deps.as_ref(), role)? {
+                ensure_kind(deps.as_ref(), key, kind, &holder)?;
+            }
+            ROLE_HOLDER_KINDS.save(deps.storage, key, &kind)?;
+            attrs.push(attr("kind", format!("{kind:?}")));
+        }
+        None => ROLE_HOLDER_KINDS.remove(deps.storage, key),
+    }
+
+    Ok(Response::new().add_attributes(action_attrs("set_role_holder_kind", attrs)))
+}
+
+/// Ensure that the address is of the kind declared for the role, if any.
+/// Used whenever an address is about to hold the role or become its candidate.
+pub fn ensure_holder_kind(deps: Deps, role: Role, address: &Addr) -> Result<(), ContractError> {
+    let key = role_key(role)?;
+    match ROLE_HOLDER_KINDS.may_load(deps.storage, key)? {
+        Some(kind) => ensure_kind(deps, key, kind, address),
+        None => Ok(()),
+    }
+}
+
+fn ensure_kind(
+    deps: Deps,
+    key: &str,
+    kind: HolderKind,
+    address: &Addr,
+) -> Result<(), ContractError> {
+    ensure!(
+        holder_kind_of(deps, address)? == kind,
+        ContractError::HolderKindMismatch {
+            role: key.to_string(),
+            address: address.to_string(),
+            expected: format!("{kind:?}"),
+        }
+    );
+
+    Ok(())
+}
+
+/// Get the kind of the address. Only contracts have contract info,
+/// any query failure other than the address not being a contract is returned as an error.
+pub fn holder_kind_of(deps: Deps, address: &Addr) -> StdResult<HolderKind> {
+    let request: QueryRequest<Empty> = WasmQuery::ContractInfo {
+        contract_addr: address.to_string(),
+    }
+    .into();
+
+    match deps.querier.raw_query(&to_vec(&request)?) {
+        SystemResult::Ok(ContractResult::Ok(_)) => Ok(HolderKind::Contract),
+        SystemResult::Err(SystemError::NoSuchContract { .. }) => Ok(HolderKind::Account),
+        SystemResult::Err(system_err) => Err(StdError::generic_err(format!(
+            "Querier system error: {system_err}"
+        ))),
+        SystemResult::Ok(ContractResult::Err(contract_err)) => Err(StdError::generic_err(format!(
+            "Querier contract error: {contract_err}"
+        ))),
+    }
+}
+
+/// Check if the governor and the custodian are required to be contracts.
+pub fn is_contract_holders_required(deps: Deps) -> StdResult<bool> {
+    Ok(CONTRACT_HOLDERS_REQUIRED
+        .may_load(deps.storage)?
+        .unwrap_or_default())
+}
+
+/// List the declared holder kinds.
+pub fn list_role_holder_kinds(deps: Deps) -> StdResult<Vec<RoleHolderKind>> {
+    let mut kinds = vec![];
+    for role in DECLARABLE_ROLES {
+        if let Some(kind) = ROLE_HOLDER_KINDS.may_load(deps.storage, role_key(role)?)? {
+            kinds.push(RoleHolderKind { role, kind });
+        }
+    }
+
+    Ok(kinds)
+}
+
+/// Get the current holder of the role, if any.
+pub fn may_get_holder(deps: Deps, role: Role) -> StdResult<Option<Addr>> {
+    match role {
+        Role::Governor => governor::get_governor(deps).map(Some),
+        Role::MemberManager => member_manager::may_get_member_manager(deps),
+        Role::Custodian => custodian::may_get_custodian(deps),
+        Role::ComplianceOfficer => compliance::may_get_compliance_officer(deps),
+        _ => role_key(role).map(|_| None),
+    }
+}
+
+/// Query message of cw3 contracts, only the voter list is used.
+#[cw_serde]
+enum Cw3QueryMsg {
+    ListVoters {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+/// Voter of a cw3 contract, mirrors `cw3::VoterDetail`.
+#[cw_serde]
+pub struct Voter {
+    pub addr: String,
+    pub weight: u64,
+}
+
+/// Mirrors `cw3::VoterListResponse`.
+#[cw_serde]
+pub struct VoterListResponse {
+    pub voters: Vec<Voter>,
+}
+
+/// List the voters of the cw3 contract holding the role.
+pub fn list_role_holder_voters(
+    deps: Deps,
+    role: Role,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Voter>> {
+    let key = role_key(role)?;
+    let holder = may_get_holder(deps, role)?
+        .ok_or_else(|| StdError::not_found(format!("Holder of `{key}`")))?;
+
+    let res: VoterListResponse = deps
+        .querier
+        .query_wasm_smart(holder, &Cw3QueryMsg::ListVoters { start_after, limit })?;
+
+    Ok(res.voters)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        from_binary,
+        testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage},
+        to_binary, ContractInfoResponse, OwnedDeps,
+    };
+
+    use super::*;
+
+    const MULTISIG: &str = "osmo1multisig";
+    const UNREACHABLE: &str = "osmo1unreachable";
+
+    fn mock_dependencies_with_multisig() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let mut deps = mock_dependencies();
+        deps.querier.update_wasm(|query| match query {
+            WasmQuery::ContractInfo { contract_addr } if contract_addr == MULTISIG => {
+                SystemResult::Ok(ContractResult::Ok(
+                    to_binary(&ContractInfoResponse::new(1, "osmo1creator")).unwrap(),
+                ))
+            }
+            WasmQuery::Smart { contract_addr, msg } if contract_addr == MULTISIG => {
+                let Cw3QueryMsg::ListVoters { start_after, .. } = from_binary(msg).unwrap();
+                let voters = ["osmo1signer1", "osmo1signer2"]
+                    .iter()
+                    .filter(|addr| match start_after.as_deref() {
+                        Some(start) => **addr > start,
+                        None => true,
+                    })
+                    .map(|addr| Voter {
+                        addr: addr.to_string(),
+                        weight: 1,
+                    })
+                    .collect();
+                SystemResult::Ok(ContractResult::Ok(
+                    to_binary(&VoterListResponse { voters }).unwrap(),
+                ))
+            }
+            WasmQuery::ContractInfo { contract_addr } if contract_addr == UNREACHABLE => {
+                SystemResult::Err(SystemError::Unknown {})
+            }
+            WasmQuery::Smart { contract_addr, .. }
+            | WasmQuery::Raw { contract_addr, .. }
+            | WasmQuery::ContractInfo { contract_addr } => {
+                SystemResult::Err(SystemError::NoSuchContract {
+                    addr: contract_addr.clone(),
+                })
+            }
+            _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: "wasm".to_string(),
+            }),
+        });
+        deps
+    }
+
+    #[test]
+    fn test_role_holder_kind() {
+        let mut deps = mock_dependencies_with_multisig();
+        let governor = "osmo1governor";
+        let member_manager = "osmo1membermanager";
+        let custodian = "osmo1custodian";
+
+        // setup
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
+        member_manager::set_member_manager(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            member_manager,
+        )
+        .unwrap();
+
+        assert_eq!(
+            holder_kind_of(deps.as_ref(), &Addr::unchecked(MULTISIG)).unwrap(),
+            HolderKind::Contract
+        );
+        assert_eq!(
+            holder_kind_of(deps.as_ref(), &Addr::unchecked(governor)).unwrap(),
+            HolderKind::Account
+        );
+
+        // query failures are not mistaken for an account
+        assert_eq!(
+            holder_kind_of(deps.as_ref(), &Addr::unchecked(UNREACHABLE)).unwrap_err(),
+            StdError::generic_err("Querier system error: Unknown system error")
+        );
+
+        // only governor can declare holder kinds
+        let err = set_role_holder_kind(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            Role::Custodian,
+            Some(HolderKind::Contract),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // only roles held by a single address can be declared
+        let err = set_role_holder_kind(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            Role::Merchant,
+            Some(HolderKind::Contract),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Std(StdError::generic_err(
+                "Role `Merchant` is not held by a single address"
+            ))
+        );
+
+        // current holder must be of the declared kind
+        let err = set_role_holder_kind(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            Role::Governor,
+            Some(HolderKind::Contract),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::HolderKindMismatch {
+                role: "governor".to_string(),
+                address: governor.to_string(),
+                expected: "Contract".to_string(),
+            }
+        );
+
+        assert_eq!(
+            set_role_holder_kind(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(governor, &[]),
+                Role::Custodian,
+                Some(HolderKind::Contract),
+            )
+            .unwrap()
+            .attributes,
+            vec![
+                attr("action", "set_role_holder_kind"),
+                attr("role", "custodian"),
+                attr("kind", "Contract"),
+            ]
+        );
+
+        // new holder must be of the declared kind
+        let err = custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::HolderKindMismatch {
+                role: "custodian".to_string(),
+                address: custodian.to_string(),
+                expected: "Contract".to_string(),
+            }
+        );
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            MULTISIG,
+        )
+        .unwrap();
+
+        assert_eq!(
+            list_role_holder_kinds(deps.as_ref()).unwrap(),
+            vec![RoleHolderKind {
+                role: Role::Custodian,
+                kind: HolderKind::Contract
+            }]
+        );
+
+        // remove declaration
+        set_role_holder_kind(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            Role::Custodian,
+            None,
+        )
+        .unwrap();
+        assert_eq!(list_role_holder_kinds(deps.as_ref()).unwrap(), vec![]);
+
+        // voters of the contract holder can be listed
+        assert_eq!(
+            list_role_holder_voters(deps.as_ref(), Role::Custodian, None, None).unwrap(),
+            vec![
+                Voter {
+                    addr: "osmo1signer1".to_string(),
+                    weight: 1
+                },
+                Voter {
+                    addr: "osmo1signer2".to_string(),
+                    weight: 1
+                },
+            ]
+        );
+        assert_eq!(
+            list_role_holder_voters(
+                deps.as_ref(),
+                Role::Custodian,
+                Some("osmo1signer1".to_string()),
+                None
+            )
+            .unwrap()
+            .len(),
+            1
+        );
+        assert!(list_role_holder_voters(deps.as_ref(), Role::Governor, None, None).is_err());
+        assert_eq!(
+            list_role_holder_voters(deps.as_ref(), Role::ComplianceOfficer, None, None)
+                .unwrap_err(),
+            StdError::not_found("Holder of `compliance_officer`")
+        );
+    }
+
+    #[test]
+    fn test_contract_holders_required() {
+        let mut deps = mock_dependencies_with_multisig();
+        let governor = "osmo1governor";
+
+        initialize_contract_holders_required(deps.as_mut()).unwrap();
+        assert!(is_contract_holders_required(deps.as_ref()).unwrap());
+        assert_eq!(
+            list_role_holder_kinds(deps.as_ref()).unwrap(),
+            vec![
+                RoleHolderKind {
+                    role: Role::Governor,
+                    kind: HolderKind::Contract
+                },
+                RoleHolderKind {
+                    role: Role::Custodian,
+                    kind: HolderKind::Contract
+                },
+            ]
+        );
+
+        // governor must be a contract
+        let err = governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::HolderKindMismatch {
+                role: "governor".to_string(),
+                address: governor.to_string(),
+                expected: "Contract".to_string(),
+            }
+        );
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            MULTISIG,
+        )
+        .unwrap();
+
+        // declarations of the governor and the custodian can not be changed
+        for kind in [None, Some(HolderKind::Account)] {
+            let err = set_role_holder_kind(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(MULTISIG, &[]),
+                Role::Custodian,
+                kind,
+            )
+            .unwrap_err();
+            assert_eq!(
+                err,
+                ContractError::ContractHolderRequired {
+                    role: "custodian".to_string()
+                }
+            );
+        }
+
+        // other roles can still be declared
+        set_role_holder_kind(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(MULTISIG, &[]),
+            Role::MemberManager,
+            Some(HolderKind::Account),
+        )
+        .unwrap();
+    }
+}
@@ -1,26 +1,147 @@
 /// `member_manager` module provides functionality to manage the member manager address.
-use cosmwasm_std::{attr, Addr, Deps, DepsMut, MessageInfo, Response, StdError};
+use cosmwasm_std::{attr, ensure, Addr, Deps, DepsMut, Env, MessageInfo, Response, StdError};
 
-use crate::{attrs::action_attrs, state::auth::MEMBER_MANAGER, ContractError};
+use crate::{
+    attrs::action_attrs,
+    state::auth::{MEMBER_MANAGER, MEMBER_MANAGER_CANDIDATE},
+    ContractError,
+};
 
-use super::{allow_only, has_no_priviledged_role, Role};
+use super::{
+    has_no_priviledged_role, has_no_priviledged_role_other_than, history, holder, require_any, Role,
+};
 
-/// Set the member manager address.
+/// Set the member manager address if it is not set yet.
+/// Once set, the role can only be handed over with `propose_member_manager` and `claim_member_manager`.
 pub fn set_member_manager(
-    deps: DepsMut,
+    mut deps: DepsMut,
+    env: &Env,
     info: &MessageInfo,
     address: &str,
 ) -> Result<Response, ContractError> {
-    allow_only(&[Role::Governor], &info.sender, deps.as_ref())?;
+    require_any(&[Role::Governor], &info.sender, deps.as_ref(), env)?;
     let address = deps.api.addr_validate(address)?;
 
-    has_no_priviledged_role(deps.as_ref(), &address)?;
+    has_no_priviledged_role(deps.as_ref(), env, &address)?;
+    holder::ensure_holder_kind(deps.as_ref(), Role::MemberManager, &address)?;
+    ensure!(
+        may_get_member_manager(deps.as_ref())?.is_none(),
+        ContractError::RoleAlreadySet {
+            role: "member manager".to_string()
+        }
+    );
     MEMBER_MANAGER.save(deps.storage, &address)?;
+    history::record_role_handover(
+        deps.branch(),
+        env,
+        Role::MemberManager,
+        None,
+        &address,
+        Some(&info.sender),
+    )?;
 
     let attrs = action_attrs("set_member_manager", vec![attr("address", address)]);
     Ok(Response::new().add_attributes(attrs))
 }
 
+/// Initialize the member manager, can only be called at contract instantiation
+pub fn initialize_member_manager(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    address: &str,
+) -> Result<(), ContractError> {
+    let address = deps.api.addr_validate(address)?;
+    has_no_priviledged_role(deps.as_ref(), env, &address)?;
+    holder::ensure_holder_kind(deps.as_ref(), Role::MemberManager, &address)?;
+    MEMBER_MANAGER.save(deps.storage, &address)?;
+    history::record_role_handover(
+        deps,
+        env,
+        Role::MemberManager,
+        None,
+        &address,
+        Some(&info.sender),
+    )
+    .map_err(Into::into)
+}
+
+/// Propose a candidate to take over the member manager role, the candidate has to claim the role.
+/// Proposing a new candidate replaces the pending one. Only the governor can propose a candidate.
+pub fn propose_member_manager(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    address: &str,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::Governor], &info.sender, deps.as_ref(), env)?;
+
+    let address = deps.api.addr_validate(address)?;
+    has_no_priviledged_role(deps.as_ref(), env, &address)?;
+    holder::ensure_holder_kind(deps.as_ref(), Role::MemberManager, &address)?;
+
+    MEMBER_MANAGER_CANDIDATE.save(deps.storage, &address)?;
+
+    let attrs = action_attrs("propose_member_manager", vec![attr("address", address)]);
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Claim the member manager role, only the member manager candidate can call this
+pub fn claim_member_manager(
+    mut deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+) -> Result<Response, ContractError> {
+    let candidate =
+        get_member_manager_candidate(deps.as_ref())?.ok_or(ContractError::Unauthorized {})?;
+
+    ensure!(info.sender == candidate, ContractError::Unauthorized {});
+    has_no_priviledged_role_other_than(
+        deps.as_ref(),
+        env,
+        &candidate,
+        &[Role::MemberManagerCandidate],
+    )?;
+    holder::ensure_holder_kind(deps.as_ref(), Role::MemberManager, &candidate)?;
+
+    let previous = may_get_member_manager(deps.as_ref())?;
+    MEMBER_MANAGER.save(deps.storage, &candidate)?;
+    MEMBER_MANAGER_CANDIDATE.remove(deps.storage);
+    history::record_role_handover(
+        deps.branch(),
+        env,
+        Role::MemberManager,
+        previous,
+        &candidate,
+        Some(&info.sender),
+    )?;
+
+    let attrs = action_attrs("claim_member_manager", vec![attr("address", candidate)]);
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Cancel the pending member manager candidate. Only the governor can cancel the proposal.
+pub fn cancel_member_manager_proposal(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::Governor], &info.sender, deps.as_ref(), env)?;
+
+    let candidate = get_member_manager_candidate(deps.as_ref())?.ok_or_else(|| {
+        ContractError::NoPendingCandidate {
+            role: "member manager".to_string(),
+        }
+    })?;
+    MEMBER_MANAGER_CANDIDATE.remove(deps.storage);
+
+    let attrs = action_attrs(
+        "cancel_member_manager_proposal",
+        vec![attr("address", candidate)],
+    );
+    Ok(Response::new().add_attributes(attrs))
+}
+
 /// Check if the given address is the member manager.
 pub fn is_member_manager(deps: Deps, address: &Addr) -> Result<bool, StdError> {
     match MEMBER_MANAGER.may_load(deps.storage)? {
@@ -31,14 +152,27 @@ pub fn is_member_manager(deps: Deps, address: &Addr) -> Result<bool, StdError> {
 
 /// Get the member manager address.
 pub fn get_member_manager(deps: Deps) -> Result<Addr, StdError> {
-    MEMBER_MANAGER
-        .may_load(deps.storage)?
-        .ok_or_else(|| StdError::not_found("MemberManager"))
+    may_get_member_manager(deps)?.ok_or_else(|| StdError::not_found("MemberManager"))
+}
+
+/// Get the member manager address if it has been set.
+pub fn may_get_member_manager(deps: Deps) -> Result<Option<Addr>, StdError> {
+    MEMBER_MANAGER.may_load(deps.storage)
+}
+
+/// Check if the given address is the member manager candidate.
+pub fn is_member_manager_candidate(deps: Deps, address: &Addr) -> Result<bool, StdError> {
+    Ok(get_member_manager_candidate(deps)?.as_ref() == Some(address))
+}
+
+/// Get the member manager candidate address.
+pub fn get_member_manager_candidate(deps: Deps) -> Result<Option<Addr>, StdError> {
+    MEMBER_MANAGER_CANDIDATE.may_load(deps.storage)
 }
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::testing::{mock_dependencies, mock_info};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
 
     use crate::auth::governor::initialize_governor;
 
@@ -53,7 +187,13 @@ mod tests {
         let non_member_manager_address = "osmo1nonmembermanager";
 
         // setup
-        initialize_governor(deps.as_mut(), governor).unwrap();
+        initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
 
         // check before set will fail
         assert!(
@@ -66,6 +206,7 @@ mod tests {
         // set member manager by non governor should fail
         let err = set_member_manager(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(non_governor, &[]),
             member_manager_address,
         )
@@ -76,6 +217,7 @@ mod tests {
         assert_eq!(
             set_member_manager(
                 deps.as_mut(),
+                &mock_env(),
                 &mock_info(governor, &[]),
                 member_manager_address
             )
@@ -100,4 +242,147 @@ mod tests {
                 .unwrap()
         );
     }
+
+    #[test]
+    fn test_member_manager_handover() {
+        let mut deps = mock_dependencies();
+        let governor = "osmo1governor";
+        let member_manager = "osmo1membermanager";
+        let new_member_manager = "osmo1newmembermanager";
+
+        // setup
+        initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
+        set_member_manager(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            member_manager,
+        )
+        .unwrap();
+
+        // can only be set once
+        let err = set_member_manager(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            new_member_manager,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::RoleAlreadySet {
+                role: "member manager".to_string()
+            }
+        );
+
+        // nothing to cancel or claim
+        let err =
+            cancel_member_manager_proposal(deps.as_mut(), &mock_env(), &mock_info(governor, &[]))
+                .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::NoPendingCandidate {
+                role: "member manager".to_string()
+            }
+        );
+        let err = claim_member_manager(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(new_member_manager, &[]),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // only governor can propose
+        let err = propose_member_manager(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            new_member_manager,
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // propose and cancel
+        propose_member_manager(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            new_member_manager,
+        )
+        .unwrap();
+        cancel_member_manager_proposal(deps.as_mut(), &mock_env(), &mock_info(governor, &[]))
+            .unwrap();
+        assert_eq!(get_member_manager_candidate(deps.as_ref()).unwrap(), None);
+
+        // propose
+        assert_eq!(
+            propose_member_manager(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(governor, &[]),
+                new_member_manager
+            )
+            .unwrap()
+            .attributes,
+            vec![
+                attr("action", "propose_member_manager"),
+                attr("address", new_member_manager)
+            ]
+        );
+        assert!(
+            is_member_manager_candidate(deps.as_ref(), &Addr::unchecked(new_member_manager))
+                .unwrap()
+        );
+
+        // member manager remains unchanged until the candidate claims the role
+        assert_eq!(get_member_manager(deps.as_ref()).unwrap(), member_manager);
+
+        // candidate can not be assigned another role
+        let err = crate::auth::merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            new_member_manager,
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::AlreadyHasPriviledgedRole {
+                address: new_member_manager.to_string()
+            }
+        );
+
+        // only the candidate can claim
+        let err = claim_member_manager(deps.as_mut(), &mock_env(), &mock_info(member_manager, &[]))
+            .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        assert_eq!(
+            claim_member_manager(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(new_member_manager, &[])
+            )
+            .unwrap()
+            .attributes,
+            vec![
+                attr("action", "claim_member_manager"),
+                attr("address", new_member_manager)
+            ]
+        );
+        assert_eq!(
+            get_member_manager(deps.as_ref()).unwrap(),
+            new_member_manager
+        );
+        assert_eq!(get_member_manager_candidate(deps.as_ref()).unwrap(), None);
+        assert!(!is_member_manager(deps.as_ref(), &Addr::unchecked(member_manager)).unwrap());
+    }
 }
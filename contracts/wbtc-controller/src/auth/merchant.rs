@@ -1,6 +1,8 @@
 /// `merchant` module provides functionality to manage merchants
+use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    attr, ensure, Addr, Deps, DepsMut, MessageInfo, Order, Response, StdError, StdResult,
+    attr, ensure, Addr, Deps, DepsMut, Env, MessageInfo, Order, Response, StdError, StdResult,
+    Timestamp,
 };
 use cw_storage_plus::Bound;
 
@@ -8,41 +10,261 @@ use crate::{
     attrs::action_attrs,
     auth::has_no_priviledged_role,
     constants::{DEFAULT_LIMIT, MAX_LIMIT},
+    error::BatchItemError,
     state::{
-        auth::MERCHANTS,
+        auth::{MERCHANTS, MERCHANT_ONBOARDED_AT, MERCHANT_PROFILES, SUSPENDED_MERCHANTS},
         deposit_address::{CUSTODIAN_DEPOSIT_ADDRESS_PER_MERCHANT, MERCHANT_DEPOSIT_ADDRESS},
     },
+    tokenfactory::payout_address,
     ContractError,
 };
 
-use super::{allow_only, Role};
+use super::{
+    history::{self, RoleChange},
+    operator,
+    permission::{allow_permission, Permission},
+    require_any, Role,
+};
+
+#[cw_serde]
+#[derive(Copy)]
+pub enum MerchantStatus {
+    Active,
+    /// Suspended merchant cannot issue mint requests, burn or accept redemptions,
+    /// and its pending mint requests cannot be approved.
+    Suspended,
+}
+
+/// Public information about who the merchant is.
+#[cw_serde]
+pub struct MerchantProfile {
+    /// Legal name of the merchant entity.
+    pub legal_name: String,
+
+    /// Website of the merchant.
+    pub website: Option<String>,
+
+    /// Jurisdiction the merchant entity is registered in.
+    pub jurisdiction: String,
+
+    /// Hex encoded SHA-256 hash of the off-chain KYC record.
+    pub kyc_reference_hash: String,
+}
+
+impl MerchantProfile {
+    fn validate(&self) -> Result<(), ContractError> {
+        let invalid = |reason: &str| ContractError::InvalidMerchantProfile {
+            reason: reason.to_string(),
+        };
+
+        ensure!(
+            !self.legal_name.trim().is_empty(),
+            invalid("legal_name must not be empty")
+        );
+        ensure!(
+            !self.jurisdiction.trim().is_empty(),
+            invalid("jurisdiction must not be empty")
+        );
+        ensure!(
+            self.website
+                .as_ref()
+                .map(|website| !website.trim().is_empty())
+                .unwrap_or(true),
+            invalid("website must not be empty if specified")
+        );
+        ensure!(
+            self.kyc_reference_hash.len() == 64
+                && self
+                    .kyc_reference_hash
+                    .chars()
+                    .all(|c| c.is_ascii_hexdigit()),
+            invalid("kyc_reference_hash must be a hex encoded SHA-256 hash")
+        );
 
-/// Add an address as member of merchant.
+        Ok(())
+    }
+}
+
+#[cw_serde]
+pub struct MerchantInfo {
+    pub address: Addr,
+    pub status: MerchantStatus,
+    /// `None` if the member manager has not set the merchant's profile.
+    pub profile: Option<MerchantProfile>,
+    /// Block time at which the merchant was added,
+    /// `None` for merchants added before the onboarding time was recorded.
+    pub onboarded_at: Option<Timestamp>,
+}
+
+/// Add an address as member of merchant, optionally with its profile.
 /// Duplicate addresses will not change the state since it's stored as a map's key.
 pub fn add_merchant(
-    deps: DepsMut,
+    mut deps: DepsMut,
+    env: &Env,
     info: &MessageInfo,
     address: &str,
+    profile: Option<MerchantProfile>,
 ) -> Result<Response, ContractError> {
-    allow_only(&[Role::MemberManager], &info.sender, deps.as_ref())?;
+    require_any(&[Role::MemberManager], &info.sender, deps.as_ref(), env)?;
 
     let validated_address = deps.api.addr_validate(address)?;
 
-    has_no_priviledged_role(deps.as_ref(), &validated_address)?;
+    has_no_priviledged_role(deps.as_ref(), env, &validated_address)?;
 
-    MERCHANTS.save(deps.storage, validated_address, &())?;
+    if let Some(profile) = profile {
+        profile.validate()?;
+        MERCHANT_PROFILES.save(deps.storage, validated_address.clone(), &profile)?;
+    }
+
+    MERCHANTS.save(deps.storage, validated_address.clone(), &())?;
+    MERCHANT_ONBOARDED_AT.save(deps.storage, validated_address.clone(), &env.block.time)?;
+    history::record_role_change(
+        deps.branch(),
+        env,
+        Role::Merchant,
+        &validated_address,
+        RoleChange::Granted,
+        Some(&info.sender),
+    )?;
 
     let attrs = action_attrs("add_merchant", vec![attr("address", address)]);
     Ok(Response::new().add_attributes(attrs))
 }
 
+/// Set the profile of the merchant. Only the member manager can set merchant profiles.
+pub fn set_merchant_profile(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    address: &str,
+    profile: MerchantProfile,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::MemberManager], &info.sender, deps.as_ref(), env)?;
+
+    let address = deps.api.addr_validate(address)?;
+    ensure!(
+        is_merchant(deps.as_ref(), &address)?,
+        ContractError::NotAMerchant {
+            address: address.to_string()
+        }
+    );
+
+    profile.validate()?;
+    MERCHANT_PROFILES.save(deps.storage, address.clone(), &profile)?;
+
+    let attrs = action_attrs(
+        "set_merchant_profile",
+        vec![
+            attr("address", address),
+            attr("legal_name", profile.legal_name),
+        ],
+    );
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Merchant to be added with `add_merchants`.
+#[cw_serde]
+pub struct NewMerchant {
+    pub address: String,
+    pub profile: Option<MerchantProfile>,
+    /// Custodian deposit address to be associated with the merchant,
+    /// requires the sender to also have the `deposit.set_custodian` permission.
+    pub custodian_deposit_address: Option<String>,
+}
+
+/// Add multiple merchants at once, optionally with their profiles and custodian deposit addresses.
+/// Setting a custodian deposit address requires the `deposit.set_custodian` permission, like `set_custodian_deposit_address`.
+/// Every merchant is validated and the errors of all failing merchants are reported together,
+/// no merchant is added if any of them fails.
+pub fn add_merchants(
+    mut deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    merchants: Vec<NewMerchant>,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::MemberManager], &info.sender, deps.as_ref(), env)?;
+
+    let mut attrs = vec![];
+    let mut errors = vec![];
+    for merchant in merchants {
+        let res = add_merchant(
+            deps.branch(),
+            env,
+            info,
+            &merchant.address,
+            merchant.profile,
+        )
+        .and_then(|_| match merchant.custodian_deposit_address.as_deref() {
+            Some(deposit_address) => {
+                allow_permission(
+                    Permission::DepositSetCustodian,
+                    &info.sender,
+                    deps.as_ref(),
+                    env,
+                )?;
+                CUSTODIAN_DEPOSIT_ADDRESS_PER_MERCHANT
+                    .set_deposit_address(
+                        deps.branch(),
+                        info,
+                        &merchant.address,
+                        Some(deposit_address),
+                    )
+                    .map(|_| vec![attr("custodian_deposit_address", deposit_address)])
+            }
+            None => Ok(vec![]),
+        });
+
+        match res {
+            Ok(deposit_address_attrs) => {
+                attrs.push(attr("address", &merchant.address));
+                attrs.extend(deposit_address_attrs);
+            }
+            Err(err) => errors.push(BatchItemError {
+                address: merchant.address,
+                error: err.to_string(),
+            }),
+        }
+    }
+
+    // the whole transaction is reverted if any merchant fails
+    ensure!(errors.is_empty(), ContractError::BatchFailed { errors });
+
+    Ok(Response::new().add_attributes(action_attrs("add_merchants", attrs)))
+}
+
+/// Initialize the merchants, can only be called at contract instantiation
+pub fn initialize_merchants(
+    mut deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    addresses: &[String],
+) -> Result<(), ContractError> {
+    for address in addresses {
+        let validated_address = deps.api.addr_validate(address)?;
+        has_no_priviledged_role(deps.as_ref(), env, &validated_address)?;
+        MERCHANTS.save(deps.storage, validated_address.clone(), &())?;
+        MERCHANT_ONBOARDED_AT.save(deps.storage, validated_address.clone(), &env.block.time)?;
+        history::record_role_change(
+            deps.branch(),
+            env,
+            Role::Merchant,
+            &validated_address,
+            RoleChange::Granted,
+            Some(&info.sender),
+        )?;
+    }
+
+    Ok(())
+}
+
 /// Remove address from member of merchant.
 pub fn remove_merchant(
     mut deps: DepsMut,
+    env: &Env,
     info: &MessageInfo,
     address: &str,
 ) -> Result<Response, ContractError> {
-    allow_only(&[Role::MemberManager], &info.sender, deps.as_ref())?;
+    require_any(&[Role::MemberManager], &info.sender, deps.as_ref(), env)?;
 
     let address = deps.api.addr_validate(address)?;
 
@@ -56,6 +278,9 @@ pub fn remove_merchant(
 
     let attrs = action_attrs("remove_merchant", vec![attr("address", address.as_str())]);
     MERCHANTS.remove(deps.storage, address.clone());
+    SUSPENDED_MERCHANTS.remove(deps.storage, address.clone());
+    MERCHANT_PROFILES.remove(deps.storage, address.clone());
+    MERCHANT_ONBOARDED_AT.remove(deps.storage, address.clone());
 
     // remove asscoiated deposit addresses
     CUSTODIAN_DEPOSIT_ADDRESS_PER_MERCHANT.set_deposit_address(
@@ -65,11 +290,149 @@ pub fn remove_merchant(
         None,
     )?;
 
-    MERCHANT_DEPOSIT_ADDRESS.set_deposit_address(deps, info, address.as_str(), None)?;
+    MERCHANT_DEPOSIT_ADDRESS.set_deposit_address(deps.branch(), info, address.as_str(), None)?;
+
+    // remove payout addresses whitelist
+    payout_address::clear_payout_addresses(deps.storage, &address)?;
+
+    // revoke operators
+    operator::clear_operators(deps.branch(), env, info, &address)?;
+
+    history::record_role_change(
+        deps.branch(),
+        env,
+        Role::Merchant,
+        &address,
+        RoleChange::Revoked,
+        Some(&info.sender),
+    )?;
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Remove multiple merchants at once.
+/// The errors of all failing addresses are reported together, no merchant is removed if any of them fails.
+pub fn remove_merchants(
+    mut deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    addresses: Vec<String>,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::MemberManager], &info.sender, deps.as_ref(), env)?;
+
+    let mut attrs = vec![];
+    let mut errors = vec![];
+    for address in addresses {
+        match remove_merchant(deps.branch(), env, info, &address) {
+            Ok(_) => attrs.push(attr("address", address)),
+            Err(err) => errors.push(BatchItemError {
+                address,
+                error: err.to_string(),
+            }),
+        }
+    }
+
+    // the whole transaction is reverted if any merchant fails
+    ensure!(errors.is_empty(), ContractError::BatchFailed { errors });
+
+    Ok(Response::new().add_attributes(action_attrs("remove_merchants", attrs)))
+}
+
+/// Suspend the merchant while preserving its deposit addresses and history.
+/// Only the member manager can suspend merchants.
+pub fn suspend_merchant(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    address: &str,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::MemberManager], &info.sender, deps.as_ref(), env)?;
+
+    let address = deps.api.addr_validate(address)?;
+    ensure!(
+        is_merchant(deps.as_ref(), &address)?,
+        ContractError::NotAMerchant {
+            address: address.to_string()
+        }
+    );
+
+    SUSPENDED_MERCHANTS.save(deps.storage, address.clone(), &())?;
+
+    let attrs = action_attrs("suspend_merchant", vec![attr("address", address)]);
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Reinstate the suspended merchant. Only the member manager can reinstate merchants.
+pub fn reinstate_merchant(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    address: &str,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::MemberManager], &info.sender, deps.as_ref(), env)?;
+
+    let address = deps.api.addr_validate(address)?;
+    ensure!(
+        is_merchant(deps.as_ref(), &address)?,
+        ContractError::NotAMerchant {
+            address: address.to_string()
+        }
+    );
+
+    SUSPENDED_MERCHANTS.remove(deps.storage, address.clone());
 
+    let attrs = action_attrs("reinstate_merchant", vec![attr("address", address)]);
     Ok(Response::new().add_attributes(attrs))
 }
 
+/// Get the status of the merchant.
+pub fn get_merchant_status(deps: Deps, address: &Addr) -> StdResult<MerchantStatus> {
+    let is_suspended = SUSPENDED_MERCHANTS
+        .may_load(deps.storage, address.clone())?
+        .is_some();
+
+    Ok(if is_suspended {
+        MerchantStatus::Suspended
+    } else {
+        MerchantStatus::Active
+    })
+}
+
+/// Ensure that the merchant is not suspended.
+pub fn ensure_merchant_not_suspended(deps: Deps, address: &Addr) -> Result<(), ContractError> {
+    ensure!(
+        get_merchant_status(deps, address)? != MerchantStatus::Suspended,
+        ContractError::MerchantSuspended {
+            address: address.to_string()
+        }
+    );
+
+    Ok(())
+}
+
+/// Get the profile of the merchant, if set.
+pub fn get_merchant_profile(deps: Deps, address: &Addr) -> StdResult<Option<MerchantProfile>> {
+    MERCHANT_PROFILES.may_load(deps.storage, address.clone())
+}
+
+/// Get the merchant's status, profile and onboarding time.
+pub fn get_merchant(deps: Deps, address: &Addr) -> StdResult<MerchantInfo> {
+    if !is_merchant(deps, address)? {
+        return Err(StdError::not_found("Merchant"));
+    }
+
+    merchant_info(deps, address.clone())
+}
+
+fn merchant_info(deps: Deps, address: Addr) -> StdResult<MerchantInfo> {
+    Ok(MerchantInfo {
+        status: get_merchant_status(deps, &address)?,
+        profile: get_merchant_profile(deps, &address)?,
+        onboarded_at: MERCHANT_ONBOARDED_AT.may_load(deps.storage, address.clone())?,
+        address,
+    })
+}
+
 /// Check if the given address is a merchant.
 pub fn is_merchant(deps: Deps, address: &Addr) -> Result<bool, StdError> {
     Ok(MERCHANTS
@@ -77,30 +440,71 @@ pub fn is_merchant(deps: Deps, address: &Addr) -> Result<bool, StdError> {
         .is_some())
 }
 
-/// List merchants with pagination.
+/// List merchants with their status with pagination, optionally filtered by status.
+/// At most `limit` merchants are scanned, so a page filtered by status may hold fewer merchants than `limit`.
+/// Returns the merchants along with the address to continue listing after, `None` if there are no more merchants.
 pub fn list_merchants(
     deps: Deps,
     start_after: Option<String>,
     limit: Option<u32>,
-) -> Result<Vec<Addr>, StdError> {
+    status: Option<MerchantStatus>,
+) -> Result<(Vec<MerchantInfo>, Option<Addr>), StdError> {
     let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
     let start_after_bound = start_after
         .map(|addr| deps.api.addr_validate(&addr))
         .transpose()?
         .map(Bound::exclusive);
 
-    let merchants = MERCHANTS
+    // suspended merchants are listed from their own map, so that they are not searched for among all merchants
+    let scanned = match status {
+        Some(MerchantStatus::Suspended) => SUSPENDED_MERCHANTS,
+        _ => MERCHANTS,
+    };
+    let addresses = scanned
         .keys(deps.storage, start_after_bound, None, Order::Ascending)
         .take(limit)
         .collect::<StdResult<Vec<_>>>()?;
 
-    Ok(merchants)
+    let next_start_after = if addresses.len() == limit {
+        addresses.last().cloned()
+    } else {
+        None
+    };
+
+    let merchants = addresses
+        .into_iter()
+        .map(|address| merchant_info(deps, address))
+        .filter(|merchant| match (merchant, status) {
+            (Ok(merchant), Some(status)) => merchant.status == status,
+            _ => true,
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok((merchants, next_start_after))
+}
+
+/// List merchant addresses with pagination.
+pub fn list_merchant_addresses(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<Addr>, StdError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after_bound = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?
+        .map(Bound::exclusive);
+
+    MERCHANTS
+        .keys(deps.storage, start_after_bound, None, Order::Ascending)
+        .take(limit)
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        auth::{custodian, governor, member_manager},
+        auth::{custodian, governor, member_manager, permission},
         tokenfactory::deposit_address::{
             get_custodian_deposit_address, get_merchant_deposit_address,
             set_custodian_deposit_address, set_merchant_deposit_address,
@@ -108,7 +512,7 @@ mod tests {
     };
 
     use super::*;
-    use cosmwasm_std::testing::{mock_dependencies, mock_info};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
 
     #[test]
     fn test_manage_merchant() {
@@ -121,9 +525,16 @@ mod tests {
         let non_merchant_address = "osmo1nonmerchant";
 
         // setup
-        governor::initialize_governor(deps.as_mut(), governor).unwrap();
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
         member_manager::set_member_manager(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(governor, &[]),
             member_manager,
         )
@@ -136,8 +547,10 @@ mod tests {
         // add merchant by non governor should fail
         let err = add_merchant(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(non_member_manager, &[]),
             merchant_address_1,
+            None,
         )
         .unwrap_err();
         assert_eq!(err, ContractError::Unauthorized {});
@@ -146,8 +559,10 @@ mod tests {
         assert_eq!(
             add_merchant(
                 deps.as_mut(),
+                &mock_env(),
                 &mock_info(member_manager, &[]),
-                merchant_address_1
+                merchant_address_1,
+                None
             )
             .unwrap()
             .attributes,
@@ -164,8 +579,10 @@ mod tests {
         assert_eq!(
             add_merchant(
                 deps.as_mut(),
+                &mock_env(),
                 &mock_info(member_manager, &[]),
-                merchant_address_2
+                merchant_address_2,
+                None
             )
             .unwrap()
             .attributes,
@@ -181,8 +598,10 @@ mod tests {
         // adding merchant 2 again should not change state and return error
         let err = add_merchant(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(member_manager, &[]),
             merchant_address_2,
+            None,
         )
         .unwrap_err();
 
@@ -196,6 +615,7 @@ mod tests {
         // remove merchant by non_governor should fail
         let err = remove_merchant(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(non_member_manager, &[]),
             merchant_address_1,
         )
@@ -205,6 +625,7 @@ mod tests {
         // remove non merchant
         let err = remove_merchant(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(member_manager, &[]),
             non_merchant_address,
         )
@@ -220,6 +641,7 @@ mod tests {
         assert_eq!(
             remove_merchant(
                 deps.as_mut(),
+                &mock_env(),
                 &mock_info(member_manager, &[]),
                 merchant_address_1
             )
@@ -242,16 +664,23 @@ mod tests {
         let member_manager = "osmo1membermanager";
 
         // setup
-        governor::initialize_governor(deps.as_mut(), governor).unwrap();
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
         member_manager::set_member_manager(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(governor, &[]),
             member_manager,
         )
         .unwrap();
 
         assert_eq!(
-            list_merchants(deps.as_ref(), None, None).unwrap(),
+            list_merchant_addresses(deps.as_ref(), None, None),
             vec![] as Vec<Addr>
         );
 
@@ -260,8 +689,10 @@ mod tests {
             let merchant_address = format!("osmo1merchant{:0>3}", i);
             add_merchant(
                 deps.as_mut(),
+                &mock_env(),
                 &mock_info(member_manager, &[]),
                 &merchant_address,
+                None,
             )
             .unwrap();
         }
@@ -272,7 +703,7 @@ mod tests {
             .collect::<Vec<Addr>>();
 
         assert_eq!(
-            list_merchants(deps.as_ref(), None, None).unwrap(),
+            list_merchant_addresses(deps.as_ref(), None, None),
             first_ten
         );
 
@@ -282,7 +713,7 @@ mod tests {
             .collect::<Vec<Addr>>();
 
         assert_eq!(
-            list_merchants(deps.as_ref(), None, Some(21)).unwrap(),
+            list_merchant_addresses(deps.as_ref(), None, Some(21)),
             first_twenty_one
         );
 
@@ -292,7 +723,7 @@ mod tests {
             .collect::<Vec<Addr>>();
 
         assert_eq!(
-            list_merchants(deps.as_ref(), None, Some(999)).unwrap(), // MAX_LIMIT = 100
+            list_merchant_addresses(deps.as_ref(), None, Some(999)), // MAX_LIMIT = 100
             first_hundred
         );
 
@@ -302,12 +733,11 @@ mod tests {
             .collect::<Vec<Addr>>();
 
         assert_eq!(
-            list_merchants(
+            list_merchant_addresses(
                 deps.as_ref(),
                 Some(first_hundred.last().unwrap().to_string()),
                 Some(42)
-            )
-            .unwrap(),
+            ),
             hundred_one_to_hundred_forty_two
         );
     }
@@ -323,15 +753,23 @@ mod tests {
         let merchant_deposit_address = "bc1q35rayrk92pvwamwm4n2hsd3epez2g2tqcqa0fx";
 
         // setup
-        governor::initialize_governor(deps.as_mut(), governor).unwrap();
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
         member_manager::set_member_manager(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(governor, &[]),
             member_manager,
         )
         .unwrap();
         custodian::set_custodian(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(member_manager, &[]),
             custodian_address,
         )
@@ -340,8 +778,10 @@ mod tests {
         // add merchant
         add_merchant(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(member_manager, &[]),
             merchant_address,
+            None,
         )
         .unwrap();
 
@@ -349,6 +789,7 @@ mod tests {
 
         set_custodian_deposit_address(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(custodian_address, &[]),
             merchant_address,
             Some(custodian_deposit_address),
@@ -357,6 +798,7 @@ mod tests {
 
         set_merchant_deposit_address(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(merchant_address, &[]),
             Some(merchant_deposit_address),
         )
@@ -377,6 +819,7 @@ mod tests {
         // remove merchant
         remove_merchant(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(member_manager, &[]),
             merchant_address,
         )
@@ -400,4 +843,605 @@ mod tests {
             ))
         );
     }
+
+    #[test]
+    fn test_suspend_merchant() {
+        let mut deps = mock_dependencies();
+        let governor = "osmo1governor";
+        let member_manager = "osmo1membermanager";
+        let custodian = "osmo1custodian";
+        let merchant_1 = "osmo1merchant1";
+        let merchant_2 = "osmo1merchant2";
+        let custodian_deposit_address = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+
+        // setup
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
+        member_manager::set_member_manager(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            member_manager,
+        )
+        .unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+        for merchant in [merchant_1, merchant_2] {
+            add_merchant(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(member_manager, &[]),
+                merchant,
+                None,
+            )
+            .unwrap();
+        }
+        set_custodian_deposit_address(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(custodian, &[]),
+            merchant_1,
+            Some(custodian_deposit_address),
+        )
+        .unwrap();
+
+        // only member manager can suspend
+        let err = suspend_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            merchant_1,
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // only merchant can be suspended
+        let err = suspend_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::NotAMerchant {
+                address: custodian.to_string()
+            }
+        );
+
+        assert_eq!(
+            suspend_merchant(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(member_manager, &[]),
+                merchant_1
+            )
+            .unwrap()
+            .attributes,
+            vec![
+                attr("action", "suspend_merchant"),
+                attr("address", merchant_1)
+            ]
+        );
+
+        // suspended merchant keeps its role and deposit address
+        let merchant_1_addr = Addr::unchecked(merchant_1);
+        assert!(is_merchant(deps.as_ref(), &merchant_1_addr).unwrap());
+        assert_eq!(
+            get_custodian_deposit_address(deps.as_ref(), &merchant_1_addr).unwrap(),
+            custodian_deposit_address
+        );
+        assert_eq!(
+            ensure_merchant_not_suspended(deps.as_ref(), &merchant_1_addr).unwrap_err(),
+            ContractError::MerchantSuspended {
+                address: merchant_1.to_string()
+            }
+        );
+
+        // list with status
+        assert_eq!(
+            list_merchants(deps.as_ref(), None, None, None).unwrap(),
+            (
+                vec![
+                    MerchantInfo {
+                        address: merchant_1_addr.clone(),
+                        status: MerchantStatus::Suspended,
+                        profile: None,
+                        onboarded_at: Some(mock_env().block.time),
+                    },
+                    MerchantInfo {
+                        address: Addr::unchecked(merchant_2),
+                        status: MerchantStatus::Active,
+                        profile: None,
+                        onboarded_at: Some(mock_env().block.time),
+                    },
+                ],
+                None
+            )
+        );
+        let merchant_2_info = MerchantInfo {
+            address: Addr::unchecked(merchant_2),
+            status: MerchantStatus::Active,
+            profile: None,
+            onboarded_at: Some(mock_env().block.time),
+        };
+        assert_eq!(
+            list_merchants(deps.as_ref(), None, None, Some(MerchantStatus::Active)).unwrap(),
+            (vec![merchant_2_info.clone()], None)
+        );
+        assert_eq!(
+            list_merchants(deps.as_ref(), None, None, Some(MerchantStatus::Suspended)).unwrap(),
+            (
+                vec![MerchantInfo {
+                    address: merchant_1_addr.clone(),
+                    status: MerchantStatus::Suspended,
+                    profile: None,
+                    onboarded_at: Some(mock_env().block.time),
+                }],
+                None
+            )
+        );
+
+        // filtered pages scan at most `limit` merchants and continue after the last scanned one
+        assert_eq!(
+            list_merchants(deps.as_ref(), None, Some(1), Some(MerchantStatus::Active)).unwrap(),
+            (vec![], Some(merchant_1_addr.clone()))
+        );
+        assert_eq!(
+            list_merchants(
+                deps.as_ref(),
+                Some(merchant_1.to_string()),
+                Some(1),
+                Some(MerchantStatus::Active)
+            )
+            .unwrap(),
+            (vec![merchant_2_info], Some(Addr::unchecked(merchant_2)))
+        );
+        assert_eq!(
+            list_merchants(
+                deps.as_ref(),
+                Some(merchant_2.to_string()),
+                Some(1),
+                Some(MerchantStatus::Active)
+            )
+            .unwrap(),
+            (vec![], None)
+        );
+
+        // reinstate
+        reinstate_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant_1,
+        )
+        .unwrap();
+        assert_eq!(
+            get_merchant_status(deps.as_ref(), &merchant_1_addr).unwrap(),
+            MerchantStatus::Active
+        );
+        assert_eq!(
+            list_merchants(deps.as_ref(), None, None, Some(MerchantStatus::Suspended)).unwrap(),
+            (vec![], None)
+        );
+    }
+
+    #[test]
+    fn test_merchant_profile() {
+        let mut deps = mock_dependencies();
+        let governor = "osmo1governor";
+        let member_manager = "osmo1membermanager";
+        let merchant_1 = "osmo1merchant1";
+        let merchant_2 = "osmo1merchant2";
+
+        // setup
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
+        member_manager::set_member_manager(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            member_manager,
+        )
+        .unwrap();
+
+        let profile = MerchantProfile {
+            legal_name: "Merchant One Ltd.".to_string(),
+            website: Some("https://merchant.one".to_string()),
+            jurisdiction: "SG".to_string(),
+            kyc_reference_hash: "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+                .to_string(),
+        };
+        let onboarded_at = mock_env().block.time;
+        let mut later_env = mock_env();
+        later_env.block.time = onboarded_at.plus_seconds(3600);
+
+        // invalid profile is rejected
+        let err = add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant_1,
+            Some(MerchantProfile {
+                kyc_reference_hash: "not a hash".to_string(),
+                ..profile.clone()
+            }),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidMerchantProfile {
+                reason: "kyc_reference_hash must be a hex encoded SHA-256 hash".to_string()
+            }
+        );
+        assert!(!is_merchant(deps.as_ref(), &Addr::unchecked(merchant_1)).unwrap());
+
+        // add merchant with and without profile
+        add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant_1,
+            Some(profile.clone()),
+        )
+        .unwrap();
+        add_merchant(
+            deps.as_mut(),
+            &later_env,
+            &mock_info(member_manager, &[]),
+            merchant_2,
+            None,
+        )
+        .unwrap();
+
+        // onboarding time is the block time the merchant was added at
+        assert_eq!(
+            get_merchant(deps.as_ref(), &Addr::unchecked(merchant_1)).unwrap(),
+            MerchantInfo {
+                address: Addr::unchecked(merchant_1),
+                status: MerchantStatus::Active,
+                profile: Some(profile.clone()),
+                onboarded_at: Some(onboarded_at),
+            }
+        );
+        assert_eq!(
+            get_merchant_profile(deps.as_ref(), &Addr::unchecked(merchant_2)).unwrap(),
+            None
+        );
+        assert_eq!(
+            get_merchant(deps.as_ref(), &Addr::unchecked(member_manager)).unwrap_err(),
+            StdError::not_found("Merchant")
+        );
+
+        // only member manager can update profile
+        let updated_profile = MerchantProfile {
+            legal_name: "Merchant Two Ltd.".to_string(),
+            website: None,
+            ..profile.clone()
+        };
+        let err = set_merchant_profile(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            merchant_2,
+            updated_profile.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // only merchant can have profile
+        let err = set_merchant_profile(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            governor,
+            updated_profile.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::NotAMerchant {
+                address: governor.to_string()
+            }
+        );
+
+        let err = set_merchant_profile(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant_2,
+            MerchantProfile {
+                legal_name: " ".to_string(),
+                ..updated_profile.clone()
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidMerchantProfile {
+                reason: "legal_name must not be empty".to_string()
+            }
+        );
+
+        // updating the profile keeps the onboarding time
+        let mut update_env = mock_env();
+        update_env.block.time = onboarded_at.plus_seconds(7200);
+        assert_eq!(
+            set_merchant_profile(
+                deps.as_mut(),
+                &update_env,
+                &mock_info(member_manager, &[]),
+                merchant_2,
+                updated_profile.clone(),
+            )
+            .unwrap()
+            .attributes,
+            vec![
+                attr("action", "set_merchant_profile"),
+                attr("address", merchant_2),
+                attr("legal_name", "Merchant Two Ltd.")
+            ]
+        );
+
+        assert_eq!(
+            list_merchants(deps.as_ref(), None, None, None).unwrap(),
+            (
+                vec![
+                    MerchantInfo {
+                        address: Addr::unchecked(merchant_1),
+                        status: MerchantStatus::Active,
+                        profile: Some(profile),
+                        onboarded_at: Some(onboarded_at),
+                    },
+                    MerchantInfo {
+                        address: Addr::unchecked(merchant_2),
+                        status: MerchantStatus::Active,
+                        profile: Some(updated_profile),
+                        onboarded_at: Some(later_env.block.time),
+                    },
+                ],
+                None
+            )
+        );
+
+        // removing merchant removes its profile
+        remove_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant_1,
+        )
+        .unwrap();
+        assert_eq!(
+            get_merchant_profile(deps.as_ref(), &Addr::unchecked(merchant_1)).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_batch_merchants() {
+        let governor = "osmo1governor";
+        let member_manager = "osmo1membermanager";
+        let merchant_1 = "osmo1merchant1";
+        let merchant_2 = "osmo1merchant2";
+        let deposit_address_1 = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+        let deposit_address_2 = "bc1q35rayrk92pvwamwm4n2hsd3epez2g2tqcqa0fx";
+
+        let setup = || {
+            let mut deps = mock_dependencies();
+            governor::initialize_governor(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info("creator", &[]),
+                governor,
+            )
+            .unwrap();
+            member_manager::set_member_manager(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(governor, &[]),
+                member_manager,
+            )
+            .unwrap();
+            permission::grant_permission(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(governor, &[]),
+                Permission::DepositSetCustodian,
+                member_manager,
+            )
+            .unwrap();
+            deps
+        };
+
+        let new_merchant = |address: &str, deposit_address: Option<&str>| NewMerchant {
+            address: address.to_string(),
+            profile: None,
+            custodian_deposit_address: deposit_address.map(String::from),
+        };
+
+        // only member manager can add merchants
+        let mut deps = setup();
+        let err = add_merchants(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            vec![new_merchant(merchant_1, None)],
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // setting custodian deposit addresses requires the `deposit.set_custodian` permission
+        let mut deps = setup();
+        permission::revoke_permission(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            Permission::DepositSetCustodian,
+            member_manager,
+        )
+        .unwrap();
+        let err = add_merchants(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            vec![
+                new_merchant(merchant_1, None),
+                new_merchant(merchant_2, Some(deposit_address_2)),
+            ],
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::BatchFailed {
+                errors: vec![BatchItemError {
+                    address: merchant_2.to_string(),
+                    error: ContractError::Unauthorized {}.to_string(),
+                }]
+            }
+        );
+
+        // errors of all failing merchants are reported
+        let mut deps = setup();
+        let err = add_merchants(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            vec![
+                new_merchant(merchant_1, Some(deposit_address_1)),
+                new_merchant(governor, None),
+                new_merchant(merchant_2, Some(deposit_address_1)),
+            ],
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::BatchFailed {
+                errors: vec![
+                    BatchItemError {
+                        address: governor.to_string(),
+                        error: ContractError::AlreadyHasPriviledgedRole {
+                            address: governor.to_string()
+                        }
+                        .to_string(),
+                    },
+                    BatchItemError {
+                        address: merchant_2.to_string(),
+                        error: ContractError::DepositAddressAlreadyAssociated {
+                            address: deposit_address_1.to_string()
+                        }
+                        .to_string(),
+                    },
+                ]
+            }
+        );
+
+        let mut deps = setup();
+        assert_eq!(
+            add_merchants(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(member_manager, &[]),
+                vec![
+                    new_merchant(merchant_1, Some(deposit_address_1)),
+                    new_merchant(merchant_2, Some(deposit_address_2)),
+                ],
+            )
+            .unwrap()
+            .attributes,
+            vec![
+                attr("action", "add_merchants"),
+                attr("address", merchant_1),
+                attr("custodian_deposit_address", deposit_address_1),
+                attr("address", merchant_2),
+                attr("custodian_deposit_address", deposit_address_2),
+            ]
+        );
+        assert_eq!(
+            list_merchant_addresses(deps.as_ref(), None, None),
+            vec![Addr::unchecked(merchant_1), Addr::unchecked(merchant_2)]
+        );
+        assert_eq!(
+            get_custodian_deposit_address(deps.as_ref(), &Addr::unchecked(merchant_2)).unwrap(),
+            deposit_address_2
+        );
+
+        // errors of all failing addresses are reported
+        let err = remove_merchants(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            vec![governor.to_string(), merchant_1.to_string()],
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::BatchFailed {
+                errors: vec![BatchItemError {
+                    address: governor.to_string(),
+                    error: ContractError::NotAMerchant {
+                        address: governor.to_string()
+                    }
+                    .to_string(),
+                }]
+            }
+        );
+
+        let mut deps = setup();
+        add_merchants(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            vec![
+                new_merchant(merchant_1, Some(deposit_address_1)),
+                new_merchant(merchant_2, None),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            remove_merchants(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(member_manager, &[]),
+                vec![merchant_1.to_string(), merchant_2.to_string()],
+            )
+            .unwrap()
+            .attributes,
+            vec![
+                attr("action", "remove_merchants"),
+                attr("address", merchant_1),
+                attr("address", merchant_2),
+            ]
+        );
+        assert!(list_merchant_addresses(deps.as_ref(), None, None).is_empty());
+    }
+
+    fn list_merchant_addresses(
+        deps: Deps,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> Vec<Addr> {
+        list_merchants(deps, start_after, limit, None)
+            .unwrap()
+            .0
+            .into_iter()
+            .map(|merchant| merchant.address)
+            .collect()
+    }
 }
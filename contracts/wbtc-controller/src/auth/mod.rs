@@ -1,39 +1,126 @@
-use cosmwasm_std::{ensure, Addr, Deps};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ensure, Addr, Deps, Env, StdResult};
 
 use crate::ContractError;
 
+pub mod compliance;
 pub mod custodian;
 pub mod governor;
+pub mod history;
+pub mod holder;
 pub mod member_manager;
 pub mod merchant;
+pub mod operator;
+pub mod permission;
 
-#[derive(Clone, Copy)]
+#[cw_serde]
+#[derive(Copy)]
 pub enum Role {
     Governor,
+    GovernorCandidate,
     MemberManager,
+    MemberManagerCandidate,
     Merchant,
+    /// Operator authorized by a merchant to act on its behalf.
+    MerchantOperator,
     Custodian,
+    CustodianCandidate,
+    ComplianceOfficer,
 }
 
-pub fn allow_only(roles: &[Role], address: &Addr, deps: Deps) -> Result<(), ContractError> {
+impl Role {
+    const ALL: [Role; 9] = [
+        Role::Governor,
+        Role::GovernorCandidate,
+        Role::MemberManager,
+        Role::MemberManagerCandidate,
+        Role::Merchant,
+        Role::MerchantOperator,
+        Role::Custodian,
+        Role::CustodianCandidate,
+        Role::ComplianceOfficer,
+    ];
+}
+
+/// Check if the address holds the role.
+/// The candidate of an expired governorship transfer no longer holds the governor candidate role.
+pub fn has_role(deps: Deps, env: &Env, role: Role, address: &Addr) -> StdResult<bool> {
+    match role {
+        Role::Governor => governor::is_governor(deps, address),
+        Role::GovernorCandidate => governor::is_governor_candidate(deps, env, address),
+        Role::MemberManager => member_manager::is_member_manager(deps, address),
+        Role::MemberManagerCandidate => member_manager::is_member_manager_candidate(deps, address),
+        Role::Merchant => merchant::is_merchant(deps, address),
+        Role::MerchantOperator => operator::is_operator(deps, address),
+        Role::Custodian => custodian::is_custodian(deps, address),
+        Role::CustodianCandidate => custodian::is_custodian_candidate(deps, address),
+        Role::ComplianceOfficer => compliance::is_compliance_officer(deps, address),
+    }
+}
+
+/// List all roles the address holds.
+pub fn roles_of(deps: Deps, env: &Env, address: &Addr) -> StdResult<Vec<Role>> {
+    let mut roles = vec![];
+    for role in Role::ALL {
+        if has_role(deps, env, role, address)? {
+            roles.push(role);
+        }
+    }
+    Ok(roles)
+}
+
+/// Ensure that the address holds all of the roles, an empty list of roles is never satisfied.
+/// Since an address holds at most one priviledged role, this is only satisfiable with a single priviledged role.
+pub fn require_all(
+    roles: &[Role],
+    address: &Addr,
+    deps: Deps,
+    env: &Env,
+) -> Result<(), ContractError> {
+    ensure!(!roles.is_empty(), ContractError::Unauthorized {});
     for role in roles {
-        let is_authorized = match role {
-            Role::Governor => governor::is_governor(deps, address)?,
-            Role::MemberManager => member_manager::is_member_manager(deps, address)?,
-            Role::Merchant => merchant::is_merchant(deps, address)?,
-            Role::Custodian => custodian::is_custodian(deps, address)?,
-        };
-        ensure!(is_authorized, ContractError::Unauthorized {});
+        ensure!(
+            has_role(deps, env, *role, address)?,
+            ContractError::Unauthorized {}
+        );
     }
     Ok(())
 }
 
-/// ensure that the address is not a priviledge address, used in context of adding a new priviledge address
-fn has_no_priviledged_role(deps: Deps, address: &Addr) -> Result<(), ContractError> {
-    let is_previledged_address = governor::is_governor(deps, address)?
-        || member_manager::is_member_manager(deps, address)?
-        || merchant::is_merchant(deps, address)?
-        || custodian::is_custodian(deps, address)?;
+/// Ensure that the address holds at least one of the roles.
+pub fn require_any(
+    roles: &[Role],
+    address: &Addr,
+    deps: Deps,
+    env: &Env,
+) -> Result<(), ContractError> {
+    for role in roles {
+        if has_role(deps, env, *role, address)? {
+            return Ok(());
+        }
+    }
+    Err(ContractError::Unauthorized {})
+}
+
+/// ensure that the address is not a priviledge address, used in context of adding a new priviledge address.
+/// Role candidates are priviledged as well, so that a candidate cannot hold another role once it claims its role.
+fn has_no_priviledged_role(deps: Deps, env: &Env, address: &Addr) -> Result<(), ContractError> {
+    has_no_priviledged_role_other_than(deps, env, address, &[])
+}
+
+/// ensure that the address holds no priviledged role other than the given ones,
+/// used in context of a candidate claiming its role.
+/// An address explicitly granted a permission is priviledged as well.
+fn has_no_priviledged_role_other_than(
+    deps: Deps,
+    env: &Env,
+    address: &Addr,
+    ignored_roles: &[Role],
+) -> Result<(), ContractError> {
+    let is_previledged_address = roles_of(deps, env, address)?
+        .into_iter()
+        .any(|role| !ignored_roles.contains(&role))
+        || permission::is_grantee(deps, address)?;
 
     if is_previledged_address {
         Err(ContractError::AlreadyHasPriviledgedRole {
@@ -45,10 +132,10 @@ fn has_no_priviledged_role(deps: Deps, address: &Addr) -> Result<(), ContractErr
 }
 
 #[cfg(test)]
-mod tests {
+pub mod tests {
     use super::*;
     use cosmwasm_std::{
-        testing::{mock_dependencies, mock_info},
+        testing::{mock_dependencies, mock_env, mock_info},
         DepsMut,
     };
     use itertools::Itertools;
@@ -58,114 +145,198 @@ mod tests {
     const MERCHANT_ADDRESS: &str = "osmo1merchant";
     const CUSTODIAN_ADDRESS: &str = "osmo1custodian";
     const NON_GOVERNOR_ADDRESS: &str = "osmo1nongovernor";
-    const NON_MEMBER_MANAGER_ADDRESS: &str = "osmo1nonmembermanager";
     const NON_MERCHANT_ADDRESS: &str = "osmo1nonmerchant";
-    const NON_CUSTODIAN_ADDRESS: &str = "osmo1noncustodian";
 
-    #[test]
-    fn test_allow_only() {
-        let mut deps = mock_dependencies();
+    /// Set up a distinct address for each role, returned along with the role it holds.
+    pub fn setup_all_roles(mut deps: DepsMut) -> Vec<(Role, Addr)> {
+        let env = mock_env();
+        let governor = mock_info(GOVERNOR_ADDRESS, &[]);
+        let member_manager = mock_info(MEMBER_MANAGER_ADDRESS, &[]);
 
-        // initialize governor
-        governor::initialize_governor(deps.as_mut(), GOVERNOR_ADDRESS).unwrap();
-
-        // initialize member manager
+        governor::initialize_governor(
+            deps.branch(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            GOVERNOR_ADDRESS,
+        )
+        .unwrap();
+        governor::transfer_governorship(deps.branch(), &env, &governor, "osmo1governorcandidate")
+            .unwrap();
         member_manager::set_member_manager(
-            deps.as_mut(),
-            &mock_info(GOVERNOR_ADDRESS, &[]),
+            deps.branch(),
+            &mock_env(),
+            &governor,
             MEMBER_MANAGER_ADDRESS,
         )
         .unwrap();
-
-        // initialize merchant
+        member_manager::propose_member_manager(
+            deps.branch(),
+            &mock_env(),
+            &governor,
+            "osmo1membermanagercandidate",
+        )
+        .unwrap();
         merchant::add_merchant(
-            deps.as_mut(),
-            &mock_info(MEMBER_MANAGER_ADDRESS, &[]),
+            deps.branch(),
+            &mock_env(),
+            &member_manager,
             MERCHANT_ADDRESS,
+            None,
+        )
+        .unwrap();
+        operator::set_operator(
+            deps.branch(),
+            &env,
+            &mock_info(MERCHANT_ADDRESS, &[]),
+            "osmo1operator",
+            vec![operator::OperatorPermission::Burn],
+            None,
         )
         .unwrap();
-
-        // initialize custodian
         custodian::set_custodian(
-            deps.as_mut(),
-            &mock_info(MEMBER_MANAGER_ADDRESS, &[]),
+            deps.branch(),
+            &mock_env(),
+            &member_manager,
             CUSTODIAN_ADDRESS,
         )
         .unwrap();
-
-        // no error when address has the role
-        allow_only(
-            &[Role::Governor],
-            &Addr::unchecked(GOVERNOR_ADDRESS),
-            deps.as_ref(),
+        custodian::propose_custodian(
+            deps.branch(),
+            &mock_env(),
+            &member_manager,
+            "osmo1custodiancandidate",
         )
         .unwrap();
-
-        allow_only(
-            &[Role::MemberManager],
-            &Addr::unchecked(MEMBER_MANAGER_ADDRESS),
-            deps.as_ref(),
+        compliance::set_compliance_officer(
+            deps.branch(),
+            &mock_env(),
+            &governor,
+            "osmo1complianceofficer",
         )
         .unwrap();
 
-        allow_only(
-            &[Role::Merchant],
-            &Addr::unchecked(MERCHANT_ADDRESS),
-            deps.as_ref(),
-        )
-        .unwrap();
+        vec![
+            (Role::Governor, Addr::unchecked(GOVERNOR_ADDRESS)),
+            (
+                Role::GovernorCandidate,
+                Addr::unchecked("osmo1governorcandidate"),
+            ),
+            (Role::MemberManager, Addr::unchecked(MEMBER_MANAGER_ADDRESS)),
+            (
+                Role::MemberManagerCandidate,
+                Addr::unchecked("osmo1membermanagercandidate"),
+            ),
+            (Role::Merchant, Addr::unchecked(MERCHANT_ADDRESS)),
+            (Role::MerchantOperator, Addr::unchecked("osmo1operator")),
+            (Role::Custodian, Addr::unchecked(CUSTODIAN_ADDRESS)),
+            (
+                Role::CustodianCandidate,
+                Addr::unchecked("osmo1custodiancandidate"),
+            ),
+            (
+                Role::ComplianceOfficer,
+                Addr::unchecked("osmo1complianceofficer"),
+            ),
+        ]
+    }
 
-        allow_only(
-            &[Role::Custodian],
-            &Addr::unchecked(CUSTODIAN_ADDRESS),
-            deps.as_ref(),
-        )
-        .unwrap();
+    #[test]
+    fn test_require_all() {
+        let mut deps = mock_dependencies();
+        let holders = setup_all_roles(deps.as_mut());
 
-        // error unauthorized when address does not have the role
-        let err = allow_only(
-            &[Role::Governor],
-            &Addr::unchecked(NON_GOVERNOR_ADDRESS),
-            deps.as_ref(),
-        )
-        .unwrap_err();
-        assert_eq!(err, ContractError::Unauthorized {});
+        // every address holds only its own role
+        for (role, address) in &holders {
+            assert_eq!(
+                roles_of(deps.as_ref(), &mock_env(), address).unwrap(),
+                vec![*role]
+            );
+        }
 
-        let err = allow_only(
-            &[Role::MemberManager],
-            &Addr::unchecked(NON_MEMBER_MANAGER_ADDRESS),
-            deps.as_ref(),
-        )
-        .unwrap_err();
-        assert_eq!(err, ContractError::Unauthorized {});
+        for roles in Role::ALL.into_iter().powerset() {
+            for (role, address) in &holders {
+                // only satisfied by the single role the address holds
+                let res = require_all(&roles, address, deps.as_ref(), &mock_env());
+                if roles == vec![*role] {
+                    res.unwrap();
+                } else {
+                    assert_eq!(res.unwrap_err(), ContractError::Unauthorized {});
+                }
+            }
 
-        let err = allow_only(
-            &[Role::Merchant],
-            &Addr::unchecked(NON_MERCHANT_ADDRESS),
-            deps.as_ref(),
-        )
-        .unwrap_err();
-        assert_eq!(err, ContractError::Unauthorized {});
+            let err = require_all(
+                &roles,
+                &Addr::unchecked(NON_GOVERNOR_ADDRESS),
+                deps.as_ref(),
+                &mock_env(),
+            )
+            .unwrap_err();
+            assert_eq!(err, ContractError::Unauthorized {});
+        }
+    }
 
-        let err = allow_only(
-            &[Role::Custodian],
-            &Addr::unchecked(NON_CUSTODIAN_ADDRESS),
-            deps.as_ref(),
-        )
-        .unwrap_err();
+    #[test]
+    fn test_require_any() {
+        let mut deps = mock_dependencies();
+        let holders = setup_all_roles(deps.as_mut());
+
+        for roles in Role::ALL.into_iter().powerset() {
+            for (role, address) in &holders {
+                // satisfied as long as the roles include the one the address holds
+                let res = require_any(&roles, address, deps.as_ref(), &mock_env());
+                if roles.contains(role) {
+                    res.unwrap();
+                } else {
+                    assert_eq!(res.unwrap_err(), ContractError::Unauthorized {});
+                }
+            }
 
-        assert_eq!(err, ContractError::Unauthorized {});
+            let err = require_any(
+                &roles,
+                &Addr::unchecked(NON_GOVERNOR_ADDRESS),
+                deps.as_ref(),
+                &mock_env(),
+            )
+            .unwrap_err();
+            assert_eq!(err, ContractError::Unauthorized {});
+        }
 
-        // error unauthorized when address does not have any of the roles
-        let err = allow_only(
-            &[Role::Governor, Role::Merchant],
+        // e.g. pausing minting is allowed for either the governor or the custodian
+        let roles = [Role::Governor, Role::Custodian];
+        require_any(
+            &roles,
             &Addr::unchecked(GOVERNOR_ADDRESS),
             deps.as_ref(),
+            &mock_env(),
+        )
+        .unwrap();
+        require_any(
+            &roles,
+            &Addr::unchecked(CUSTODIAN_ADDRESS),
+            deps.as_ref(),
+            &mock_env(),
+        )
+        .unwrap();
+        assert_eq!(
+            require_any(
+                &roles,
+                &Addr::unchecked(MERCHANT_ADDRESS),
+                deps.as_ref(),
+                &mock_env()
+            )
+            .unwrap_err(),
+            ContractError::Unauthorized {}
+        );
+        assert_eq!(
+            require_all(
+                &roles,
+                &Addr::unchecked(GOVERNOR_ADDRESS),
+                deps.as_ref(),
+                &mock_env()
+            )
+            .unwrap_err(),
+            ContractError::Unauthorized {}
         );
-
-        assert_eq!(err.unwrap_err().to_string(), "Unauthorized");
-
-        // no error when address has all of the roles
     }
 
     #[test]
@@ -173,65 +344,113 @@ mod tests {
         let mut deps = mock_dependencies();
 
         // governor
-        has_no_priviledged_role(deps.as_ref(), &Addr::unchecked(GOVERNOR_ADDRESS)).unwrap();
+        has_no_priviledged_role(
+            deps.as_ref(),
+            &mock_env(),
+            &Addr::unchecked(GOVERNOR_ADDRESS),
+        )
+        .unwrap();
 
-        governor::initialize_governor(deps.as_mut(), GOVERNOR_ADDRESS).unwrap();
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            GOVERNOR_ADDRESS,
+        )
+        .unwrap();
 
         assert_eq!(
-            has_no_priviledged_role(deps.as_ref(), &Addr::unchecked(GOVERNOR_ADDRESS)).unwrap_err(),
+            has_no_priviledged_role(
+                deps.as_ref(),
+                &mock_env(),
+                &Addr::unchecked(GOVERNOR_ADDRESS)
+            )
+            .unwrap_err(),
             ContractError::AlreadyHasPriviledgedRole {
                 address: GOVERNOR_ADDRESS.to_string()
             }
         );
 
         // member manager
-        has_no_priviledged_role(deps.as_ref(), &Addr::unchecked(MEMBER_MANAGER_ADDRESS)).unwrap();
+        has_no_priviledged_role(
+            deps.as_ref(),
+            &mock_env(),
+            &Addr::unchecked(MEMBER_MANAGER_ADDRESS),
+        )
+        .unwrap();
 
         member_manager::set_member_manager(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(GOVERNOR_ADDRESS, &[]),
             MEMBER_MANAGER_ADDRESS,
         )
         .unwrap();
 
         assert_eq!(
-            has_no_priviledged_role(deps.as_ref(), &Addr::unchecked(MEMBER_MANAGER_ADDRESS))
-                .unwrap_err(),
+            has_no_priviledged_role(
+                deps.as_ref(),
+                &mock_env(),
+                &Addr::unchecked(MEMBER_MANAGER_ADDRESS)
+            )
+            .unwrap_err(),
             ContractError::AlreadyHasPriviledgedRole {
                 address: MEMBER_MANAGER_ADDRESS.to_string()
             }
         );
 
         // merchant
-        has_no_priviledged_role(deps.as_ref(), &Addr::unchecked(MERCHANT_ADDRESS)).unwrap();
+        has_no_priviledged_role(
+            deps.as_ref(),
+            &mock_env(),
+            &Addr::unchecked(MERCHANT_ADDRESS),
+        )
+        .unwrap();
 
         merchant::add_merchant(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(MEMBER_MANAGER_ADDRESS, &[]),
             MERCHANT_ADDRESS,
+            None,
         )
         .unwrap();
 
         assert_eq!(
-            has_no_priviledged_role(deps.as_ref(), &Addr::unchecked(MERCHANT_ADDRESS)).unwrap_err(),
+            has_no_priviledged_role(
+                deps.as_ref(),
+                &mock_env(),
+                &Addr::unchecked(MERCHANT_ADDRESS)
+            )
+            .unwrap_err(),
             ContractError::AlreadyHasPriviledgedRole {
                 address: MERCHANT_ADDRESS.to_string()
             }
         );
 
         // custodian
-        has_no_priviledged_role(deps.as_ref(), &Addr::unchecked(CUSTODIAN_ADDRESS)).unwrap();
+        has_no_priviledged_role(
+            deps.as_ref(),
+            &mock_env(),
+            &Addr::unchecked(CUSTODIAN_ADDRESS),
+        )
+        .unwrap();
 
         custodian::set_custodian(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(MEMBER_MANAGER_ADDRESS, &[]),
             CUSTODIAN_ADDRESS,
         )
         .unwrap();
 
         assert_eq!(
-            has_no_priviledged_role(deps.as_ref(), &Addr::unchecked(CUSTODIAN_ADDRESS))
-                .unwrap_err(),
+            has_no_priviledged_role(
+                deps.as_ref(),
+                &mock_env(),
+                &Addr::unchecked(CUSTODIAN_ADDRESS)
+            )
+            .unwrap_err(),
             ContractError::AlreadyHasPriviledgedRole {
                 address: CUSTODIAN_ADDRESS.to_string()
             }
@@ -248,22 +467,38 @@ mod tests {
         type SetterFn = fn(DepsMut, &str) -> Result<(), ContractError>;
 
         fn _initialize_governor(deps: DepsMut, address: &str) -> Result<(), ContractError> {
-            governor::initialize_governor(deps, address)?;
+            governor::initialize_governor(deps, &mock_env(), &mock_info("creator", &[]), address)?;
             Ok(())
         }
 
         fn _set_member_manager(deps: DepsMut, address: &str) -> Result<(), ContractError> {
-            member_manager::set_member_manager(deps, &mock_info(GOVERNOR_ADDRESS, &[]), address)?;
+            member_manager::set_member_manager(
+                deps,
+                &mock_env(),
+                &mock_info(GOVERNOR_ADDRESS, &[]),
+                address,
+            )?;
             Ok(())
         }
 
         fn _add_merchant(deps: DepsMut, address: &str) -> Result<(), ContractError> {
-            merchant::add_merchant(deps, &mock_info(MEMBER_MANAGER_ADDRESS, &[]), address)?;
+            merchant::add_merchant(
+                deps,
+                &mock_env(),
+                &mock_info(MEMBER_MANAGER_ADDRESS, &[]),
+                address,
+                None,
+            )?;
             Ok(())
         }
 
         fn _set_custodian(deps: DepsMut, address: &str) -> Result<(), ContractError> {
-            custodian::set_custodian(deps, &mock_info(MEMBER_MANAGER_ADDRESS, &[]), address)?;
+            custodian::set_custodian(
+                deps,
+                &mock_env(),
+                &mock_info(MEMBER_MANAGER_ADDRESS, &[]),
+                address,
+            )?;
             Ok(())
         }
 
@@ -284,6 +519,90 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_roles_of() {
+        let mut deps = mock_dependencies();
+        let candidate = "osmo1candidate";
+
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            GOVERNOR_ADDRESS,
+        )
+        .unwrap();
+        member_manager::set_member_manager(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(GOVERNOR_ADDRESS, &[]),
+            MEMBER_MANAGER_ADDRESS,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(MEMBER_MANAGER_ADDRESS, &[]),
+            MERCHANT_ADDRESS,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            roles_of(
+                deps.as_ref(),
+                &mock_env(),
+                &Addr::unchecked(GOVERNOR_ADDRESS)
+            )
+            .unwrap(),
+            vec![Role::Governor]
+        );
+        assert_eq!(
+            roles_of(
+                deps.as_ref(),
+                &mock_env(),
+                &Addr::unchecked(MERCHANT_ADDRESS)
+            )
+            .unwrap(),
+            vec![Role::Merchant]
+        );
+        assert_eq!(
+            roles_of(
+                deps.as_ref(),
+                &mock_env(),
+                &Addr::unchecked(NON_MERCHANT_ADDRESS)
+            )
+            .unwrap(),
+            vec![]
+        );
+
+        // candidate can not be assigned another role
+        governor::transfer_governorship(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(GOVERNOR_ADDRESS, &[]),
+            candidate,
+        )
+        .unwrap();
+        assert_eq!(
+            roles_of(deps.as_ref(), &mock_env(), &Addr::unchecked(candidate)).unwrap(),
+            vec![Role::GovernorCandidate]
+        );
+        assert_eq!(
+            has_no_priviledged_role(deps.as_ref(), &mock_env(), &Addr::unchecked(candidate))
+                .unwrap_err(),
+            ContractError::AlreadyHasPriviledgedRole {
+                address: candidate.to_string()
+            }
+        );
+        has_no_priviledged_role_other_than(
+            deps.as_ref(),
+            &mock_env(),
+            &Addr::unchecked(candidate),
+            &[Role::GovernorCandidate],
+        )
+        .unwrap();
+    }
+
     fn pair_permutation_iterator<T, U>(v1: Vec<T>, v2: Vec<U>) -> impl Iterator<Item = (T, U)>
     where
         T: Clone,
@@ -0,0 +1,704 @@
+/// `operator` module provides functionality for merchants to authorize operator addresses
+/// to act on their behalf, so that the merchant's own key can be kept in cold storage.
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    attr, ensure, Addr, Attribute, Deps, DepsMut, Env, MessageInfo, Order, Response, StdError,
+    StdResult, Timestamp,
+};
+use cw_storage_plus::Bound;
+
+use crate::{
+    attrs::action_attrs,
+    constants::{DEFAULT_LIMIT, MAX_LIMIT},
+    state::auth::{MERCHANT_OPERATORS, OPERATOR_MERCHANT},
+    ContractError,
+};
+
+use super::{
+    has_no_priviledged_role_other_than, has_role,
+    history::{self, RoleChange},
+    require_any, Role,
+};
+
+/// Action an operator can be permitted to perform on behalf of the merchant.
+#[cw_serde]
+#[derive(Copy)]
+pub enum OperatorPermission {
+    IssueMintRequest,
+    CancelMintRequest,
+    Burn,
+    SetDepositAddress,
+}
+
+#[cw_serde]
+pub struct OperatorGrant {
+    pub permissions: Vec<OperatorPermission>,
+    /// Grant can no longer be used from this time. `None` if it never expires.
+    pub expires_at: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct OperatorInfo {
+    pub address: Addr,
+    pub merchant: Addr,
+    pub permissions: Vec<OperatorPermission>,
+    pub expires_at: Option<Timestamp>,
+}
+
+/// Authorize or update an operator of the sender merchant.
+/// An operator can only act on behalf of a single merchant and cannot hold any other priviledged role.
+pub fn set_operator(
+    mut deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    operator: &str,
+    permissions: Vec<OperatorPermission>,
+    expires_at: Option<Timestamp>,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::Merchant], &info.sender, deps.as_ref(), env)?;
+
+    let operator = deps.api.addr_validate(operator)?;
+    let merchant = info.sender.clone();
+
+    // operator of the same merchant can be updated
+    has_no_priviledged_role_other_than(deps.as_ref(), env, &operator, &[Role::MerchantOperator])?;
+    ensure!(
+        OPERATOR_MERCHANT
+            .may_load(deps.storage, operator.clone())?
+            .map(|operator_merchant| operator_merchant == merchant)
+            .unwrap_or(true),
+        ContractError::AlreadyHasPriviledgedRole {
+            address: operator.to_string()
+        }
+    );
+
+    ensure!(
+        !permissions.is_empty(),
+        ContractError::EmptyOperatorPermissions {}
+    );
+    if let Some(expires_at) = expires_at {
+        ensure!(
+            expires_at > env.block.time,
+            ContractError::InvalidOperatorExpiry { expires_at }
+        );
+    }
+
+    let mut permissions = permissions;
+    permissions.sort_by_key(|permission| *permission as u8);
+    permissions.dedup();
+
+    let mut attrs = vec![
+        attr("merchant", merchant.as_str()),
+        attr("operator", operator.as_str()),
+    ];
+    attrs.extend(
+        permissions
+            .iter()
+            .map(|permission| attr("permission", format!("{permission:?}"))),
+    );
+    if let Some(expires_at) = expires_at {
+        attrs.push(attr("expires_at", expires_at.to_string()));
+    }
+
+    // updating the grant of an existing operator is not a role change
+    if !is_operator(deps.as_ref(), &operator)? {
+        history::record_role_change(
+            deps.branch(),
+            env,
+            Role::MerchantOperator,
+            &operator,
+            RoleChange::Granted,
+            Some(&merchant),
+        )?;
+    }
+
+    OPERATOR_MERCHANT.save(deps.storage, operator.clone(), &merchant)?;
+    MERCHANT_OPERATORS.save(
+        deps.storage,
+        (merchant, operator),
+        &OperatorGrant {
+            permissions,
+            expires_at,
+        },
+    )?;
+
+    Ok(Response::new().add_attributes(action_attrs("set_operator", attrs)))
+}
+
+/// Revoke an operator of the sender merchant.
+pub fn remove_operator(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    operator: &str,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::Merchant], &info.sender, deps.as_ref(), env)?;
+
+    let operator = deps.api.addr_validate(operator)?;
+    let merchant = info.sender.clone();
+
+    ensure!(
+        MERCHANT_OPERATORS.has(deps.storage, (merchant.clone(), operator.clone())),
+        ContractError::NotAnOperator {
+            address: operator.to_string()
+        }
+    );
+
+    OPERATOR_MERCHANT.remove(deps.storage, operator.clone());
+    MERCHANT_OPERATORS.remove(deps.storage, (merchant.clone(), operator.clone()));
+    history::record_role_change(
+        deps,
+        env,
+        Role::MerchantOperator,
+        &operator,
+        RoleChange::Revoked,
+        Some(&merchant),
+    )?;
+
+    let attrs = action_attrs(
+        "remove_operator",
+        vec![attr("merchant", merchant), attr("operator", operator)],
+    );
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Remove all operators of the merchant, used when the merchant is removed by the sender.
+pub fn clear_operators(
+    mut deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    merchant: &Addr,
+) -> StdResult<()> {
+    let operators = MERCHANT_OPERATORS
+        .prefix(merchant.clone())
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for operator in operators {
+        OPERATOR_MERCHANT.remove(deps.storage, operator.clone());
+        MERCHANT_OPERATORS.remove(deps.storage, (merchant.clone(), operator.clone()));
+        history::record_role_change(
+            deps.branch(),
+            env,
+            Role::MerchantOperator,
+            &operator,
+            RoleChange::Revoked,
+            Some(&info.sender),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Ensure that the sender is either a merchant or an unexpired operator permitted to perform the action,
+/// returns the merchant the sender is acting on behalf of.
+pub fn allow_merchant_or_operator(
+    permission: OperatorPermission,
+    sender: &Addr,
+    env: &Env,
+    deps: Deps,
+) -> Result<Addr, ContractError> {
+    if has_role(deps, env, Role::Merchant, sender)? {
+        return Ok(sender.clone());
+    }
+
+    let operator = may_get_operator(deps, sender)?.ok_or(ContractError::Unauthorized {})?;
+
+    if let Some(expires_at) = operator.expires_at {
+        ensure!(
+            env.block.time < expires_at,
+            ContractError::OperatorExpired {
+                address: sender.to_string(),
+                expires_at
+            }
+        );
+    }
+    ensure!(
+        operator.permissions.contains(&permission),
+        ContractError::Unauthorized {}
+    );
+
+    Ok(operator.merchant)
+}
+
+/// Attributes recording the operator, empty if the merchant acted by itself.
+pub fn operator_attrs(sender: &Addr, merchant: &Addr) -> Vec<Attribute> {
+    if sender == merchant {
+        vec![]
+    } else {
+        vec![attr("operator", sender.as_str())]
+    }
+}
+
+/// Check if the given address is an operator of any merchant, including expired ones.
+pub fn is_operator(deps: Deps, address: &Addr) -> StdResult<bool> {
+    Ok(OPERATOR_MERCHANT.has(deps.storage, address.clone()))
+}
+
+/// Get the operator and its grant if the address is an operator.
+pub fn may_get_operator(deps: Deps, address: &Addr) -> StdResult<Option<OperatorInfo>> {
+    let merchant = match OPERATOR_MERCHANT.may_load(deps.storage, address.clone())? {
+        Some(merchant) => merchant,
+        None => return Ok(None),
+    };
+
+    let grant = MERCHANT_OPERATORS.load(deps.storage, (merchant.clone(), address.clone()))?;
+
+    Ok(Some(OperatorInfo {
+        address: address.clone(),
+        merchant,
+        permissions: grant.permissions,
+        expires_at: grant.expires_at,
+    }))
+}
+
+/// Get the operator and its grant.
+pub fn get_operator(deps: Deps, address: &Addr) -> StdResult<OperatorInfo> {
+    may_get_operator(deps, address)?.ok_or_else(|| StdError::not_found("Operator"))
+}
+
+/// List operators of the merchant with pagination, including expired ones.
+pub fn list_operators(
+    deps: Deps,
+    merchant: &Addr,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<OperatorInfo>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after_bound = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?
+        .map(Bound::exclusive);
+
+    MERCHANT_OPERATORS
+        .prefix(merchant.clone())
+        .range(deps.storage, start_after_bound, None, Order::Ascending)
+        .take(limit)
+        .map(|operator| {
+            let (address, grant) = operator?;
+            Ok(OperatorInfo {
+                address,
+                merchant: merchant.clone(),
+                permissions: grant.permissions,
+                expires_at: grant.expires_at,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Coin, CosmosMsg, Uint128,
+    };
+    use osmosis_std::types::osmosis::tokenfactory::v1beta1::MsgBurn;
+
+    use crate::{
+        auth::{custodian, governor, member_manager, merchant},
+        tokenfactory::{burn, deposit_address, mint, token},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_manage_operator() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let governor = "osmo1governor";
+        let member_manager = "osmo1membermanager";
+        let merchant_1 = "osmo1merchant1";
+        let merchant_2 = "osmo1merchant2";
+        let operator_1 = "osmo1operator1";
+        let operator_2 = "osmo1operator2";
+
+        // setup
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
+        member_manager::set_member_manager(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            member_manager,
+        )
+        .unwrap();
+        for merchant in [merchant_1, merchant_2] {
+            merchant::add_merchant(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(member_manager, &[]),
+                merchant,
+                None,
+            )
+            .unwrap();
+        }
+
+        let permissions = vec![
+            OperatorPermission::Burn,
+            OperatorPermission::IssueMintRequest,
+            OperatorPermission::Burn,
+        ];
+        let expires_at = env.block.time.plus_seconds(3600);
+
+        // only merchant can set operator
+        let err = set_operator(
+            deps.as_mut(),
+            &env,
+            &mock_info(member_manager, &[]),
+            operator_1,
+            permissions.clone(),
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // priviledged address can not be an operator
+        let err = set_operator(
+            deps.as_mut(),
+            &env,
+            &mock_info(merchant_1, &[]),
+            merchant_2,
+            permissions.clone(),
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::AlreadyHasPriviledgedRole {
+                address: merchant_2.to_string()
+            }
+        );
+
+        // operator must have permissions
+        let err = set_operator(
+            deps.as_mut(),
+            &env,
+            &mock_info(merchant_1, &[]),
+            operator_1,
+            vec![],
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::EmptyOperatorPermissions {});
+
+        // expiry must be in the future
+        let err = set_operator(
+            deps.as_mut(),
+            &env,
+            &mock_info(merchant_1, &[]),
+            operator_1,
+            permissions.clone(),
+            Some(env.block.time),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidOperatorExpiry {
+                expires_at: env.block.time
+            }
+        );
+
+        assert_eq!(
+            set_operator(
+                deps.as_mut(),
+                &env,
+                &mock_info(merchant_1, &[]),
+                operator_1,
+                permissions,
+                Some(expires_at),
+            )
+            .unwrap()
+            .attributes,
+            vec![
+                attr("action", "set_operator"),
+                attr("merchant", merchant_1),
+                attr("operator", operator_1),
+                attr("permission", "IssueMintRequest"),
+                attr("permission", "Burn"),
+                attr("expires_at", expires_at.to_string()),
+            ]
+        );
+        set_operator(
+            deps.as_mut(),
+            &env,
+            &mock_info(merchant_1, &[]),
+            operator_2,
+            vec![OperatorPermission::SetDepositAddress],
+            None,
+        )
+        .unwrap();
+
+        let operator_1_info = OperatorInfo {
+            address: Addr::unchecked(operator_1),
+            merchant: Addr::unchecked(merchant_1),
+            permissions: vec![
+                OperatorPermission::IssueMintRequest,
+                OperatorPermission::Burn,
+            ],
+            expires_at: Some(expires_at),
+        };
+        assert_eq!(
+            get_operator(deps.as_ref(), &Addr::unchecked(operator_1)).unwrap(),
+            operator_1_info
+        );
+        assert!(has_role(
+            deps.as_ref(),
+            &mock_env(),
+            Role::MerchantOperator,
+            &Addr::unchecked(operator_1)
+        )
+        .unwrap());
+
+        // operator can act on behalf of a single merchant only
+        let err = set_operator(
+            deps.as_mut(),
+            &env,
+            &mock_info(merchant_2, &[]),
+            operator_1,
+            vec![OperatorPermission::Burn],
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::AlreadyHasPriviledgedRole {
+                address: operator_1.to_string()
+            }
+        );
+
+        // operator can not become merchant
+        let err = merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            operator_1,
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::AlreadyHasPriviledgedRole {
+                address: operator_1.to_string()
+            }
+        );
+
+        assert_eq!(
+            list_operators(deps.as_ref(), &Addr::unchecked(merchant_1), None, None)
+                .unwrap()
+                .into_iter()
+                .map(|operator| operator.address)
+                .collect::<Vec<_>>(),
+            vec![Addr::unchecked(operator_1), Addr::unchecked(operator_2)]
+        );
+        assert_eq!(
+            list_operators(
+                deps.as_ref(),
+                &Addr::unchecked(merchant_1),
+                Some(operator_1.to_string()),
+                None
+            )
+            .unwrap()
+            .len(),
+            1
+        );
+
+        // only operator of the sender merchant can be removed
+        let err = remove_operator(deps.as_mut(), &env, &mock_info(merchant_2, &[]), operator_1)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::NotAnOperator {
+                address: operator_1.to_string()
+            }
+        );
+
+        remove_operator(deps.as_mut(), &env, &mock_info(merchant_1, &[]), operator_1).unwrap();
+        assert_eq!(
+            get_operator(deps.as_ref(), &Addr::unchecked(operator_1)).unwrap_err(),
+            StdError::not_found("Operator")
+        );
+
+        // removing merchant revokes its operators
+        merchant::remove_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant_1,
+        )
+        .unwrap();
+        assert!(!is_operator(deps.as_ref(), &Addr::unchecked(operator_2)).unwrap());
+        assert_eq!(
+            list_operators(deps.as_ref(), &Addr::unchecked(merchant_1), None, None).unwrap(),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_operator_acts_on_behalf_of_merchant() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let governor = "osmo1governor";
+        let member_manager = "osmo1membermanager";
+        let custodian = "osmo1custodian";
+        let merchant = "osmo1merchant";
+        let operator = "osmo1operator";
+        let contract = env.contract.address.clone();
+        let denom = format!("factory/{}/wbtc", contract);
+
+        // setup
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
+        member_manager::set_member_manager(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            member_manager,
+        )
+        .unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant,
+            None,
+        )
+        .unwrap();
+        deposit_address::set_custodian_deposit_address(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(custodian, &[]),
+            merchant,
+            Some("bc1qzmylp874rg2st6pdlt8yjga3ek9pr96wuzelun"),
+        )
+        .unwrap();
+        token::set_token_denom(deps.as_mut().storage, &denom).unwrap();
+
+        let expires_at = env.block.time.plus_seconds(3600);
+        set_operator(
+            deps.as_mut(),
+            &env,
+            &mock_info(merchant, &[]),
+            operator,
+            vec![
+                OperatorPermission::IssueMintRequest,
+                OperatorPermission::SetDepositAddress,
+                OperatorPermission::Burn,
+            ],
+            Some(expires_at),
+        )
+        .unwrap();
+
+        // operator sets the merchant's deposit address
+        deposit_address::set_merchant_deposit_address(
+            deps.as_mut(),
+            &env,
+            &mock_info(operator, &[]),
+            Some("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq"),
+        )
+        .unwrap();
+        assert_eq!(
+            deposit_address::get_merchant_deposit_address(
+                deps.as_ref(),
+                &Addr::unchecked(merchant)
+            )
+            .unwrap(),
+            "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq"
+        );
+
+        // mint request issued by operator belongs to the merchant
+        let res = mint::issue_mint_request(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(operator, &[]),
+            Uint128::new(100_000_000),
+            "44e25bc0ed840f9bf0e58d6227db15192d5b89e79ba4304da16b09703f68ceaf".to_string(),
+        )
+        .unwrap();
+        assert!(res.attributes.contains(&attr("operator", operator)));
+        let request_hash = res
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "request_hash")
+            .unwrap()
+            .value
+            .clone();
+        assert_eq!(
+            mint::get_mint_request_by_hash(deps.as_ref(), &request_hash)
+                .unwrap()
+                .requester,
+            Addr::unchecked(merchant)
+        );
+
+        // operator is not permitted to cancel mint request
+        let err = mint::cancel_mint_request(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(operator, &[]),
+            request_hash.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // operator burns from the merchant's balance
+        let amount = Uint128::new(100_000_000);
+        let res = burn::burn(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(operator, &[]),
+            amount,
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages
+                .into_iter()
+                .map(|msg| msg.msg)
+                .collect::<Vec<_>>(),
+            vec![<CosmosMsg>::from(MsgBurn {
+                sender: contract.to_string(),
+                amount: Some(Coin::new(amount.u128(), denom).into()),
+                burn_from_address: merchant.to_string(),
+            })]
+        );
+
+        // expired operator can not act on behalf of the merchant
+        let mut expired_env = env.clone();
+        expired_env.block.time = expires_at;
+        let err = burn::burn(
+            deps.as_mut(),
+            expired_env,
+            mock_info(operator, &[]),
+            amount,
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::OperatorExpired {
+                address: operator.to_string(),
+                expires_at
+            }
+        );
+
+        // merchant can still act by itself
+        mint::cancel_mint_request(deps.as_mut(), env, mock_info(merchant, &[]), request_hash)
+            .unwrap();
+    }
+}
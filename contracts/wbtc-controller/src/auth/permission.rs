@@ -0,0 +1,819 @@
+/// `permission` module provides a registry of named permissions granted to addresses,
+/// so that the powers of a role can be split across separate keys.
+/// Each permission is granted by default to the holder of a role, which the governor can disable.
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    attr, ensure, Addr, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult,
+};
+use cw_storage_plus::Bound;
+
+use crate::{
+    attrs::action_attrs,
+    constants::{DEFAULT_LIMIT, MAX_LIMIT},
+    state::auth::{DISABLED_DEFAULT_GRANTS, PERMISSION_GRANTS},
+    ContractError,
+};
+
+use super::{
+    compliance, custodian, governor, has_role,
+    history::{self, RoleChange},
+    require_any, Role,
+};
+
+#[cw_serde]
+#[derive(Copy)]
+pub enum Permission {
+    #[serde(rename = "mint.approve")]
+    MintApprove,
+    #[serde(rename = "mint.reject")]
+    MintReject,
+    #[serde(rename = "burn.confirm")]
+    BurnConfirm,
+    /// Set the min, max and per merchant burn amounts.
+    #[serde(rename = "burn.set_limits")]
+    BurnSetLimits,
+    /// Set the burn mode and the burn confirmation tolerance.
+    #[serde(rename = "burn.configure")]
+    BurnConfigure,
+    #[serde(rename = "deposit.set_custodian")]
+    DepositSetCustodian,
+    /// Pause and unpause token transfer.
+    #[serde(rename = "token.pause")]
+    TokenPause,
+    #[serde(rename = "token.set_metadata")]
+    TokenSetMetadata,
+    #[serde(rename = "redemption.set_timeout")]
+    RedemptionSetTimeout,
+    /// Freeze and unfreeze accounts.
+    #[serde(rename = "enforcement.freeze")]
+    EnforcementFreeze,
+    #[serde(rename = "enforcement.force_transfer")]
+    EnforcementForceTransfer,
+    /// Set the transfer limits and their exemptions.
+    #[serde(rename = "transfer_limit.configure")]
+    TransferLimitConfigure,
+    /// Enable and disable the transfer allowlist mode.
+    #[serde(rename = "transfer_allowlist.configure")]
+    TransferAllowlistConfigure,
+    /// Add and remove addresses from the transfer allowlist.
+    #[serde(rename = "transfer_allowlist.manage")]
+    TransferAllowlistManage,
+}
+
+impl Permission {
+    pub const ALL: [Permission; 14] = [
+        Permission::MintApprove,
+        Permission::MintReject,
+        Permission::BurnConfirm,
+        Permission::BurnSetLimits,
+        Permission::BurnConfigure,
+        Permission::DepositSetCustodian,
+        Permission::TokenPause,
+        Permission::TokenSetMetadata,
+        Permission::RedemptionSetTimeout,
+        Permission::EnforcementFreeze,
+        Permission::EnforcementForceTransfer,
+        Permission::TransferLimitConfigure,
+        Permission::TransferAllowlistConfigure,
+        Permission::TransferAllowlistManage,
+    ];
+
+    /// Name of the permission, also used as its storage key.
+    pub fn key(self) -> &'static str {
+        match self {
+            Permission::MintApprove => "mint.approve",
+            Permission::MintReject => "mint.reject",
+            Permission::BurnConfirm => "burn.confirm",
+            Permission::BurnSetLimits => "burn.set_limits",
+            Permission::BurnConfigure => "burn.configure",
+            Permission::DepositSetCustodian => "deposit.set_custodian",
+            Permission::TokenPause => "token.pause",
+            Permission::TokenSetMetadata => "token.set_metadata",
+            Permission::RedemptionSetTimeout => "redemption.set_timeout",
+            Permission::EnforcementFreeze => "enforcement.freeze",
+            Permission::EnforcementForceTransfer => "enforcement.force_transfer",
+            Permission::TransferLimitConfigure => "transfer_limit.configure",
+            Permission::TransferAllowlistConfigure => "transfer_allowlist.configure",
+            Permission::TransferAllowlistManage => "transfer_allowlist.manage",
+        }
+    }
+
+    /// Role which holder is granted the permission unless the default grant is disabled.
+    pub fn default_role(self) -> Role {
+        match self {
+            Permission::MintApprove
+            | Permission::MintReject
+            | Permission::BurnConfirm
+            | Permission::BurnSetLimits
+            | Permission::DepositSetCustodian => Role::Custodian,
+            Permission::BurnConfigure
+            | Permission::TokenPause
+            | Permission::TokenSetMetadata
+            | Permission::RedemptionSetTimeout
+            | Permission::EnforcementFreeze
+            | Permission::EnforcementForceTransfer
+            | Permission::TransferLimitConfigure
+            | Permission::TransferAllowlistConfigure => Role::Governor,
+            Permission::TransferAllowlistManage => Role::ComplianceOfficer,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct PermissionGrants {
+    pub permission: Permission,
+    /// Role which holder is granted the permission, `None` if the default grant is disabled.
+    pub default_role: Option<Role>,
+    pub grantees: Vec<Addr>,
+}
+
+/// Grant the permission to the address in addition to its default role. Only the governor can grant permissions.
+/// Merchants, operators and role candidates cannot be granted permissions, neither can the governor be granted custodian permissions.
+pub fn grant_permission(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    permission: Permission,
+    address: &str,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::Governor], &info.sender, deps.as_ref(), env)?;
+
+    let address = deps.api.addr_validate(address)?;
+    has_no_conflicting_role(deps.as_ref(), env, permission, &address)?;
+
+    let key = (permission.key(), address.clone());
+    if !PERMISSION_GRANTS.has(deps.storage, key.clone()) {
+        PERMISSION_GRANTS.save(deps.storage, key, &())?;
+        history::record_permission_change(
+            deps,
+            env,
+            permission,
+            &address,
+            RoleChange::Granted,
+            &info.sender,
+        )?;
+    }
+
+    let attrs = action_attrs(
+        "grant_permission",
+        vec![
+            attr("permission", permission.key()),
+            attr("address", address),
+        ],
+    );
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// ensure that the grantee holds no role conflicting with the permission.
+fn has_no_conflicting_role(
+    deps: Deps,
+    env: &Env,
+    permission: Permission,
+    address: &Addr,
+) -> Result<(), ContractError> {
+    let mut conflicting_roles = vec![
+        Role::Merchant,
+        Role::MerchantOperator,
+        Role::GovernorCandidate,
+        Role::MemberManagerCandidate,
+        Role::CustodianCandidate,
+    ];
+    if permission.default_role() == Role::Custodian {
+        conflicting_roles.push(Role::Governor);
+    }
+
+    for role in conflicting_roles {
+        ensure!(
+            !has_role(deps, env, role, address)?,
+            ContractError::AlreadyHasPriviledgedRole {
+                address: address.to_string(),
+            }
+        );
+    }
+    Ok(())
+}
+
+/// Revoke the permission granted to the address. Only the governor can revoke permissions.
+/// This does not affect the default grant, see `set_default_permission_grant`.
+pub fn revoke_permission(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    permission: Permission,
+    address: &str,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::Governor], &info.sender, deps.as_ref(), env)?;
+
+    let address = deps.api.addr_validate(address)?;
+
+    let key = (permission.key(), address.clone());
+    if PERMISSION_GRANTS.has(deps.storage, key.clone()) {
+        PERMISSION_GRANTS.remove(deps.storage, key);
+        history::record_permission_change(
+            deps,
+            env,
+            permission,
+            &address,
+            RoleChange::Revoked,
+            &info.sender,
+        )?;
+    }
+
+    let attrs = action_attrs(
+        "revoke_permission",
+        vec![
+            attr("permission", permission.key()),
+            attr("address", address),
+        ],
+    );
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Enable or disable granting the permission to the holder of its default role,
+/// e.g. to move a custodian power to a separate key. Only the governor can change default grants.
+/// The change is recorded in the role history against the current holder of the default role,
+/// unless it is also explicitly granted the permission.
+pub fn set_default_permission_grant(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    permission: Permission,
+    enabled: bool,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::Governor], &info.sender, deps.as_ref(), env)?;
+
+    let was_enabled = !DISABLED_DEFAULT_GRANTS.has(deps.storage, permission.key());
+    if enabled {
+        DISABLED_DEFAULT_GRANTS.remove(deps.storage, permission.key());
+    } else {
+        DISABLED_DEFAULT_GRANTS.save(deps.storage, permission.key(), &())?;
+    }
+
+    if was_enabled != enabled {
+        if let Some(holder) = default_role_holder(deps.as_ref(), permission)? {
+            if !PERMISSION_GRANTS.has(deps.storage, (permission.key(), holder.clone())) {
+                let change = if enabled {
+                    RoleChange::Granted
+                } else {
+                    RoleChange::Revoked
+                };
+                history::record_permission_change(
+                    deps,
+                    env,
+                    permission,
+                    &holder,
+                    change,
+                    &info.sender,
+                )?;
+            }
+        }
+    }
+
+    let attrs = action_attrs(
+        "set_default_permission_grant",
+        vec![
+            attr("permission", permission.key()),
+            attr("enabled", enabled.to_string()),
+        ],
+    );
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Current holder of the default role of the permission, if any.
+fn default_role_holder(deps: Deps, permission: Permission) -> StdResult<Option<Addr>> {
+    match permission.default_role() {
+        Role::Governor => governor::get_governor(deps).map(Some),
+        Role::Custodian => custodian::may_get_custodian(deps),
+        Role::ComplianceOfficer => compliance::may_get_compliance_officer(deps),
+        _ => Ok(None),
+    }
+}
+
+/// Check if the address has been granted the permission, either explicitly or through its default role.
+pub fn has_permission(
+    deps: Deps,
+    env: &Env,
+    permission: Permission,
+    address: &Addr,
+) -> StdResult<bool> {
+    if PERMISSION_GRANTS
+        .may_load(deps.storage, (permission.key(), address.clone()))?
+        .is_some()
+    {
+        return Ok(true);
+    }
+
+    if DISABLED_DEFAULT_GRANTS
+        .may_load(deps.storage, permission.key())?
+        .is_some()
+    {
+        return Ok(false);
+    }
+
+    has_role(deps, env, permission.default_role(), address)
+}
+
+/// Check if the address has been explicitly granted any permission.
+pub fn is_grantee(deps: Deps, address: &Addr) -> StdResult<bool> {
+    for permission in Permission::ALL {
+        if PERMISSION_GRANTS
+            .may_load(deps.storage, (permission.key(), address.clone()))?
+            .is_some()
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+pub fn allow_permission(
+    permission: Permission,
+    address: &Addr,
+    deps: Deps,
+    env: &Env,
+) -> Result<(), ContractError> {
+    ensure!(
+        has_permission(deps, env, permission, address)?,
+        ContractError::Unauthorized {}
+    );
+    Ok(())
+}
+
+/// List the addresses explicitly granted the permission along with its default role.
+pub fn list_permission_grants(
+    deps: Deps,
+    permission: Permission,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<PermissionGrants> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?
+        .map(Bound::exclusive);
+
+    let grantees = PERMISSION_GRANTS
+        .prefix(permission.key())
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let default_role = if DISABLED_DEFAULT_GRANTS
+        .may_load(deps.storage, permission.key())?
+        .is_some()
+    {
+        None
+    } else {
+        Some(permission.default_role())
+    };
+
+    Ok(PermissionGrants {
+        permission,
+        default_role,
+        grantees,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        from_binary,
+        testing::{mock_dependencies, mock_env, mock_info},
+        to_binary,
+    };
+
+    use crate::auth::{
+        custodian, governor, history::RoleHistoryEntry, member_manager, merchant, operator,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_permission_names() {
+        for permission in Permission::ALL {
+            assert_eq!(
+                to_binary(&permission).unwrap().to_vec(),
+                format!("\"{}\"", permission.key()).into_bytes()
+            );
+            assert_eq!(
+                from_binary::<Permission>(&to_binary(&permission.key()).unwrap()).unwrap(),
+                permission
+            );
+        }
+    }
+
+    #[test]
+    fn test_split_custodian_permissions() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let governor = "osmo1governor";
+        let member_manager = "osmo1membermanager";
+        let custodian = "osmo1custodian";
+        let approver = "osmo1approver";
+
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
+        member_manager::initialize_member_manager(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            member_manager,
+        )
+        .unwrap();
+        custodian::initialize_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            custodian,
+        )
+        .unwrap();
+
+        let custodian_addr = Addr::unchecked(custodian);
+        let approver_addr = Addr::unchecked(approver);
+
+        // current roles are default grants
+        assert!(has_permission(
+            deps.as_ref(),
+            &mock_env(),
+            Permission::MintApprove,
+            &custodian_addr
+        )
+        .unwrap());
+        assert!(!has_permission(
+            deps.as_ref(),
+            &mock_env(),
+            Permission::MintApprove,
+            &approver_addr
+        )
+        .unwrap());
+        assert!(has_permission(
+            deps.as_ref(),
+            &mock_env(),
+            Permission::TokenPause,
+            &Addr::unchecked(governor)
+        )
+        .unwrap());
+
+        // only the governor can administer permissions
+        let err = grant_permission(
+            deps.as_mut(),
+            &env,
+            &mock_info(custodian, &[]),
+            Permission::MintApprove,
+            approver,
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        let err = set_default_permission_grant(
+            deps.as_mut(),
+            &env,
+            &mock_info(custodian, &[]),
+            Permission::MintApprove,
+            false,
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // move approving mint requests to a separate key
+        let res = grant_permission(
+            deps.as_mut(),
+            &env,
+            &mock_info(governor, &[]),
+            Permission::MintApprove,
+            approver,
+        )
+        .unwrap();
+        assert_eq!(
+            res.attributes,
+            vec![
+                attr("action", "grant_permission"),
+                attr("permission", "mint.approve"),
+                attr("address", approver),
+            ]
+        );
+
+        let res = set_default_permission_grant(
+            deps.as_mut(),
+            &env,
+            &mock_info(governor, &[]),
+            Permission::MintApprove,
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            res.attributes,
+            vec![
+                attr("action", "set_default_permission_grant"),
+                attr("permission", "mint.approve"),
+                attr("enabled", "false"),
+            ]
+        );
+
+        assert!(has_permission(
+            deps.as_ref(),
+            &mock_env(),
+            Permission::MintApprove,
+            &approver_addr
+        )
+        .unwrap());
+        assert!(!has_permission(
+            deps.as_ref(),
+            &mock_env(),
+            Permission::MintApprove,
+            &custodian_addr
+        )
+        .unwrap());
+        assert_eq!(
+            allow_permission(
+                Permission::MintApprove,
+                &custodian_addr,
+                deps.as_ref(),
+                &mock_env()
+            )
+            .unwrap_err(),
+            ContractError::Unauthorized {}
+        );
+
+        // other custodian permissions are unaffected
+        assert!(has_permission(
+            deps.as_ref(),
+            &mock_env(),
+            Permission::MintReject,
+            &custodian_addr
+        )
+        .unwrap());
+        assert!(!has_permission(
+            deps.as_ref(),
+            &mock_env(),
+            Permission::MintReject,
+            &approver_addr
+        )
+        .unwrap());
+
+        assert_eq!(
+            list_permission_grants(deps.as_ref(), Permission::MintApprove, None, None).unwrap(),
+            PermissionGrants {
+                permission: Permission::MintApprove,
+                default_role: None,
+                grantees: vec![approver_addr.clone()],
+            }
+        );
+        assert_eq!(
+            list_permission_grants(
+                deps.as_ref(),
+                Permission::MintApprove,
+                Some(approver.to_string()),
+                None
+            )
+            .unwrap()
+            .grantees,
+            vec![] as Vec<Addr>
+        );
+
+        // revoke and restore
+        revoke_permission(
+            deps.as_mut(),
+            &env,
+            &mock_info(governor, &[]),
+            Permission::MintApprove,
+            approver,
+        )
+        .unwrap();
+        set_default_permission_grant(
+            deps.as_mut(),
+            &env,
+            &mock_info(governor, &[]),
+            Permission::MintApprove,
+            true,
+        )
+        .unwrap();
+
+        assert!(!has_permission(
+            deps.as_ref(),
+            &mock_env(),
+            Permission::MintApprove,
+            &approver_addr
+        )
+        .unwrap());
+        assert!(has_permission(
+            deps.as_ref(),
+            &mock_env(),
+            Permission::MintApprove,
+            &custodian_addr
+        )
+        .unwrap());
+        assert_eq!(
+            list_permission_grants(deps.as_ref(), Permission::MintApprove, None, None).unwrap(),
+            PermissionGrants {
+                permission: Permission::MintApprove,
+                default_role: Some(Role::Custodian),
+                grantees: vec![],
+            }
+        );
+
+        // enabling the already enabled default grant changes nothing
+        set_default_permission_grant(
+            deps.as_mut(),
+            &env,
+            &mock_info(governor, &[]),
+            Permission::MintApprove,
+            true,
+        )
+        .unwrap();
+
+        // toggling the default grant is recorded against the custodian
+        let default_grant_change = |change| RoleHistoryEntry {
+            role: Role::Custodian,
+            address: custodian_addr.clone(),
+            change,
+            actor: Some(Addr::unchecked(governor)),
+            timestamp: env.block.time,
+            permission: Some(Permission::MintApprove),
+        };
+        assert_eq!(
+            history::list_role_history(
+                deps.as_ref(),
+                None,
+                Some(custodian.to_string()),
+                None,
+                None
+            )
+            .unwrap()
+            .into_iter()
+            .map(|item| item.entry)
+            .filter(|entry| entry.permission.is_some())
+            .collect::<Vec<_>>(),
+            vec![
+                default_grant_change(RoleChange::Revoked),
+                default_grant_change(RoleChange::Granted),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_grantee_role_separation() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let governor = "osmo1governor";
+        let member_manager = "osmo1membermanager";
+        let custodian = "osmo1custodian";
+        let merchant = "osmo1merchant";
+        let operator = "osmo1operator";
+        let approver = "osmo1approver";
+
+        governor::initialize_governor(deps.as_mut(), &env, &mock_info("creator", &[]), governor)
+            .unwrap();
+        member_manager::initialize_member_manager(
+            deps.as_mut(),
+            &env,
+            &mock_info("creator", &[]),
+            member_manager,
+        )
+        .unwrap();
+        custodian::initialize_custodian(deps.as_mut(), &env, &mock_info("creator", &[]), custodian)
+            .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &env,
+            &mock_info(member_manager, &[]),
+            merchant,
+            None,
+        )
+        .unwrap();
+        operator::set_operator(
+            deps.as_mut(),
+            &env,
+            &mock_info(merchant, &[]),
+            operator,
+            vec![operator::OperatorPermission::Burn],
+            None,
+        )
+        .unwrap();
+        custodian::propose_custodian(
+            deps.as_mut(),
+            &env,
+            &mock_info(member_manager, &[]),
+            "osmo1custodiancandidate",
+        )
+        .unwrap();
+
+        // merchants, operators and candidates cannot be granted permissions
+        for address in [merchant, operator, "osmo1custodiancandidate"] {
+            let err = grant_permission(
+                deps.as_mut(),
+                &env,
+                &mock_info(governor, &[]),
+                Permission::TokenPause,
+                address,
+            )
+            .unwrap_err();
+            assert_eq!(
+                err,
+                ContractError::AlreadyHasPriviledgedRole {
+                    address: address.to_string()
+                }
+            );
+        }
+
+        // the governor cannot be granted custodian permissions
+        let err = grant_permission(
+            deps.as_mut(),
+            &env,
+            &mock_info(governor, &[]),
+            Permission::MintApprove,
+            governor,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::AlreadyHasPriviledgedRole {
+                address: governor.to_string()
+            }
+        );
+
+        // but can be granted other permissions, as can the custodian
+        grant_permission(
+            deps.as_mut(),
+            &env,
+            &mock_info(governor, &[]),
+            Permission::TransferAllowlistManage,
+            governor,
+        )
+        .unwrap();
+        grant_permission(
+            deps.as_mut(),
+            &env,
+            &mock_info(governor, &[]),
+            Permission::TokenPause,
+            custodian,
+        )
+        .unwrap();
+
+        // a grantee cannot take a priviledged role afterwards
+        grant_permission(
+            deps.as_mut(),
+            &env,
+            &mock_info(governor, &[]),
+            Permission::MintApprove,
+            approver,
+        )
+        .unwrap();
+        let already_priviledged = ContractError::AlreadyHasPriviledgedRole {
+            address: approver.to_string(),
+        };
+
+        let err = merchant::add_merchant(
+            deps.as_mut(),
+            &env,
+            &mock_info(member_manager, &[]),
+            approver,
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(err, already_priviledged);
+
+        let err = operator::set_operator(
+            deps.as_mut(),
+            &env,
+            &mock_info(merchant, &[]),
+            approver,
+            vec![operator::OperatorPermission::Burn],
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(err, already_priviledged);
+
+        let err = governor::transfer_governorship(
+            deps.as_mut(),
+            &env,
+            &mock_info(governor, &[]),
+            approver,
+        )
+        .unwrap_err();
+        assert_eq!(err, already_priviledged);
+
+        // until the grant is revoked
+        revoke_permission(
+            deps.as_mut(),
+            &env,
+            &mock_info(governor, &[]),
+            Permission::MintApprove,
+            approver,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &env,
+            &mock_info(member_manager, &[]),
+            approver,
+            None,
+        )
+        .unwrap();
+    }
+}
@@ -3,3 +3,24 @@ pub const DEFAULT_LIMIT: u32 = 10;
 
 /// Max limit for pagination
 pub const MAX_LIMIT: u32 = 100;
+
+/// Default period in seconds after which a pending redemption request can be reclaimed by the holder
+pub const DEFAULT_REDEMPTION_TIMEOUT_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+/// Rolling window in seconds over which the per-merchant burn limit applies
+pub const BURN_LIMIT_WINDOW_SECONDS: u64 = 24 * 60 * 60;
+
+/// Granularity in seconds of the burned amount tracking for the per-merchant burn limit
+pub const BURN_LIMIT_BUCKET_SECONDS: u64 = 60 * 60;
+
+/// Delay in seconds between scheduling and completing a denom admin handover
+pub const DENOM_ADMIN_HANDOVER_DELAY_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+/// Period in seconds during which the governor candidate can claim the governorship
+pub const GOVERNORSHIP_TRANSFER_EXPIRY_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+/// Rolling window in seconds over which the transfer limits apply
+pub const TRANSFER_LIMIT_WINDOW_SECONDS: u64 = 24 * 60 * 60;
+
+/// Granularity in seconds of the sent amount tracking for the transfer limits
+pub const TRANSFER_LIMIT_BUCKET_SECONDS: u64 = 60 * 60;
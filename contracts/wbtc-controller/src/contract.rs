@@ -1,29 +1,51 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    ensure, to_binary, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Reply, Response,
-    StdError, StdResult, SubMsg,
-};
-use cw2::set_contract_version;
-use osmosis_std::types::osmosis::tokenfactory::v1beta1::{
-    MsgCreateDenom, MsgCreateDenomResponse, MsgSetBeforeSendHook,
+    attr, ensure, to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError,
+    StdResult, SubMsg,
 };
+use cw2::{get_contract_version, set_contract_version};
+use osmosis_std::types::osmosis::tokenfactory::v1beta1::{MsgCreateDenom, MsgCreateDenomResponse};
 
-use crate::auth::{custodian, governor, member_manager, merchant};
+use crate::auth::{
+    self, compliance, custodian, governor, history, holder, member_manager, merchant, operator,
+    permission, Role,
+};
+use crate::constants::{BURN_LIMIT_WINDOW_SECONDS, TRANSFER_LIMIT_WINDOW_SECONDS};
 use crate::error::{non_payable, ContractError};
 use crate::msg::{
-    ExecuteMsg, GetBurnRequestByHashResponse, GetBurnRequestByNonceResponse,
-    GetBurnRequestsCountResponse, GetCustodianDepositAddressResponse, GetCustodianResponse,
-    GetGovernorCandidateResponse, GetGovernorResponse, GetMemberManagerResponse,
-    GetMerchantDepositAddressResponse, GetMinBurnAmountResponse, GetMintRequestByHashResponse,
-    GetMintRequestByNonceResponse, GetMintRequestsCountResponse, GetTokenDenomResponse,
-    InstantiateMsg, IsCustodianResponse, IsGovernorCandidateResponse, IsGovernorResponse,
-    IsMemberManagerResponse, IsMerchantResponse, IsPausedResponse, ListBurnRequestsResponse,
-    ListMerchantsResponse, ListMintRequestsResponse, QueryMsg, SudoMsg,
+    ExecuteMsg, GetAddressRolesResponse, GetBurnConfirmationResponse,
+    GetBurnConfirmationToleranceResponse, GetBurnLimitsResponse, GetBurnModeResponse,
+    GetBurnRequestByHashResponse, GetBurnRequestByNonceResponse, GetBurnRequestsCountResponse,
+    GetComplianceOfficerResponse, GetConfigResponse, GetCustodianCandidateResponse,
+    GetCustodianDepositAddressResponse, GetCustodianResponse, GetDenomAdminHandoverResponse,
+    GetGovernorCandidateResponse, GetGovernorResponse, GetHolderCountResponse,
+    GetMemberManagerCandidateResponse, GetMemberManagerResponse, GetMerchantDepositAddressResponse,
+    GetMerchantResponse, GetMinBurnAmountResponse, GetMintRequestByHashResponse,
+    GetMintRequestByNonceResponse, GetMintRequestsCountResponse, GetOperatorResponse,
+    GetPendingDenomResponse, GetRedemptionRequestByHashResponse,
+    GetRedemptionRequestsCountResponse, GetRedemptionTimeoutResponse, GetRoleHolderKindsResponse,
+    GetRolesResponse, GetTokenDenomResponse, GetTransferLimitsResponse, HasPermissionResponse,
+    InstantiateMsg, IsComplianceOfficerResponse, IsCustodianCandidateResponse, IsCustodianResponse,
+    IsFrozenResponse, IsGovernorCandidateResponse, IsGovernorResponse,
+    IsMemberManagerCandidateResponse, IsMemberManagerResponse, IsMerchantResponse,
+    IsMintingPausedResponse, IsPausedResponse, IsPayoutAddressWhitelistEnforcedResponse,
+    IsRetiredResponse, IsTransferAllowlistEnabledResponse, IsTransferAllowlistedResponse,
+    ListBurnRequestsResponse, ListEnforcementRecordsResponse, ListFrozenAccountsResponse,
+    ListHoldersResponse, ListMerchantsResponse, ListMintRequestsResponse, ListOperatorsResponse,
+    ListPayoutAddressesResponse, ListPermissionGrantsResponse, ListRedemptionRequestsResponse,
+    ListRoleHistoryResponse, ListRoleHolderVotersResponse, ListTransferAllowlistResponse,
+    ListTransferLimitExemptionsResponse, MerchantRoleInfo, QueryMsg, SudoMsg,
 };
-use crate::tokenfactory::burn;
+use crate::tokenfactory::burn::{self, BurnConfirmation, BurnMode};
+use crate::tokenfactory::denom_admin;
+use crate::tokenfactory::enforcement::{self, FORCE_TRANSFER_REPLY_ID};
+use crate::tokenfactory::holders;
 use crate::tokenfactory::mint;
-use crate::tokenfactory::{deposit_address, token};
+use crate::tokenfactory::redemption;
+use crate::tokenfactory::{
+    deposit_address, payout_address, token, transfer_allowlist, transfer_limit,
+};
 
 // version info for migration info
 pub const CONTRACT_NAME: &str = "crates.io:wbtc-controller";
@@ -34,29 +56,73 @@ const CREATE_DENOM_REPLY_ID: u64 = 1;
 /// Handling contract instantiation
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
-    _info: MessageInfo,
+    info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    // Holder kinds must be declared before the roles are initialized
+    if msg.require_contract_holders.unwrap_or_default() {
+        holder::initialize_contract_holders_required(deps.branch())?;
+    }
+
     // Initialize the admin, no auth is required only at contract instantiation
-    governor::initialize_governor(deps, msg.governor.as_ref())?;
+    governor::initialize_governor(deps.branch(), &env, &info, msg.governor.as_ref())?;
 
-    // create denom
-    let msg_create_denom = SubMsg::reply_on_success(
-        MsgCreateDenom {
-            sender: env.contract.address.to_string(),
-            subdenom: msg.subdenom,
-        },
-        CREATE_DENOM_REPLY_ID,
-    );
+    // Initialize the optional roles, subject to the same priviledged role rules as their setters
+    let mut role_attrs = vec![];
+    if let Some(member_manager) = msg.member_manager {
+        member_manager::initialize_member_manager(deps.branch(), &env, &info, &member_manager)?;
+        role_attrs.push(attr("member_manager", member_manager));
+    }
+    if let Some(custodian) = msg.custodian {
+        custodian::initialize_custodian(deps.branch(), &env, &info, &custodian)?;
+        role_attrs.push(attr("custodian", custodian));
+    }
+    if let Some(merchants) = msg.merchants {
+        merchant::initialize_merchants(deps.branch(), &env, &info, &merchants)?;
+        role_attrs.extend(
+            merchants
+                .into_iter()
+                .map(|merchant| attr("merchant", merchant)),
+        );
+    }
+
+    if let Some(min_burn_amount) = msg.min_burn_amount {
+        burn::initialize_min_burn_amount(deps.storage, min_burn_amount)?;
+    }
+
+    // denom metadata can only be set once the contract is the denom admin
+    if let Some(denom_metadata) = msg.denom_metadata {
+        token::initialize_denom_metadata(deps.storage, &denom_metadata)?;
+    }
+
+    let res = match msg.existing_denom {
+        // adopt existing denom once its admin has been changed to this contract
+        Some(existing_denom) => {
+            denom_admin::set_pending_denom(deps.storage, &existing_denom)?;
+            Response::new().add_attribute("existing_denom", existing_denom)
+        }
+
+        // create denom
+        None => {
+            let msg_create_denom = SubMsg::reply_on_success(
+                MsgCreateDenom {
+                    sender: env.contract.address.to_string(),
+                    subdenom: msg.subdenom,
+                },
+                CREATE_DENOM_REPLY_ID,
+            );
+            Response::new().add_submessage(msg_create_denom)
+        }
+    };
 
-    Ok(Response::new()
-        .add_submessage(msg_create_denom)
+    Ok(res
         .add_attribute("action", "instantiate")
-        .add_attribute("governor", msg.governor))
+        .add_attribute("governor", msg.governor)
+        .add_attributes(role_attrs))
 }
 
 /// Handling contract execution
@@ -67,8 +133,33 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    // no execute message requires sending funds, reject all non-zero funds
-    non_payable(&info)?;
+    // only redemption request and burn in `AttachedFunds` mode require sending funds,
+    // reject all non-zero funds for other messages
+    let accepts_funds = match msg {
+        ExecuteMsg::RequestRedemption { .. } => true,
+        ExecuteMsg::Burn { .. } => burn::get_burn_mode(deps.as_ref())? == BurnMode::AttachedFunds,
+        _ => false,
+    };
+    if !accepts_funds {
+        non_payable(&info)?;
+    }
+
+    // messages that require the contract to be the denom admin are rejected once it is retired
+    let requires_denom_admin = matches!(
+        msg,
+        ExecuteMsg::IssueMintRequest { .. }
+            | ExecuteMsg::ApproveMintRequest { .. }
+            | ExecuteMsg::Burn { .. }
+            | ExecuteMsg::RequestRedemption { .. }
+            | ExecuteMsg::AcceptRedemption { .. }
+            | ExecuteMsg::SetDenomMetadata { .. }
+            | ExecuteMsg::ForceTransfer { .. }
+            | ExecuteMsg::HandoverDenomAdmin { .. }
+            | ExecuteMsg::CompleteDenomAdminHandover {}
+    );
+    if requires_denom_admin {
+        denom_admin::ensure_not_retired(deps.as_ref())?;
+    }
 
     match msg {
         // === mint ===
@@ -76,34 +167,148 @@ pub fn execute(
             mint::issue_mint_request(deps, env, info, amount, tx_id)
         }
         ExecuteMsg::CancelMintRequest { request_hash } => {
-            mint::cancel_mint_request(deps, info, request_hash)
+            mint::cancel_mint_request(deps, env, info, request_hash)
         }
         ExecuteMsg::ApproveMintRequest { request_hash } => {
-            mint::approve_mint_request(deps, info, env.contract.address, request_hash)
+            mint::approve_mint_request(deps, &env, info, env.contract.address.clone(), request_hash)
         }
         ExecuteMsg::RejectMintRequest { request_hash } => {
-            mint::reject_mint_request(deps, info, request_hash)
+            mint::reject_mint_request(deps, &env, info, request_hash)
         }
 
         // === burn ===
-        ExecuteMsg::Burn { amount } => burn::burn(deps, env, info, amount),
+        ExecuteMsg::Burn {
+            amount,
+            btc_address,
+        } => burn::burn(deps, env, info, amount, btc_address),
         ExecuteMsg::ConfirmBurnRequest {
             request_hash,
             tx_id,
-        } => burn::confirm_burn_request(deps, info, request_hash, tx_id),
-        ExecuteMsg::SetMinBurnAmount { amount } => burn::set_min_burn_amount(deps, &info, amount),
+            btc_amount_sent,
+            network_fee,
+            vout,
+        } => burn::confirm_burn_request(
+            deps,
+            &env,
+            info,
+            request_hash,
+            BurnConfirmation {
+                tx_id,
+                vout,
+                btc_amount_sent,
+                network_fee,
+            },
+        ),
+        ExecuteMsg::AddPayoutAddress { btc_address } => {
+            payout_address::add_payout_address(deps, &env, &info, &btc_address)
+        }
+        ExecuteMsg::RemovePayoutAddress { btc_address } => {
+            payout_address::remove_payout_address(deps, &env, &info, &btc_address)
+        }
+        ExecuteMsg::SetPayoutAddressWhitelistEnforced { enforced } => {
+            payout_address::set_payout_address_whitelist_enforced(deps, &env, &info, enforced)
+        }
+        ExecuteMsg::SetMinBurnAmount { amount } => {
+            burn::set_min_burn_amount(deps, &env, &info, amount)
+        }
+        ExecuteMsg::SetMaxBurnAmount { amount } => {
+            burn::set_max_burn_amount(deps, &env, &info, amount)
+        }
+        ExecuteMsg::SetMerchantBurnLimit { amount } => {
+            burn::set_merchant_burn_limit(deps, &env, &info, amount)
+        }
+        ExecuteMsg::SetBurnMode { mode } => burn::set_burn_mode(deps, &env, &info, mode),
+        ExecuteMsg::SetBurnConfirmationTolerance { amount } => {
+            burn::set_burn_confirmation_tolerance(deps, &env, &info, amount)
+        }
+
+        // === redemption ===
+        ExecuteMsg::RequestRedemption {
+            merchant,
+            btc_address,
+        } => redemption::request_redemption(deps, env, info, merchant, btc_address),
+        ExecuteMsg::AcceptRedemption { request_hash } => {
+            redemption::accept_redemption(deps, env, info, request_hash)
+        }
+        ExecuteMsg::DeclineRedemption { request_hash } => {
+            redemption::decline_redemption(deps, &env, info, request_hash)
+        }
+        ExecuteMsg::ReclaimRedemption { request_hash } => {
+            redemption::reclaim_redemption(deps, env, info, request_hash)
+        }
+        ExecuteMsg::SetRedemptionTimeout { seconds } => {
+            redemption::set_redemption_timeout(deps, &env, &info, seconds)
+        }
 
         // === auth ===
         ExecuteMsg::TransferGovernorship {
             new_governor_address,
-        } => governor::transfer_governorship(deps, &info, &new_governor_address),
-        ExecuteMsg::ClaimGovernorship {} => governor::claim_governorship(deps, info),
+        } => governor::transfer_governorship(deps, &env, &info, &new_governor_address),
+        ExecuteMsg::ClaimGovernorship {} => governor::claim_governorship(deps, &env, info),
+        ExecuteMsg::CancelGovernorshipTransfer {} => {
+            governor::cancel_governorship_transfer(deps, &env, &info)
+        }
         ExecuteMsg::SetMemberManager { address } => {
-            member_manager::set_member_manager(deps, &info, &address)
+            member_manager::set_member_manager(deps, &env, &info, &address)
+        }
+        ExecuteMsg::ProposeMemberManager { address } => {
+            member_manager::propose_member_manager(deps, &env, &info, &address)
+        }
+        ExecuteMsg::ClaimMemberManager {} => {
+            member_manager::claim_member_manager(deps, &env, &info)
         }
-        ExecuteMsg::SetCustodian { address } => custodian::set_custodian(deps, &info, &address),
-        ExecuteMsg::AddMerchant { address } => merchant::add_merchant(deps, &info, &address),
-        ExecuteMsg::RemoveMerchant { address } => merchant::remove_merchant(deps, &info, &address),
+        ExecuteMsg::CancelMemberManagerProposal {} => {
+            member_manager::cancel_member_manager_proposal(deps, &env, &info)
+        }
+        ExecuteMsg::SetCustodian { address } => {
+            custodian::set_custodian(deps, &env, &info, &address)
+        }
+        ExecuteMsg::ProposeCustodian { address } => {
+            custodian::propose_custodian(deps, &env, &info, &address)
+        }
+        ExecuteMsg::ClaimCustodian {} => custodian::claim_custodian(deps, &env, &info),
+        ExecuteMsg::CancelCustodianProposal {} => {
+            custodian::cancel_custodian_proposal(deps, &env, &info)
+        }
+        ExecuteMsg::AddMerchant { address, profile } => {
+            merchant::add_merchant(deps, &env, &info, &address, profile)
+        }
+        ExecuteMsg::AddMerchants { merchants } => {
+            merchant::add_merchants(deps, &env, &info, merchants)
+        }
+        ExecuteMsg::RemoveMerchants { addresses } => {
+            merchant::remove_merchants(deps, &env, &info, addresses)
+        }
+        ExecuteMsg::SetMerchantProfile { address, profile } => {
+            merchant::set_merchant_profile(deps, &env, &info, &address, profile)
+        }
+        ExecuteMsg::RemoveMerchant { address } => {
+            merchant::remove_merchant(deps, &env, &info, &address)
+        }
+        ExecuteMsg::SuspendMerchant { address } => {
+            merchant::suspend_merchant(deps, &env, &info, &address)
+        }
+        ExecuteMsg::ReinstateMerchant { address } => {
+            merchant::reinstate_merchant(deps, &env, &info, &address)
+        }
+        ExecuteMsg::SetRoleHolderKind { role, kind } => {
+            holder::set_role_holder_kind(deps, &env, &info, role, kind)
+        }
+        ExecuteMsg::SetComplianceOfficer { address } => {
+            compliance::set_compliance_officer(deps, &env, &info, &address)
+        }
+        ExecuteMsg::GrantPermission {
+            permission,
+            address,
+        } => permission::grant_permission(deps, &env, &info, permission, &address),
+        ExecuteMsg::RevokePermission {
+            permission,
+            address,
+        } => permission::revoke_permission(deps, &env, &info, permission, &address),
+        ExecuteMsg::SetDefaultPermissionGrant {
+            permission,
+            enabled,
+        } => permission::set_default_permission_grant(deps, &env, &info, permission, enabled),
 
         // === deposit address ===
         ExecuteMsg::SetCustodianDepositAddress {
@@ -111,27 +316,97 @@ pub fn execute(
             deposit_address,
         } => deposit_address::set_custodian_deposit_address(
             deps,
+            &env,
             &info,
             merchant.as_str(),
             deposit_address.as_deref(),
         ),
         ExecuteMsg::SetMerchantDepositAddress { deposit_address } => {
-            deposit_address::set_merchant_deposit_address(deps, &info, deposit_address.as_deref())
+            deposit_address::set_merchant_deposit_address(
+                deps,
+                &env,
+                &info,
+                deposit_address.as_deref(),
+            )
+        }
+
+        ExecuteMsg::SetOperator {
+            operator,
+            permissions,
+            expires_at,
+        } => operator::set_operator(deps, &env, &info, &operator, permissions, expires_at),
+        ExecuteMsg::RemoveOperator { operator } => {
+            operator::remove_operator(deps, &env, &info, &operator)
         }
 
         ExecuteMsg::SetDenomMetadata { metadata } => {
             token::set_denom_metadata(deps.as_ref(), &env, &info, metadata)
         }
 
+        // === denom admin ===
+        ExecuteMsg::HandoverDenomAdmin { new_admin } => {
+            denom_admin::handover_denom_admin(deps, &env, &info, &new_admin)
+        }
+        ExecuteMsg::CompleteDenomAdminHandover {} => {
+            denom_admin::complete_denom_admin_handover(deps, &env, &info)
+        }
+        ExecuteMsg::CancelDenomAdminHandover {} => {
+            denom_admin::cancel_denom_admin_handover(deps, &env, &info)
+        }
+        ExecuteMsg::AcceptDenomAdmin {} => denom_admin::accept_denom_admin(deps, &env, &info),
+
         // === pausing ===
-        ExecuteMsg::Pause {} => token::pause(deps, &info),
-        ExecuteMsg::Unpause {} => token::unpause(deps, &info),
+        ExecuteMsg::Pause {} => token::pause(deps, &env, &info),
+        ExecuteMsg::Unpause {} => token::unpause(deps, &env, &info),
+        ExecuteMsg::PauseMinting {} => mint::pause_minting(deps, &env, &info),
+        ExecuteMsg::UnpauseMinting {} => mint::unpause_minting(deps, &env, &info),
+
+        // === transfer allowlist ===
+        ExecuteMsg::SetTransferAllowlistEnabled { enabled } => {
+            transfer_allowlist::set_transfer_allowlist_enabled(deps, &env, &info, enabled)
+        }
+        ExecuteMsg::AddToTransferAllowlist { addresses } => {
+            transfer_allowlist::add_to_transfer_allowlist(deps, &env, &info, addresses)
+        }
+        ExecuteMsg::RemoveFromTransferAllowlist { addresses } => {
+            transfer_allowlist::remove_from_transfer_allowlist(deps, &env, &info, addresses)
+        }
+
+        // === transfer limits ===
+        ExecuteMsg::SetAddressTransferLimit { amount } => {
+            transfer_limit::set_address_transfer_limit(deps, &env, &info, amount)
+        }
+        ExecuteMsg::SetGlobalTransferLimit { amount } => {
+            transfer_limit::set_global_transfer_limit(deps, &env, &info, amount)
+        }
+        ExecuteMsg::AddTransferLimitExemption { address } => {
+            transfer_limit::add_transfer_limit_exemption(deps, &env, &info, &address)
+        }
+        ExecuteMsg::RemoveTransferLimitExemption { address } => {
+            transfer_limit::remove_transfer_limit_exemption(deps, &env, &info, &address)
+        }
+
+        // === enforcement ===
+        ExecuteMsg::FreezeAccount {
+            account,
+            case_reference,
+        } => enforcement::freeze_account(deps, &env, &info, &account, case_reference),
+        ExecuteMsg::UnfreezeAccount {
+            account,
+            case_reference,
+        } => enforcement::unfreeze_account(deps, &env, &info, &account, case_reference),
+        ExecuteMsg::ForceTransfer {
+            from,
+            to,
+            amount,
+            case_reference,
+        } => enforcement::force_transfer(deps, &env, &info, &from, &to, amount, case_reference),
     }
 }
 
 /// Handling contract query
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         // === mint ===
         QueryMsg::GetMintRequestByNonce { nonce } => {
@@ -187,18 +462,184 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             amount: burn::get_min_burn_amount(deps)?,
         }),
 
+        QueryMsg::ListPayoutAddresses {
+            merchant,
+            limit,
+            start_after,
+        } => to_binary(&ListPayoutAddressesResponse {
+            addresses: payout_address::list_payout_addresses(
+                deps,
+                &deps.api.addr_validate(&merchant)?,
+                start_after,
+                limit,
+            )?,
+        }),
+
+        QueryMsg::IsPayoutAddressWhitelistEnforced { merchant } => {
+            to_binary(&IsPayoutAddressWhitelistEnforcedResponse {
+                is_enforced: payout_address::is_payout_address_whitelist_enforced(
+                    deps,
+                    &deps.api.addr_validate(&merchant)?,
+                )?,
+            })
+        }
+
+        QueryMsg::GetBurnLimits { merchant } => {
+            let merchant = deps.api.addr_validate(&merchant)?;
+            let merchant_burn_limit = burn::get_merchant_burn_limit(deps)?;
+            let burned_amount = burn::get_burned_amount(deps, &merchant, env.block.time)?;
+
+            to_binary(&GetBurnLimitsResponse {
+                min_burn_amount: burn::get_min_burn_amount(deps)?,
+                max_burn_amount: burn::get_max_burn_amount(deps)?,
+                merchant_burn_limit,
+                window_seconds: BURN_LIMIT_WINDOW_SECONDS,
+                burned_amount,
+                remaining_burn_amount: merchant_burn_limit
+                    .map(|limit| limit.saturating_sub(burned_amount)),
+            })
+        }
+
+        QueryMsg::GetBurnMode {} => to_binary(&GetBurnModeResponse {
+            mode: burn::get_burn_mode(deps)?,
+        }),
+
+        QueryMsg::GetBurnConfirmationTolerance {} => {
+            to_binary(&GetBurnConfirmationToleranceResponse {
+                amount: burn::get_burn_confirmation_tolerance(deps)?,
+            })
+        }
+
+        QueryMsg::GetBurnConfirmation { request_hash } => to_binary(&GetBurnConfirmationResponse {
+            confirmation: burn::get_burn_confirmation(deps, &request_hash)?,
+        }),
+
+        // === redemption ===
+        QueryMsg::GetRedemptionRequestByHash { request_hash } => {
+            to_binary(&GetRedemptionRequestByHashResponse {
+                request: redemption::get_redemption_request_by_hash(deps, &request_hash)?,
+            })
+        }
+        QueryMsg::GetRedemptionRequestsCount {} => to_binary(&GetRedemptionRequestsCountResponse {
+            count: redemption::get_redemption_request_count(deps)?,
+        }),
+        QueryMsg::ListRedemptionRequests {
+            limit,
+            start_after_nonce,
+            status,
+        } => to_binary(&ListRedemptionRequestsResponse {
+            requests: redemption::list_redemption_requests(deps, limit, start_after_nonce, status)?,
+        }),
+        QueryMsg::GetRedemptionTimeout {} => to_binary(&GetRedemptionTimeoutResponse {
+            seconds: redemption::get_redemption_timeout(deps)?,
+        }),
+
         // === token ===
         QueryMsg::GetTokenDenom {} => to_binary(&GetTokenDenomResponse {
             denom: token::get_token_denom(deps.storage)?,
         }),
+        QueryMsg::GetConfig {} => to_binary(&GetConfigResponse {
+            contract_version: get_contract_version(deps.storage)?,
+            token_denom: token::may_get_token_denom(deps.storage)?,
+            pending_denom: denom_admin::get_pending_denom(deps)?,
+            is_paused: token::is_paused(deps)?,
+            is_minting_paused: mint::is_minting_paused(deps)?,
+            is_retired: denom_admin::is_retired(deps)?,
+            min_burn_amount: burn::get_min_burn_amount(deps)?,
+            max_burn_amount: burn::get_max_burn_amount(deps)?,
+            merchant_burn_limit: burn::get_merchant_burn_limit(deps)?,
+            burn_mode: burn::get_burn_mode(deps)?,
+            burn_confirmation_tolerance: burn::get_burn_confirmation_tolerance(deps)?,
+            redemption_timeout_seconds: redemption::get_redemption_timeout(deps)?,
+            transfer_allowlist_enabled: transfer_allowlist::is_transfer_allowlist_enabled(deps)?,
+            address_transfer_limit: transfer_limit::get_address_transfer_limit(deps)?,
+            global_transfer_limit: transfer_limit::get_global_transfer_limit(deps)?,
+        }),
+        QueryMsg::GetAddressRoles { address } => {
+            let address = deps.api.addr_validate(&address)?;
+            let roles = auth::roles_of(deps, &env, &address)?;
+            let merchant =
+                if roles.contains(&Role::Merchant) {
+                    Some(MerchantRoleInfo {
+                        status: merchant::get_merchant_status(deps, &address)?,
+                        custodian_deposit_address:
+                            deposit_address::may_get_custodian_deposit_address(deps, &address)?,
+                        merchant_deposit_address:
+                            deposit_address::may_get_merchant_deposit_address(deps, &address)?,
+                    })
+                } else {
+                    None
+                };
+
+            to_binary(&GetAddressRolesResponse { roles, merchant })
+        }
+        QueryMsg::HasPermission {
+            permission,
+            address,
+        } => to_binary(&HasPermissionResponse {
+            has_permission: permission::has_permission(
+                deps,
+                &env,
+                permission,
+                &deps.api.addr_validate(&address)?,
+            )?,
+        }),
+        QueryMsg::ListPermissionGrants {
+            permission,
+            limit,
+            start_after,
+        } => to_binary(&ListPermissionGrantsResponse {
+            grants: permission::list_permission_grants(deps, permission, start_after, limit)?,
+        }),
+        QueryMsg::ListRoleHistory {
+            role,
+            address,
+            limit,
+            start_after,
+        } => to_binary(&ListRoleHistoryResponse {
+            entries: history::list_role_history(deps, role, address, start_after, limit)?,
+        }),
+        QueryMsg::GetRoleHolderKinds {} => to_binary(&GetRoleHolderKindsResponse {
+            contract_holders_required: holder::is_contract_holders_required(deps)?,
+            kinds: holder::list_role_holder_kinds(deps)?,
+        }),
+        QueryMsg::ListRoleHolderVoters {
+            role,
+            limit,
+            start_after,
+        } => to_binary(&ListRoleHolderVotersResponse {
+            voters: holder::list_role_holder_voters(deps, role, start_after, limit)?,
+        }),
+        QueryMsg::GetRoles { limit, start_after } => to_binary(&GetRolesResponse {
+            governor: governor::get_governor(deps)?,
+            governor_candidate: governor::get_governor_candidate(deps, &env)?,
+            member_manager: member_manager::may_get_member_manager(deps)?,
+            member_manager_candidate: member_manager::get_member_manager_candidate(deps)?,
+            custodian: custodian::may_get_custodian(deps)?,
+            custodian_candidate: custodian::get_custodian_candidate(deps)?,
+            compliance_officer: compliance::may_get_compliance_officer(deps)?,
+            merchants: merchant::list_merchant_addresses(deps, start_after, limit)?,
+        }),
 
         // === auth ===
         QueryMsg::IsMerchant { address } => to_binary(&IsMerchantResponse {
             is_merchant: merchant::is_merchant(deps, &deps.api.addr_validate(&address)?)?,
         }),
-        QueryMsg::ListMerchants { limit, start_after } => to_binary(&ListMerchantsResponse {
-            merchants: merchant::list_merchants(deps, start_after, limit)?,
+        QueryMsg::GetMerchant { address } => to_binary(&GetMerchantResponse {
+            merchant: merchant::get_merchant(deps, &deps.api.addr_validate(&address)?)?,
         }),
+        QueryMsg::ListMerchants {
+            limit,
+            start_after,
+            status,
+        } => {
+            let (merchants, next_start_after) =
+                merchant::list_merchants(deps, start_after, limit, status)?;
+            to_binary(&ListMerchantsResponse {
+                merchants,
+                next_start_after,
+            })
+        }
         QueryMsg::IsMemberManager { address } => to_binary(&IsMemberManagerResponse {
             is_member_manager: member_manager::is_member_manager(
                 deps,
@@ -214,163 +655,1015 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::GetCustodian {} => to_binary(&GetCustodianResponse {
             address: custodian::get_custodian(deps)?,
         }),
+        QueryMsg::IsComplianceOfficer { address } => to_binary(&IsComplianceOfficerResponse {
+            is_compliance_officer: compliance::is_compliance_officer(
+                deps,
+                &deps.api.addr_validate(&address)?,
+            )?,
+        }),
+        QueryMsg::GetComplianceOfficer {} => to_binary(&GetComplianceOfficerResponse {
+            address: compliance::get_compliance_officer(deps)?,
+        }),
         QueryMsg::GetGovernor {} => to_binary(&GetGovernorResponse {
             address: governor::get_governor(deps)?,
         }),
         QueryMsg::GetGovernorCandidate {} => to_binary(&GetGovernorCandidateResponse {
-            address: governor::get_governor_candidate(deps)?,
+            address: governor::get_governor_candidate(deps, &env)?,
+            expires_at: governor::get_governorship_transfer_expires_at(deps)?,
         }),
         QueryMsg::IsGovernor { address } => to_binary(&IsGovernorResponse {
             is_governor: governor::is_governor(deps, &deps.api.addr_validate(&address)?)?,
         }),
         QueryMsg::IsGovernorCandidate { address } => to_binary(&IsGovernorCandidateResponse {
             is_governor_candidate: governor::is_governor_candidate(
+                deps,
+                &env,
+                &deps.api.addr_validate(&address)?,
+            )?,
+        }),
+        QueryMsg::GetMemberManagerCandidate {} => to_binary(&GetMemberManagerCandidateResponse {
+            address: member_manager::get_member_manager_candidate(deps)?,
+        }),
+        QueryMsg::IsMemberManagerCandidate { address } => {
+            to_binary(&IsMemberManagerCandidateResponse {
+                is_member_manager_candidate: member_manager::is_member_manager_candidate(
+                    deps,
+                    &deps.api.addr_validate(&address)?,
+                )?,
+            })
+        }
+        QueryMsg::GetCustodianCandidate {} => to_binary(&GetCustodianCandidateResponse {
+            address: custodian::get_custodian_candidate(deps)?,
+        }),
+        QueryMsg::IsCustodianCandidate { address } => to_binary(&IsCustodianCandidateResponse {
+            is_custodian_candidate: custodian::is_custodian_candidate(
+                deps,
+                &deps.api.addr_validate(&address)?,
+            )?,
+        }),
+
+        // == deposit address ==
+        QueryMsg::GetCustodianDepositAddress { merchant } => {
+            to_binary(&GetCustodianDepositAddressResponse {
+                address: deposit_address::get_custodian_deposit_address(
+                    deps,
+                    &deps.api.addr_validate(&merchant)?,
+                )?,
+            })
+        }
+        QueryMsg::GetMerchantDepositAddress { merchant } => {
+            to_binary(&GetMerchantDepositAddressResponse {
+                address: deposit_address::get_merchant_deposit_address(
+                    deps,
+                    &deps.api.addr_validate(&merchant)?,
+                )?,
+            })
+        }
+        QueryMsg::GetOperator { address } => to_binary(&GetOperatorResponse {
+            operator: operator::get_operator(deps, &deps.api.addr_validate(&address)?)?,
+        }),
+        QueryMsg::ListOperators {
+            merchant,
+            limit,
+            start_after,
+        } => to_binary(&ListOperatorsResponse {
+            operators: operator::list_operators(
+                deps,
+                &deps.api.addr_validate(&merchant)?,
+                start_after,
+                limit,
+            )?,
+        }),
+
+        // == denom admin ==
+        QueryMsg::GetDenomAdminHandover {} => to_binary(&GetDenomAdminHandoverResponse {
+            handover: denom_admin::get_pending_denom_admin_handover(deps)?,
+        }),
+        QueryMsg::GetPendingDenom {} => to_binary(&GetPendingDenomResponse {
+            denom: denom_admin::get_pending_denom(deps)?,
+        }),
+        QueryMsg::IsRetired {} => to_binary(&IsRetiredResponse {
+            is_retired: denom_admin::is_retired(deps)?,
+        }),
+
+        // == pausing ==
+        QueryMsg::IsPaused {} => to_binary(&IsPausedResponse {
+            is_paused: token::is_paused(deps)?,
+        }),
+        QueryMsg::IsMintingPaused {} => to_binary(&IsMintingPausedResponse {
+            is_minting_paused: mint::is_minting_paused(deps)?,
+        }),
+
+        // == transfer allowlist ==
+        QueryMsg::IsTransferAllowlistEnabled {} => to_binary(&IsTransferAllowlistEnabledResponse {
+            is_enabled: transfer_allowlist::is_transfer_allowlist_enabled(deps)?,
+        }),
+        QueryMsg::IsTransferAllowlisted { address } => to_binary(&IsTransferAllowlistedResponse {
+            is_allowlisted: transfer_allowlist::is_transfer_allowlisted(
                 deps,
                 &deps.api.addr_validate(&address)?,
             )?,
         }),
+        QueryMsg::ListTransferAllowlist { limit, start_after } => {
+            to_binary(&ListTransferAllowlistResponse {
+                addresses: transfer_allowlist::list_transfer_allowlist(deps, start_after, limit)?,
+            })
+        }
+
+        // == transfer limits ==
+        QueryMsg::GetTransferLimits { address } => {
+            let address = deps.api.addr_validate(&address)?;
+            let address_transfer_limit = transfer_limit::get_address_transfer_limit(deps)?;
+            let global_transfer_limit = transfer_limit::get_global_transfer_limit(deps)?;
+            let sent_amount = transfer_limit::get_sent_amount(deps, &address, env.block.time)?;
+            let globally_sent_amount =
+                transfer_limit::get_globally_sent_amount(deps, env.block.time)?;
+
+            to_binary(&GetTransferLimitsResponse {
+                address_transfer_limit,
+                global_transfer_limit,
+                window_seconds: TRANSFER_LIMIT_WINDOW_SECONDS,
+                is_exempt: transfer_limit::is_transfer_limit_exempt(deps, &address)?,
+                sent_amount,
+                remaining_amount: address_transfer_limit
+                    .map(|limit| limit.saturating_sub(sent_amount)),
+                globally_sent_amount,
+                globally_remaining_amount: global_transfer_limit
+                    .map(|limit| limit.saturating_sub(globally_sent_amount)),
+            })
+        }
+        QueryMsg::ListTransferLimitExemptions { limit, start_after } => {
+            to_binary(&ListTransferLimitExemptionsResponse {
+                addresses: transfer_limit::list_transfer_limit_exemptions(
+                    deps,
+                    start_after,
+                    limit,
+                )?,
+            })
+        }
+
+        // == holders ==
+        QueryMsg::ListHolders { limit, start_after } => to_binary(&ListHoldersResponse {
+            holders: holders::list_holders(deps, start_after, limit)?,
+        }),
+        QueryMsg::GetHolderCount {} => to_binary(&GetHolderCountResponse {
+            count: holders::get_holder_count(deps)?,
+        }),
+
+        // == enforcement ==
+        QueryMsg::IsFrozen { account } => to_binary(&IsFrozenResponse {
+            is_frozen: enforcement::is_frozen(deps.storage, &deps.api.addr_validate(&account)?)?,
+        }),
+        QueryMsg::ListFrozenAccounts { limit, start_after } => {
+            to_binary(&ListFrozenAccountsResponse {
+                accounts: enforcement::list_frozen_accounts(deps, start_after, limit)?,
+            })
+        }
+        QueryMsg::ListEnforcementRecords { limit, start_after } => {
+            to_binary(&ListEnforcementRecordsResponse {
+                records: enforcement::list_enforcement_records(deps, start_after, limit)?,
+            })
+        }
+    }
+}
+
+/// Handling submessage reply.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        CREATE_DENOM_REPLY_ID => {
+            // register created token denom and set beforesend listener to this contract
+            let MsgCreateDenomResponse { new_token_denom } = msg.result.try_into()?;
+            // denom metadata provided at instantiation is set as well
+            let msgs = token::register_token_denom(deps.storage, &env, &new_token_denom)?;
+
+            Ok(Response::new()
+                .add_attribute("new_token_denom", new_token_denom)
+                .add_messages(msgs))
+        }
+        FORCE_TRANSFER_REPLY_ID => {
+            // force transfer has been executed, stop bypassing send restrictions
+            enforcement::clear_pending_force_transfer(deps.storage);
+
+            Ok(Response::new())
+        }
+        _ => Err(StdError::not_found(format!("No reply handler found for: {:?}", msg)).into()),
+    }
+}
+
+/// Handling contract sudo call.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    match msg {
+        // Hook for bank send (aka. token transfer), this is called before the token is sent if this contract is registered with MsgSetBeforeSendHook
+        SudoMsg::BlockBeforeSend { from, to, amount } => {
+            // force transfer issued by the governor bypasses send restrictions
+            if enforcement::is_pending_force_transfer(deps.storage, &from, &to)? {
+                return Ok(Response::new().add_attribute("hook", "block_before_send"));
+            }
+
+            // ensure that neither sender nor recipient is frozen
+            enforcement::ensure_not_frozen(deps.storage, &from, &to)?;
+
+            // ensure that both parties are allowlisted if transfer allowlist mode is enabled
+            transfer_allowlist::ensure_transfer_allowed(deps.as_ref(), &env, &from, &to)?;
+
+            // ensure that token transfer is not paused
+            let token_transfer_is_not_paused = !token::is_paused(deps.as_ref())?;
+            ensure!(
+                token_transfer_is_not_paused,
+                ContractError::TokenTransferPaused {}
+            );
+
+            // ensure that the sender stays within the transfer limits and record the transfer
+            transfer_limit::record_transfer(deps, &env, &from, amount.amount)?;
+
+            Ok(Response::new().add_attribute("hook", "block_before_send"))
+        }
+
+        // Hook for bank send that can not block the transfer, used to maintain the holder index
+        SudoMsg::TrackBeforeSend { from, to, amount } => {
+            holders::track_transfer(deps, &env, &from, &to, &amount)?;
+
+            Ok(Response::new().add_attribute("hook", "track_before_send"))
+        }
+
+        // Overrides reachable only by chain governance, bypassing the role checks
+        SudoMsg::ForceSetGovernor { address } => governor::force_set_governor(deps, &env, &address),
+        SudoMsg::ForcePause {} => token::force_pause(deps),
+        SudoMsg::ForceSetCustodian { address } => {
+            custodian::force_set_custodian(deps, &env, &address)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        attr, from_binary,
+        testing::{mock_dependencies, mock_env, mock_info},
+        Addr, Coin, CosmosMsg, Empty, Event, SubMsgResponse, SubMsgResult, Uint128,
+    };
+    use cw2::ContractVersion;
+
+    use crate::auth::merchant::{MerchantInfo, MerchantStatus};
+
+    use crate::constants::GOVERNORSHIP_TRANSFER_EXPIRY_SECONDS;
+    use osmosis_std::types::{
+        cosmos::bank::v1beta1::Metadata, osmosis::tokenfactory::v1beta1::MsgSetDenomMetadata,
+    };
+
+    use crate::{
+        attrs::tests::setup_contract,
+        msg::{GetMerchantDepositAddressResponse, IsGovernorCandidateResponse},
+        tokenfactory::{
+            burn::{BurnRequest, BurnRequestWithHash},
+            mint::{MintRequest, MintRequestWithHash},
+        },
+        BurnRequestStatus, MintRequestStatus,
+    };
+
+    use super::*;
+
+    #[test]
+    fn instantiate_attrs() {
+        let mut deps = mock_dependencies();
+        let msg = InstantiateMsg {
+            governor: "osmo1governor".to_string(),
+            subdenom: "subdenom".to_string(),
+            existing_denom: None,
+            member_manager: None,
+            custodian: None,
+            merchants: None,
+            min_burn_amount: None,
+            denom_metadata: None,
+            require_contract_holders: None,
+        };
+
+        let info = mock_info("creator", &[]);
+        let res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        // check attributes
+        assert_eq!(
+            res.attributes,
+            vec![
+                attr("action", "instantiate"),
+                attr("governor", "osmo1governor"),
+            ]
+        );
+    }
+
+    #[test]
+    fn instantiate_with_full_configuration() {
+        let mut deps = mock_dependencies();
+        let governor = "osmo1governor";
+        let member_manager = "osmo1membermanager";
+        let custodian = "osmo1custodian";
+        let merchant = "osmo1merchant";
+        let metadata = Metadata {
+            description: "Wrapped Bitcoin".to_string(),
+            denom_units: vec![osmosis_std::types::cosmos::bank::v1beta1::DenomUnit {
+                denom: "uwbtc".to_string(),
+                exponent: 0,
+                aliases: vec![],
+            }],
+            base: "uwbtc".to_string(),
+            display: "wbtc".to_string(),
+            name: "Wrapped Bitcoin".to_string(),
+            symbol: "WBTC".to_string(),
+        };
+        let msg = InstantiateMsg {
+            governor: governor.to_string(),
+            subdenom: "wbtc".to_string(),
+            existing_denom: None,
+            member_manager: Some(member_manager.to_string()),
+            custodian: Some(custodian.to_string()),
+            merchants: Some(vec![merchant.to_string()]),
+            min_burn_amount: Some(Uint128::new(10000)),
+            denom_metadata: Some(metadata.clone()),
+            require_contract_holders: None,
+        };
+
+        // roles are subject to the same priviledged role rules as their setters
+        let err = instantiate(
+            mock_dependencies().as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            InstantiateMsg {
+                custodian: Some(member_manager.to_string()),
+                ..msg.clone()
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::AlreadyHasPriviledgedRole {
+                address: member_manager.to_string()
+            }
+        );
+
+        let res = instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        assert_eq!(
+            res.attributes,
+            vec![
+                attr("action", "instantiate"),
+                attr("governor", governor),
+                attr("member_manager", member_manager),
+                attr("custodian", custodian),
+                attr("merchant", merchant),
+            ]
+        );
+
+        let query_custodian: GetCustodianResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::GetCustodian {}).unwrap())
+                .unwrap();
+        assert_eq!(query_custodian.address, custodian);
+
+        let query_is_merchant: IsMerchantResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::IsMerchant {
+                    address: merchant.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(query_is_merchant.is_merchant);
+
+        let query_min_burn_amount: GetMinBurnAmountResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::GetMinBurnAmount {}).unwrap())
+                .unwrap();
+        assert_eq!(query_min_burn_amount.amount, Uint128::new(10000));
+
+        // denom metadata is set once the denom is created
+        let token_denom = format!("factory/{}/wbtc", mock_env().contract.address);
+        let res = reply(
+            deps.as_mut(),
+            mock_env(),
+            Reply {
+                id: CREATE_DENOM_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: Some(
+                        MsgCreateDenomResponse {
+                            new_token_denom: token_denom.clone(),
+                        }
+                        .into(),
+                    ),
+                }),
+            },
+        )
+        .unwrap();
+
+        let mut expected_metadata = metadata;
+        expected_metadata.base = token_denom.clone();
+        expected_metadata.denom_units[0].denom = token_denom;
+        assert_eq!(
+            res.messages[1].msg,
+            CosmosMsg::from(MsgSetDenomMetadata {
+                sender: mock_env().contract.address.to_string(),
+                metadata: Some(expected_metadata),
+            })
+        );
+    }
+
+    #[test]
+    fn query_config_and_roles() {
+        let mut deps = mock_dependencies();
+        let governor = "osmo1governor";
+        let merchant = "osmo1merchant";
+        let other_merchant = "osmo1othermerchant";
+        let msg = InstantiateMsg {
+            governor: governor.to_string(),
+            subdenom: "wbtc".to_string(),
+            existing_denom: None,
+            member_manager: None,
+            custodian: None,
+            merchants: Some(vec![merchant.to_string(), other_merchant.to_string()]),
+            min_burn_amount: None,
+            denom_metadata: None,
+            require_contract_holders: None,
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+
+        // unset settings and roles do not fail the queries
+        let config: GetConfigResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::GetConfig {}).unwrap())
+                .unwrap();
+        assert_eq!(
+            config,
+            GetConfigResponse {
+                contract_version: ContractVersion {
+                    contract: CONTRACT_NAME.to_string(),
+                    version: CONTRACT_VERSION.to_string(),
+                },
+                token_denom: None,
+                pending_denom: None,
+                is_paused: false,
+                is_minting_paused: false,
+                is_retired: false,
+                min_burn_amount: Uint128::zero(),
+                max_burn_amount: None,
+                merchant_burn_limit: None,
+                burn_mode: BurnMode::BurnFromAddress,
+                burn_confirmation_tolerance: Uint128::zero(),
+                redemption_timeout_seconds: redemption::get_redemption_timeout(deps.as_ref())
+                    .unwrap(),
+                transfer_allowlist_enabled: false,
+                address_transfer_limit: None,
+                global_transfer_limit: None,
+            }
+        );
+
+        let get_roles = |limit: Option<u32>, start_after: Option<&str>| {
+            from_binary::<GetRolesResponse>(
+                &query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::GetRoles {
+                        limit,
+                        start_after: start_after.map(ToString::to_string),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+        let roles = get_roles(None, None);
+        assert_eq!(
+            roles,
+            GetRolesResponse {
+                governor: Addr::unchecked(governor),
+                governor_candidate: None,
+                member_manager: None,
+                member_manager_candidate: None,
+                custodian: None,
+                custodian_candidate: None,
+                compliance_officer: None,
+                merchants: vec![Addr::unchecked(merchant), Addr::unchecked(other_merchant)],
+            }
+        );
+
+        // merchants are paginated
+        assert_eq!(
+            get_roles(Some(1), None).merchants,
+            vec![Addr::unchecked(merchant)]
+        );
+        assert_eq!(
+            get_roles(Some(1), Some(merchant)).merchants,
+            vec![Addr::unchecked(other_merchant)]
+        );
+    }
+
+    #[test]
+    fn query_address_roles() {
+        let mut deps = mock_dependencies();
+        let governor = "osmo1governor";
+        let custodian = "osmo1custodian";
+        let merchant = "osmo1merchant";
+        let msg = InstantiateMsg {
+            governor: governor.to_string(),
+            subdenom: "wbtc".to_string(),
+            existing_denom: None,
+            member_manager: None,
+            custodian: Some(custodian.to_string()),
+            merchants: Some(vec![merchant.to_string()]),
+            min_burn_amount: None,
+            denom_metadata: None,
+            require_contract_holders: None,
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(custodian, &[]),
+            ExecuteMsg::SetCustodianDepositAddress {
+                merchant: merchant.to_string(),
+                deposit_address: Some("bc1qdeposit".to_string()),
+            },
+        )
+        .unwrap();
+
+        let query_address_roles = |address: &str| -> GetAddressRolesResponse {
+            from_binary(
+                &query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::GetAddressRoles {
+                        address: address.to_string(),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+
+        assert_eq!(
+            query_address_roles(governor),
+            GetAddressRolesResponse {
+                roles: vec![Role::Governor],
+                merchant: None,
+            }
+        );
+        assert_eq!(
+            query_address_roles(merchant),
+            GetAddressRolesResponse {
+                roles: vec![Role::Merchant],
+                merchant: Some(MerchantRoleInfo {
+                    status: MerchantStatus::Active,
+                    custodian_deposit_address: Some("bc1qdeposit".to_string()),
+                    merchant_deposit_address: None,
+                }),
+            }
+        );
+        assert_eq!(
+            query_address_roles("osmo1nobody"),
+            GetAddressRolesResponse {
+                roles: vec![],
+                merchant: None,
+            }
+        );
+    }
+
+    #[test]
+    fn execute_reject_all_non_zero_funds() {
+        // sample messages
+        let msgs = vec![
+            ExecuteMsg::Burn {
+                amount: 1000u128.into(),
+                btc_address: None,
+            },
+            ExecuteMsg::Pause {},
+            ExecuteMsg::SetMinBurnAmount {
+                amount: 10000u128.into(),
+            },
+            ExecuteMsg::IssueMintRequest {
+                amount: 10000u128.into(),
+                tx_id: "tx_id".to_string(),
+            },
+        ];
+
+        for msg in msgs {
+            let mut deps = mock_dependencies();
+            let err = execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("sender", &[Coin::new(1, "uosmo")]),
+                msg,
+            )
+            .unwrap_err();
+            assert_eq!(err, ContractError::NonPayable {});
+        }
+    }
+
+    #[test]
+    fn execute_burn_accepts_funds_only_in_attached_funds_mode() {
+        let mut deps = mock_dependencies();
+        let governor = "osmo1governor";
+        let contract_address = mock_env().contract.address;
+        let token_denom =
+            setup_contract(deps.as_mut(), contract_address.as_str(), governor, "wbtc").unwrap();
+
+        let burn_with_funds = |deps: DepsMut| {
+            execute(
+                deps,
+                mock_env(),
+                mock_info("osmo1sender", &[Coin::new(1000, &token_denom)]),
+                ExecuteMsg::Burn {
+                    amount: 1000u128.into(),
+                    btc_address: None,
+                },
+            )
+            .unwrap_err()
+        };
+
+        // `BurnFromAddress` mode rejects funds
+        assert_eq!(burn_with_funds(deps.as_mut()), ContractError::NonPayable {});
+
+        // setting burn mode does not accept funds
+        assert_eq!(
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(governor, &[Coin::new(1, "uosmo")]),
+                ExecuteMsg::SetBurnMode {
+                    mode: BurnMode::AttachedFunds,
+                },
+            )
+            .unwrap_err(),
+            ContractError::NonPayable {}
+        );
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(governor, &[]),
+            ExecuteMsg::SetBurnMode {
+                mode: BurnMode::AttachedFunds,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            from_binary::<GetBurnModeResponse>(
+                &query(deps.as_ref(), mock_env(), QueryMsg::GetBurnMode {}).unwrap()
+            )
+            .unwrap(),
+            GetBurnModeResponse {
+                mode: BurnMode::AttachedFunds
+            }
+        );
+
+        // `AttachedFunds` mode passes funds through to the burn handler
+        assert_eq!(
+            burn_with_funds(deps.as_mut()),
+            ContractError::Unauthorized {}
+        );
+    }
+
+    #[test]
+    fn sudo_overrides_bypass_role_checks() {
+        let mut deps = mock_dependencies();
+        let governor = "osmo1governor";
+        let new_governor = "osmo1newgovernor";
+        let member_manager = "osmo1membermanager";
+        let custodian = "osmo1custodian";
+        let new_custodian = "osmo1newcustodian";
+        let contract_address = mock_env().contract.address;
+        let token_denom =
+            setup_contract(deps.as_mut(), contract_address.as_str(), governor, "wbtc").unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(governor, &[]),
+            ExecuteMsg::SetMemberManager {
+                address: member_manager.to_string(),
+            },
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(member_manager, &[]),
+            ExecuteMsg::SetCustodian {
+                address: custodian.to_string(),
+            },
+        )
+        .unwrap();
+
+        // the new governor must not hold any other role
+        let err = sudo(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::ForceSetGovernor {
+                address: custodian.to_string(),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::AlreadyHasPriviledgedRole {
+                address: custodian.to_string()
+            }
+        );
+
+        // force set governor, discarding the pending transfer
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(governor, &[]),
+            ExecuteMsg::TransferGovernorship {
+                new_governor_address: "osmo1candidate".to_string(),
+            },
+        )
+        .unwrap();
+        let res = sudo(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::ForceSetGovernor {
+                address: new_governor.to_string(),
+            },
+        )
+        .unwrap();
+        assert!(res.attributes.is_empty());
+        assert_eq!(
+            res.events,
+            vec![Event::new("sudo_override").add_attributes(vec![
+                attr("action", "force_set_governor"),
+                attr("address", new_governor),
+                attr("previous", governor),
+            ])]
+        );
+        assert_eq!(
+            from_binary::<GetGovernorResponse>(
+                &query(deps.as_ref(), mock_env(), QueryMsg::GetGovernor {}).unwrap()
+            )
+            .unwrap()
+            .address,
+            new_governor
+        );
+        assert!(
+            !from_binary::<IsGovernorCandidateResponse>(
+                &query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::IsGovernorCandidate {
+                        address: "osmo1candidate".to_string()
+                    }
+                )
+                .unwrap()
+            )
+            .unwrap()
+            .is_governor_candidate
+        );
+
+        // the previous governor has lost its role
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(governor, &[]),
+            ExecuteMsg::Pause {},
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
 
-        // == deposit address ==
-        QueryMsg::GetCustodianDepositAddress { merchant } => {
-            to_binary(&GetCustodianDepositAddressResponse {
-                address: deposit_address::get_custodian_deposit_address(
-                    deps,
-                    &deps.api.addr_validate(&merchant)?,
-                )?,
-            })
-        }
-        QueryMsg::GetMerchantDepositAddress { merchant } => {
-            to_binary(&GetMerchantDepositAddressResponse {
-                address: deposit_address::get_merchant_deposit_address(
-                    deps,
-                    &deps.api.addr_validate(&merchant)?,
-                )?,
-            })
-        }
+        // force pause
+        let res = sudo(deps.as_mut(), mock_env(), SudoMsg::ForcePause {}).unwrap();
+        assert_eq!(
+            res.events,
+            vec![Event::new("sudo_override").add_attribute("action", "force_pause")]
+        );
+        let err = sudo(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::BlockBeforeSend {
+                from: "osmo1sender".to_string(),
+                to: "osmo1recipient".to_string(),
+                amount: Coin::new(1, token_denom),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::TokenTransferPaused {});
 
-        // == pausing ==
-        QueryMsg::IsPaused {} => to_binary(&IsPausedResponse {
-            is_paused: token::is_paused(deps)?,
-        }),
+        // force set custodian
+        let res = sudo(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::ForceSetCustodian {
+                address: new_custodian.to_string(),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            res.events,
+            vec![Event::new("sudo_override").add_attributes(vec![
+                attr("action", "force_set_custodian"),
+                attr("address", new_custodian),
+                attr("previous", custodian),
+            ])]
+        );
+        assert_eq!(
+            from_binary::<GetCustodianResponse>(
+                &query(deps.as_ref(), mock_env(), QueryMsg::GetCustodian {}).unwrap()
+            )
+            .unwrap()
+            .address,
+            new_custodian
+        );
     }
-}
 
-/// Handling submessage reply.
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
-    match msg.id {
-        CREATE_DENOM_REPLY_ID => {
-            // register created token denom
-            let MsgCreateDenomResponse { new_token_denom } = msg.result.try_into()?;
-            token::set_token_denom(deps.storage, &new_token_denom)?;
-
-            // set beforesend listener to this contract
-            // this will trigger sudo endpoint before any bank send
-            // which makes token transfer pause possible
-            let msg_set_beforesend_hook: CosmosMsg = MsgSetBeforeSendHook {
-                sender: env.contract.address.to_string(),
-                denom: new_token_denom.clone(),
-                cosmwasm_address: env.contract.address.to_string(),
-            }
-            .into();
+    #[test]
+    fn block_before_send_enforces_frozen_accounts_except_force_transfer() {
+        let mut deps = mock_dependencies();
+        let governor = "osmo1governor";
+        let thief = "osmo1thief";
+        let victim = "osmo1victim";
+        let contract_address = mock_env().contract.address;
+        let token_denom =
+            setup_contract(deps.as_mut(), contract_address.as_str(), governor, "wbtc").unwrap();
+
+        let send = |deps: DepsMut, from: &str, to: &str| {
+            sudo(
+                deps,
+                mock_env(),
+                SudoMsg::BlockBeforeSend {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    amount: Coin::new(100, &token_denom),
+                },
+            )
+        };
 
-            Ok(Response::new()
-                .add_attribute("new_token_denom", new_token_denom)
-                .add_message(msg_set_beforesend_hook))
-        }
-        _ => Err(StdError::not_found(format!("No reply handler found for: {:?}", msg)).into()),
-    }
-}
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(governor, &[]),
+            ExecuteMsg::FreezeAccount {
+                account: thief.to_string(),
+                case_reference: "case-1".to_string(),
+            },
+        )
+        .unwrap();
 
-/// Handling contract sudo call.
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
-    match msg {
-        // Hook for bank send (aka. token transfer), this is called before the token is sent if this contract is registered with MsgSetBeforeSendHook
-        SudoMsg::BlockBeforeSend { .. } => {
-            // ensure that token transfer is not paused
-            let token_transfer_is_not_paused = !token::is_paused(deps.as_ref())?;
-            ensure!(
-                token_transfer_is_not_paused,
-                ContractError::TokenTransferPaused {}
-            );
+        assert_eq!(
+            from_binary::<IsFrozenResponse>(
+                &query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::IsFrozen {
+                        account: thief.to_string()
+                    }
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            IsFrozenResponse { is_frozen: true }
+        );
 
-            Ok(Response::new().add_attribute("hook", "block_before_send"))
-        }
-    }
-}
+        assert_eq!(
+            send(deps.as_mut(), thief, victim).unwrap_err(),
+            ContractError::AccountFrozen {
+                address: thief.to_string()
+            }
+        );
 
-#[cfg(test)]
-mod tests {
-    use cosmwasm_std::{
-        attr, from_binary,
-        testing::{mock_dependencies, mock_env, mock_info},
-        Addr, Coin, Empty, SubMsgResponse, SubMsgResult,
-    };
-    use osmosis_std::types::{
-        cosmos::bank::v1beta1::Metadata, osmosis::tokenfactory::v1beta1::MsgSetDenomMetadata,
-    };
+        // force transfer bypasses the freeze until its reply is handled
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(governor, &[]),
+            ExecuteMsg::ForceTransfer {
+                from: thief.to_string(),
+                to: victim.to_string(),
+                amount: 100u128.into(),
+                case_reference: "case-1".to_string(),
+            },
+        )
+        .unwrap();
 
-    use crate::{
-        msg::{GetMerchantDepositAddressResponse, IsGovernorCandidateResponse},
-        tokenfactory::{
-            burn::{BurnRequest, BurnRequestWithHash},
-            mint::{MintRequest, MintRequestWithHash},
-        },
-        BurnRequestStatus, MintRequestStatus,
-    };
+        send(deps.as_mut(), thief, victim).unwrap();
 
-    use super::*;
+        reply(
+            deps.as_mut(),
+            mock_env(),
+            Reply {
+                id: FORCE_TRANSFER_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            send(deps.as_mut(), thief, victim).unwrap_err(),
+            ContractError::AccountFrozen {
+                address: thief.to_string()
+            }
+        );
+
+        assert_eq!(
+            from_binary::<ListEnforcementRecordsResponse>(
+                &query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::ListEnforcementRecords {
+                        limit: None,
+                        start_after: None
+                    }
+                )
+                .unwrap()
+            )
+            .unwrap()
+            .records
+            .len(),
+            2
+        );
+    }
 
     #[test]
-    fn instantiate_attrs() {
+    fn track_before_send_maintains_holder_index() {
         let mut deps = mock_dependencies();
-        let msg = InstantiateMsg {
-            governor: "osmo1governor".to_string(),
-            subdenom: "subdenom".to_string(),
+        let governor = "osmo1governor";
+        let alice = "osmo1alice";
+        let bob = "osmo1bob";
+        let contract_address = mock_env().contract.address;
+        let token_denom =
+            setup_contract(deps.as_mut(), contract_address.as_str(), governor, "wbtc").unwrap();
+
+        let track = |deps: DepsMut, from: &str, to: &str, amount: u128| {
+            sudo(
+                deps,
+                mock_env(),
+                SudoMsg::TrackBeforeSend {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    amount: Coin::new(amount, &token_denom),
+                },
+            )
+        };
+        let holders = |deps: Deps| {
+            from_binary::<ListHoldersResponse>(
+                &query(
+                    deps,
+                    mock_env(),
+                    QueryMsg::ListHolders {
+                        limit: None,
+                        start_after: None,
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap()
+            .holders
+            .into_iter()
+            .map(|holder| holder.address)
+            .collect::<Vec<_>>()
+        };
+        let holder_count = |deps: Deps| {
+            from_binary::<GetHolderCountResponse>(
+                &query(deps, mock_env(), QueryMsg::GetHolderCount {}).unwrap(),
+            )
+            .unwrap()
+            .count
         };
 
-        let info = mock_info("creator", &[]);
-        let res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+        // minted tokens sent from the contract to alice
+        deps.querier.update_balance(
+            contract_address.as_str(),
+            vec![Coin::new(100, &token_denom)],
+        );
+        let res = track(deps.as_mut(), contract_address.as_str(), alice, 100).unwrap();
+        assert_eq!(res.attributes, vec![attr("hook", "track_before_send")]);
+        deps.querier
+            .update_balance(alice, vec![Coin::new(100, &token_denom)]);
+
+        assert_eq!(holders(deps.as_ref()), vec![Addr::unchecked(alice)]);
+        assert_eq!(holder_count(deps.as_ref()), 1);
+
+        // alice sends part of its balance to bob
+        track(deps.as_mut(), alice, bob, 30).unwrap();
+        deps.querier
+            .update_balance(alice, vec![Coin::new(70, &token_denom)]);
+        deps.querier
+            .update_balance(bob, vec![Coin::new(30, &token_denom)]);
 
-        // check attributes
         assert_eq!(
-            res.attributes,
-            vec![
-                attr("action", "instantiate"),
-                attr("governor", "osmo1governor"),
-            ]
+            holders(deps.as_ref()),
+            vec![Addr::unchecked(alice), Addr::unchecked(bob)]
         );
-    }
+        assert_eq!(holder_count(deps.as_ref()), 2);
 
-    #[test]
-    fn execute_reject_all_non_zero_funds() {
-        // sample messages
-        let msgs = vec![
-            ExecuteMsg::Burn {
-                amount: 1000u128.into(),
-            },
-            ExecuteMsg::Pause {},
-            ExecuteMsg::SetMinBurnAmount {
-                amount: 10000u128.into(),
-            },
-            ExecuteMsg::IssueMintRequest {
-                amount: 10000u128.into(),
-                tx_id: "tx_id".to_string(),
-            },
-        ];
+        // alice sends the rest of its balance and is no longer a holder
+        track(deps.as_mut(), alice, bob, 70).unwrap();
 
-        for msg in msgs {
-            let mut deps = mock_dependencies();
-            let err = execute(
-                deps.as_mut(),
-                mock_env(),
-                mock_info("sender", &[Coin::new(1, "uosmo")]),
-                msg,
-            )
-            .unwrap_err();
-            assert_eq!(err, ContractError::NonPayable {});
-        }
+        assert_eq!(holders(deps.as_ref()), vec![Addr::unchecked(bob)]);
+        assert_eq!(holder_count(deps.as_ref()), 1);
     }
 
     #[test]
@@ -390,6 +1683,13 @@ mod tests {
             InstantiateMsg {
                 governor: String::from(governor),
                 subdenom: String::from("wbtc"),
+                existing_denom: None,
+                member_manager: None,
+                custodian: None,
+                merchants: None,
+                min_burn_amount: None,
+                denom_metadata: None,
+                require_contract_holders: None,
             },
         )
         .unwrap();
@@ -568,6 +1868,7 @@ mod tests {
             mock_info(member_manager, &[]),
             ExecuteMsg::AddMerchant {
                 address: merchant.to_string(),
+                profile: None,
             },
         )
         .unwrap();
@@ -579,14 +1880,21 @@ mod tests {
                     mock_env(),
                     QueryMsg::ListMerchants {
                         limit: None,
-                        start_after: None
+                        start_after: None,
+                        status: None
                     }
                 )
                 .unwrap()
             )
             .unwrap(),
             ListMerchantsResponse {
-                merchants: vec![Addr::unchecked(merchant)]
+                merchants: vec![MerchantInfo {
+                    address: Addr::unchecked(merchant),
+                    status: MerchantStatus::Active,
+                    profile: None,
+                    onboarded_at: Some(mock_env().block.time),
+                }],
+                next_start_after: None,
             }
         );
 
@@ -623,13 +1931,17 @@ mod tests {
                     mock_env(),
                     QueryMsg::ListMerchants {
                         limit: None,
-                        start_after: None
+                        start_after: None,
+                        status: None
                     }
                 )
                 .unwrap()
             )
             .unwrap(),
-            ListMerchantsResponse { merchants: vec![] }
+            ListMerchantsResponse {
+                merchants: vec![],
+                next_start_after: None
+            }
         );
 
         assert_eq!(
@@ -654,6 +1966,7 @@ mod tests {
             mock_info(member_manager, &[]),
             ExecuteMsg::AddMerchant {
                 address: merchant.to_string(),
+                profile: None,
             },
         )
         .unwrap();
@@ -757,7 +2070,13 @@ mod tests {
             )
             .unwrap(),
             GetGovernorCandidateResponse {
-                address: Some(Addr::unchecked(new_governor))
+                address: Some(Addr::unchecked(new_governor)),
+                expires_at: Some(
+                    mock_env()
+                        .block
+                        .time
+                        .plus_seconds(GOVERNORSHIP_TRANSFER_EXPIRY_SECONDS)
+                )
             }
         );
 
@@ -818,7 +2137,10 @@ mod tests {
                 &query(deps.as_ref(), mock_env(), QueryMsg::GetGovernorCandidate {}).unwrap()
             )
             .unwrap(),
-            GetGovernorCandidateResponse { address: None }
+            GetGovernorCandidateResponse {
+                address: None,
+                expires_at: None
+            }
         );
 
         // mint
@@ -1134,10 +2456,44 @@ mod tests {
             mock_info(merchant, &[]),
             ExecuteMsg::Burn {
                 amount: 10000u128.into(),
+                btc_address: None,
+            },
+        )
+        .unwrap();
+
+        // check burn limits
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(custodian, &[]),
+            ExecuteMsg::SetMerchantBurnLimit {
+                amount: Some(100000u128.into()),
             },
         )
         .unwrap();
 
+        assert_eq!(
+            from_binary::<GetBurnLimitsResponse>(
+                &query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::GetBurnLimits {
+                        merchant: merchant.to_string()
+                    }
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            GetBurnLimitsResponse {
+                min_burn_amount: 1u128.into(),
+                max_burn_amount: None,
+                merchant_burn_limit: Some(100000u128.into()),
+                window_seconds: 24 * 60 * 60,
+                burned_amount: 10000u128.into(),
+                remaining_burn_amount: Some(90000u128.into()),
+            }
+        );
+
         // check burn request
         let burn_request = BurnRequest {
             requester: Addr::unchecked(merchant),
@@ -1215,15 +2571,44 @@ mod tests {
             mock_info(custodian, &[]),
             ExecuteMsg::ConfirmBurnRequest {
                 request_hash: request_hash.to_string(),
-                tx_id: "burn_tx_id".to_string(),
+                tx_id: "1f6a3c2e9b5d4e8f7a6b5c4d3e2f1a0b9c8d7e6f5a4b3c2d1e0f9a8b7c6d5e4f"
+                    .to_string(),
+                btc_amount_sent: Uint128::new(9000),
+                network_fee: Uint128::new(1000),
+                vout: 0,
             },
         )
         .unwrap();
 
+        assert_eq!(
+            from_binary::<GetBurnConfirmationResponse>(
+                &query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::GetBurnConfirmation {
+                        request_hash: request_hash.to_string(),
+                    }
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            GetBurnConfirmationResponse {
+                confirmation: BurnConfirmation {
+                    tx_id: "1f6a3c2e9b5d4e8f7a6b5c4d3e2f1a0b9c8d7e6f5a4b3c2d1e0f9a8b7c6d5e4f"
+                        .to_string(),
+                    vout: 0,
+                    btc_amount_sent: Uint128::new(9000),
+                    network_fee: Uint128::new(1000),
+                }
+            }
+        );
+
         // check burn request
         let burn_request_confirmed = BurnRequest {
             status: BurnRequestStatus::Confirmed,
-            tx_id: Some("burn_tx_id".to_string()),
+            tx_id: Some(
+                "1f6a3c2e9b5d4e8f7a6b5c4d3e2f1a0b9c8d7e6f5a4b3c2d1e0f9a8b7c6d5e4f".to_string(),
+            ),
             ..burn_request
         };
 
@@ -1,4 +1,4 @@
-use cosmwasm_std::{ensure, MessageInfo, StdError, Uint128};
+use cosmwasm_std::{ensure, MessageInfo, StdError, Timestamp, Uint128};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -12,6 +12,25 @@ pub enum ContractError {
     #[error("Multiple privileged role for single address is not allowed: `{address}`")]
     AlreadyHasPriviledgedRole { address: String },
 
+    #[error("{role} is already set, it can only be handed over with a proposal")]
+    RoleAlreadySet { role: String },
+
+    #[error("No pending {role} candidate")]
+    NoPendingCandidate { role: String },
+
+    #[error("{role} must be held by a `{expected}` but `{address}` is not")]
+    HolderKindMismatch {
+        role: String,
+        address: String,
+        expected: String,
+    },
+
+    #[error("{role} is required to be held by a contract")]
+    ContractHolderRequired { role: String },
+
+    #[error("Governorship transfer expired at {expires_at}")]
+    GovernorshipTransferExpired { expires_at: Timestamp },
+
     #[error("Expect request to have updatable status: request_hash: {request_hash}")]
     UpdatableStatusExpected { request_hash: String },
 
@@ -26,20 +45,168 @@ pub enum ContractError {
     #[error("Custodian deposit address not found for merchant {merchant}")]
     CustodianDepositAddressNotFound { merchant: String },
 
+    #[error("Invalid BTC address: `{address}`")]
+    InvalidBtcAddress { address: String },
+
+    #[error("Invalid BTC transaction id: `{tx_id}`, expected 64 hex characters")]
+    InvalidBtcTxId { tx_id: String },
+
+    #[error(
+        "BTC address `{address}` is not a whitelisted payout address of merchant `{merchant}`"
+    )]
+    PayoutAddressNotWhitelisted { merchant: String, address: String },
+
     #[error("Address `{address}` is not a merchant")]
     NotAMerchant { address: String },
 
+    #[error("Merchant `{address}` is suspended")]
+    MerchantSuspended { address: String },
+
+    #[error("Invalid merchant profile: {reason}")]
+    InvalidMerchantProfile { reason: String },
+
+    #[error("Batch failed: {}", format_batch_errors(.errors))]
+    BatchFailed { errors: Vec<BatchItemError> },
+
+    #[error("Address `{address}` is not an operator of the merchant")]
+    NotAnOperator { address: String },
+
+    #[error("Operator must be granted at least one permission")]
+    EmptyOperatorPermissions {},
+
+    #[error("Operator expiry must be in the future: {expires_at}")]
+    InvalidOperatorExpiry { expires_at: Timestamp },
+
+    #[error("Operator `{address}` expired at {expires_at}")]
+    OperatorExpired {
+        address: String,
+        expires_at: Timestamp,
+    },
+
+    #[error("Contract is retired: denom admin has been handed over to a new controller")]
+    ContractRetired {},
+
+    #[error("Invalid tokenfactory denom: `{denom}`")]
+    InvalidTokenfactoryDenom { denom: String },
+
+    #[error("No pending denom to accept admin of")]
+    NoPendingDenom {},
+
+    #[error("Contract is not the admin of denom `{denom}`")]
+    NotDenomAdmin { denom: String },
+
+    #[error("No pending denom admin handover")]
+    NoPendingDenomAdminHandover {},
+
+    #[error("Denom admin handover is timelocked until {executable_at}")]
+    DenomAdminHandoverTimelocked { executable_at: Timestamp },
+
+    #[error("New denom admin `{address}` is not a contract")]
+    DenomAdminNotAContract { address: String },
+
     #[error("Token transfer is paused")]
     TokenTransferPaused {},
 
+    #[error("Minting is paused")]
+    MintingPaused {},
+
+    #[error("Account `{address}` is frozen")]
+    AccountFrozen { address: String },
+
+    #[error("Address `{address}` is not on the transfer allowlist")]
+    NotTransferAllowlisted { address: String },
+
+    #[error("Transfer limit exceeded for `{address}`: {remaining_amount} remaining within the limit window, but got {requested_amount}")]
+    AddressTransferLimitExceeded {
+        address: String,
+        requested_amount: Uint128,
+        remaining_amount: Uint128,
+    },
+
+    #[error("Global transfer limit exceeded: {remaining_amount} remaining within the limit window, but got {requested_amount}")]
+    GlobalTransferLimitExceeded {
+        requested_amount: Uint128,
+        remaining_amount: Uint128,
+    },
+
+    #[error("Account `{address}` is not frozen")]
+    AccountNotFrozen { address: String },
+
+    #[error("Case reference is required for enforcement actions")]
+    CaseReferenceRequired {},
+
     #[error("Burn amount too small: required at least {min_burn_amount}, but got {requested_burn_amount}")]
     BurnAmountTooSmall {
         requested_burn_amount: Uint128,
         min_burn_amount: Uint128,
     },
 
+    #[error(
+        "Burn amount too large: allowed at most {max_burn_amount}, but got {requested_burn_amount}"
+    )]
+    BurnAmountTooLarge {
+        requested_burn_amount: Uint128,
+        max_burn_amount: Uint128,
+    },
+
+    #[error("Burn limit exceeded for merchant `{merchant}`: {remaining_burn_amount} remaining within the limit window, but got {requested_burn_amount}")]
+    MerchantBurnLimitExceeded {
+        merchant: String,
+        requested_burn_amount: Uint128,
+        remaining_burn_amount: Uint128,
+    },
+
+    #[error("Confirmed amount mismatch: burned {burned_amount}, but BTC amount sent {btc_amount_sent} plus network fee {network_fee} is not within tolerance {tolerance}")]
+    BurnConfirmationAmountMismatch {
+        burned_amount: Uint128,
+        btc_amount_sent: Uint128,
+        network_fee: Uint128,
+        tolerance: Uint128,
+    },
+
+    #[error("BTC output `{tx_id}:{vout}` has already been used to confirm burn request `{request_hash}`")]
+    BtcOutputAlreadyConfirmed {
+        tx_id: String,
+        vout: u32,
+        request_hash: String,
+    },
+
     #[error("This message does not accept funds")]
     NonPayable {},
+
+    #[error("No funds sent")]
+    NoFundsSent {},
+
+    #[error("Only single denom is accepted")]
+    MultipleDenomsSent {},
+
+    #[error("Invalid funds denom: expected `{expected}`, but got `{got}`")]
+    InvalidFundsDenom { expected: String, got: String },
+
+    #[error("Funds amount mismatch: expected {expected}, but got {got}")]
+    FundsAmountMismatch { expected: Uint128, got: Uint128 },
+
+    #[error("Redemption request `{request_hash}` can not be reclaimed before {reclaimable_at}")]
+    RedemptionNotReclaimable {
+        request_hash: String,
+        reclaimable_at: Timestamp,
+    },
+}
+
+/// Error of a single item in a batch, reported along with the errors of other items.
+#[derive(Debug, PartialEq)]
+pub struct BatchItemError {
+    pub address: String,
+    pub error: String,
+}
+
+// join the errors of all failing batch items into a single message
+fn format_batch_errors(errors: &[BatchItemError]) -> String {
+    errors
+        .iter()
+        .map(|e| format!("`{}`: {}", e.address, e.error))
+        .collect::<Vec<_>>()
+        .join("; ")
 }
 
 // ensure that the message sender is the merchant
@@ -48,6 +215,26 @@ pub fn non_payable(info: &MessageInfo) -> Result<(), ContractError> {
     Ok(())
 }
 
+// ensure that the message has exactly one coin of the given denom attached and return its amount
+pub fn must_pay(info: &MessageInfo, denom: &str) -> Result<Uint128, ContractError> {
+    let coin = match info.funds.as_slice() {
+        [] => return Err(ContractError::NoFundsSent {}),
+        [coin] => coin,
+        _ => return Err(ContractError::MultipleDenomsSent {}),
+    };
+
+    ensure!(
+        coin.denom == denom,
+        ContractError::InvalidFundsDenom {
+            expected: denom.to_string(),
+            got: coin.denom.clone()
+        }
+    );
+    ensure!(!coin.amount.is_zero(), ContractError::NoFundsSent {});
+
+    Ok(coin.amount)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -64,4 +251,42 @@ mod tests {
             ContractError::NonPayable {}
         );
     }
+
+    #[test]
+    fn test_must_pay() {
+        let denom = "factory/osmo1contract/wbtc";
+
+        let info = mock_info("osmo1xxx", &[Coin::new(100, denom)]);
+        assert_eq!(must_pay(&info, denom).unwrap(), Uint128::new(100));
+
+        let info = mock_info("osmo1xxx", &[]);
+        assert_eq!(
+            must_pay(&info, denom).unwrap_err(),
+            ContractError::NoFundsSent {}
+        );
+
+        let info = mock_info("osmo1xxx", &[Coin::new(0, denom)]);
+        assert_eq!(
+            must_pay(&info, denom).unwrap_err(),
+            ContractError::NoFundsSent {}
+        );
+
+        let info = mock_info("osmo1xxx", &[Coin::new(100, "uosmo")]);
+        assert_eq!(
+            must_pay(&info, denom).unwrap_err(),
+            ContractError::InvalidFundsDenom {
+                expected: denom.to_string(),
+                got: "uosmo".to_string()
+            }
+        );
+
+        let info = mock_info(
+            "osmo1xxx",
+            &[Coin::new(100, denom), Coin::new(100, "uosmo")],
+        );
+        assert_eq!(
+            must_pay(&info, denom).unwrap_err(),
+            ContractError::MultipleDenomsSent {}
+        );
+    }
 }
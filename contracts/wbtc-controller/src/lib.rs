@@ -7,7 +7,12 @@ pub mod msg;
 mod state;
 mod tokenfactory;
 
-pub use crate::error::ContractError;
-pub use tokenfactory::burn::BurnRequestStatus;
+pub use crate::auth::history::{RoleChange, RoleHistoryEntry, RoleHistoryEntryWithId};
+pub use crate::auth::holder::{HolderKind, RoleHolderKind, Voter};
+pub use crate::auth::permission::{Permission, PermissionGrants};
+pub use crate::auth::{require_all, require_any, Role};
+pub use crate::error::{BatchItemError, ContractError};
+pub use tokenfactory::burn::{BurnMode, BurnRequestStatus};
 pub use tokenfactory::mint::MintRequestStatus;
+pub use tokenfactory::redemption::RedemptionRequestStatus;
 pub use tokenfactory::{Request, RequestData, Status};
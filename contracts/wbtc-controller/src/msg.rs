@@ -1,10 +1,24 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Coin, Uint128};
+use cosmwasm_std::{Addr, Coin, Timestamp, Uint128};
+use cw2::ContractVersion;
+
+use crate::auth::{
+    history::RoleHistoryEntryWithId,
+    holder::{HolderKind, RoleHolderKind, Voter},
+    merchant::{MerchantInfo, MerchantProfile, MerchantStatus, NewMerchant},
+    operator::{OperatorInfo, OperatorPermission},
+    permission::{Permission, PermissionGrants},
+    Role,
+};
 use osmosis_std::types::cosmos::bank::v1beta1::Metadata;
 
 use crate::tokenfactory::{
-    burn::{BurnRequest, BurnRequestStatus, BurnRequestWithHash},
+    burn::{BurnConfirmation, BurnMode, BurnRequest, BurnRequestStatus, BurnRequestWithHash},
+    denom_admin::DenomAdminHandover,
+    enforcement::EnforcementRecordWithId,
+    holders::HolderInfo,
     mint::{MintRequest, MintRequestStatus, MintRequestWithHash},
+    redemption::{RedemptionRequestInfo, RedemptionRequestStatus},
 };
 
 #[cw_serde]
@@ -16,7 +30,33 @@ pub struct InstantiateMsg {
 
     /// Subdenom of the token that will be created on behalf of this contract
     /// The resulting denom will be tokenfactory denom: "factory/<contract_address>/<subdenom>"
+    /// Ignored if `existing_denom` is set.
     pub subdenom: String,
+
+    /// Existing tokenfactory denom to be adopted instead of creating a new one.
+    /// Its admin must be changed to this contract, then the governor activates it with `AcceptDenomAdmin`.
+    pub existing_denom: Option<String>,
+
+    /// Address of the initial member manager.
+    pub member_manager: Option<String>,
+
+    /// Address of the initial custodian.
+    pub custodian: Option<String>,
+
+    /// Addresses of the initial merchants.
+    pub merchants: Option<Vec<String>>,
+
+    /// Initial minimum burn amount.
+    pub min_burn_amount: Option<Uint128>,
+
+    /// Denom metadata, set once the contract is the denom admin.
+    /// `base` and the denom unit with exponent 0 are replaced with the token denom,
+    /// since it is only known after the denom is created.
+    pub denom_metadata: Option<Metadata>,
+
+    /// Require the governor and the custodian to be contracts, e.g. cw3 multisigs or DAOs.
+    /// Should be enabled on mainnet deployments. Cannot be disabled once enabled.
+    pub require_contract_holders: Option<bool>,
 }
 
 #[cw_serde]
@@ -24,37 +64,98 @@ pub enum ExecuteMsg {
     /// Transfer governorship of the contract to another address.
     TransferGovernorship { new_governor_address: String },
 
-    /// Claim governorship of the contract.
+    /// Claim governorship of the contract before the transfer expires.
     ClaimGovernorship {},
 
-    /// Set member manager contract address.
+    /// Cancel the pending governorship transfer. Message sender must be the governor.
+    CancelGovernorshipTransfer {},
+
+    /// Set member manager contract address, only if it is not set yet.
+    /// Message sender must be the governor.
     SetMemberManager { address: String },
 
-    /// Set custodian address.
+    /// Propose a candidate to take over the member manager role. Message sender must be the governor.
+    ProposeMemberManager { address: String },
+
+    /// Claim the member manager role. Message sender must be the member manager candidate.
+    ClaimMemberManager {},
+
+    /// Cancel the pending member manager candidate. Message sender must be the governor.
+    CancelMemberManagerProposal {},
+
+    /// Set custodian address, only if it is not set yet.
+    /// Message sender must be the member manager.
     SetCustodian { address: String },
 
-    /// Add merchant address.
-    AddMerchant { address: String },
+    /// Propose a candidate to take over the custodian role. Message sender must be the member manager.
+    ProposeCustodian { address: String },
+
+    /// Claim the custodian role. Message sender must be the custodian candidate.
+    ClaimCustodian {},
+
+    /// Cancel the pending custodian candidate. Message sender must be the member manager.
+    CancelCustodianProposal {},
+
+    /// Add merchant address, optionally with its profile.
+    AddMerchant {
+        address: String,
+        profile: Option<MerchantProfile>,
+    },
+
+    /// Add multiple merchants, optionally with their profiles and custodian deposit addresses.
+    /// Message sender must be the member manager, and have the `deposit.set_custodian` permission
+    /// to set custodian deposit addresses.
+    /// If any merchant fails, no merchant is added and the errors of all failing merchants are returned.
+    AddMerchants { merchants: Vec<NewMerchant> },
+
+    /// Remove multiple merchants. Message sender must be the member manager.
+    /// If any address fails, no merchant is removed and the errors of all failing addresses are returned.
+    RemoveMerchants { addresses: Vec<String> },
+
+    /// Set the profile of the merchant. Message sender must be the member manager.
+    SetMerchantProfile {
+        address: String,
+        profile: MerchantProfile,
+    },
 
     /// Remove merchant address.
     RemoveMerchant { address: String },
 
+    /// Suspend merchant while preserving its deposit addresses and history.
+    /// Message sender must be the member manager.
+    SuspendMerchant { address: String },
+
+    /// Reinstate suspended merchant. Message sender must be the member manager.
+    ReinstateMerchant { address: String },
+
     /// Set custodian BTC deposit address of the specified merchant
     SetCustodianDepositAddress {
         merchant: String,
         deposit_address: Option<String>,
     },
 
-    /// Set merchant BTC deposit address. Message sender must be a merchant.
-    /// This deposit address will be associated with message sender.
+    /// Set merchant BTC deposit address. Message sender must be a merchant or its operator.
+    /// This deposit address will be associated with the merchant.
     SetMerchantDepositAddress { deposit_address: Option<String> },
 
+    /// Authorize or update an operator to act on behalf of the sender merchant.
+    /// Message sender must be a merchant.
+    SetOperator {
+        operator: String,
+        permissions: Vec<OperatorPermission>,
+        /// If not specified, the operator never expires.
+        expires_at: Option<Timestamp>,
+    },
+
+    /// Revoke an operator of the sender merchant. Message sender must be a merchant.
+    RemoveOperator { operator: String },
+
     /// Issue request to mint tokens.
-    /// Only merchants can issue mint requests.
+    /// Only merchants or their operators can issue mint requests, the request belongs to the merchant.
     /// The request needs to be approved by the custodian in order to mint tokens.
     IssueMintRequest { amount: Uint128, tx_id: String },
 
-    /// Cancel mint request. Message sender must be the requester.
+    /// Cancel mint request. Message sender must be the requester or its operator.
     CancelMintRequest { request_hash: String },
 
     /// Approve mint request. Message sender must be the custodian.
@@ -65,26 +166,199 @@ pub enum ExecuteMsg {
     /// Reject mint request. Message sender must be the custodian.
     RejectMintRequest { request_hash: String },
 
-    /// Burn tokens. Message sender must be merchant.
-    /// Funds attached with execute message must match the amount of tokens and denom to be burned.
-    /// The tokens will be burned immediately and the BTC will be sent from custodian to the merchant's deposit address.
-    Burn { amount: Uint128 },
+    /// Burn tokens. Message sender must be merchant or its operator.
+    /// In `AttachedFunds` burn mode, funds attached with execute message must match the amount of tokens and denom to be burned.
+    /// In `BurnFromAddress` burn mode, no funds must be attached and the tokens are burned from the merchant's balance.
+    /// The tokens will be burned immediately and the BTC will be sent from custodian to the merchant's deposit address,
+    /// or to `btc_address` if specified. If the merchant enforces its payout address whitelist,
+    /// `btc_address` must be one of the merchant's payout addresses.
+    Burn {
+        amount: Uint128,
+        btc_address: Option<String>,
+    },
 
     /// Only custodian can execute this message.
     /// Once the custodian has sent the BTC to the merchant's deposit address, the custodian can confirm the burn request.
-    ConfirmBurnRequest { request_hash: String, tx_id: String },
+    /// `btc_amount_sent` plus `network_fee` must match the burned amount within the burn confirmation tolerance
+    /// and the output `tx_id:vout` can not be used to confirm more than one burn request.
+    /// `tx_id` must be 64 hex characters and is stored lowercased.
+    ConfirmBurnRequest {
+        request_hash: String,
+        tx_id: String,
+        btc_amount_sent: Uint128,
+        network_fee: Uint128,
+        vout: u32,
+    },
+
+    /// Add a BTC address to the sender's payout address whitelist. Message sender must be merchant.
+    AddPayoutAddress { btc_address: String },
+
+    /// Remove a BTC address from the sender's payout address whitelist. Message sender must be merchant.
+    RemovePayoutAddress { btc_address: String },
+
+    /// Set whether burns of the sender to `btc_address` must use a whitelisted payout address.
+    /// Message sender must be merchant.
+    SetPayoutAddressWhitelistEnforced { enforced: bool },
 
     /// Set minimum burn amount. Message sender must be the Custodian.
     SetMinBurnAmount { amount: Uint128 },
 
+    /// Set maximum amount of a single burn. Message sender must be the Custodian.
+    /// Setting it to `None` removes the maximum.
+    SetMaxBurnAmount { amount: Option<Uint128> },
+
+    /// Set maximum amount each merchant can burn within the rolling burn limit window (24 hours).
+    /// Message sender must be the Custodian. Setting it to `None` removes the limit.
+    SetMerchantBurnLimit { amount: Option<Uint128> },
+
+    /// Set how the tokens to be burned are collected from the merchant. Message sender must be the Governor.
+    SetBurnMode { mode: BurnMode },
+
+    /// Set the maximum difference allowed between the burned amount and
+    /// the BTC amount sent plus the network fee when confirming a burn request.
+    /// Message sender must be the Governor.
+    SetBurnConfirmationTolerance { amount: Uint128 },
+
+    /// Request redemption of the attached tokens through the specified merchant.
+    /// Anyone holding the token can request redemption.
+    /// Funds attached with execute message are the tokens to be redeemed,
+    /// they will be escrowed by the contract until the merchant accepts or declines the request.
+    RequestRedemption {
+        merchant: String,
+        /// BTC address of the holder, must not be a custodian or merchant deposit address.
+        btc_address: String,
+    },
+
+    /// Accept redemption request. Message sender must be the merchant chosen by the holder.
+    /// The escrowed tokens will be burned and a burn request will be created for the merchant
+    /// with the holder's BTC address as deposit address.
+    AcceptRedemption { request_hash: String },
+
+    /// Decline redemption request. Message sender must be the merchant chosen by the holder.
+    /// The escrowed tokens will be returned to the holder.
+    DeclineRedemption { request_hash: String },
+
+    /// Reclaim escrowed tokens of a pending redemption request once the redemption timeout has passed.
+    /// Message sender must be the holder who requested the redemption.
+    ReclaimRedemption { request_hash: String },
+
+    /// Set redemption timeout in seconds. Message sender must be the governor.
+    SetRedemptionTimeout { seconds: u64 },
+
     /// Set denom metadata. Message sender must be the governor.
     SetDenomMetadata { metadata: Metadata },
 
+    /// Schedule handing over the tokenfactory denom admin to a new controller contract.
+    /// The handover can be completed once the handover delay has passed. Message sender must be the governor.
+    /// `new_admin` must be a contract.
+    HandoverDenomAdmin { new_admin: String },
+
+    /// Complete the scheduled denom admin handover. The before-send hook is moved to the new admin,
+    /// which therefore must be a contract implementing the hook, and the denom admin is changed to the new admin.
+    /// The contract is retired afterwards and rejects all further mint and burn messages.
+    /// Message sender must be the governor.
+    CompleteDenomAdminHandover {},
+
+    /// Cancel the scheduled denom admin handover. Message sender must be the governor.
+    CancelDenomAdminHandover {},
+
+    /// Activate the existing denom set at instantiation once its admin has been changed to this contract.
+    /// Registers this contract as the before-send hook of the denom. Message sender must be the governor.
+    AcceptDenomAdmin {},
+
     /// Pause contract. Message sender must be the governor.
     Pause {},
 
     /// Unpause contract. Message sender must be the governor.
     Unpause {},
+
+    /// Pause issuing and approving mint requests. Message sender must be the governor or the custodian.
+    PauseMinting {},
+
+    /// Unpause minting. Message sender must be the governor or the custodian.
+    UnpauseMinting {},
+
+    /// Set compliance officer. Message sender must be the governor.
+    SetComplianceOfficer { address: String },
+
+    /// Declare whether the role must be held by an account or a contract, or remove the declaration with `None`.
+    /// The current holder must be of the declared kind. Message sender must be the governor.
+    /// If contract holders are required, the governor and the custodian must be declared as contracts.
+    SetRoleHolderKind {
+        role: Role,
+        kind: Option<HolderKind>,
+    },
+
+    /// Grant the permission to the address in addition to the holder of its default role.
+    /// Message sender must be the governor. Merchants, operators, role candidates, and the governor
+    /// for custodian permissions cannot be granted permissions, and a grantee cannot take a priviledged role.
+    GrantPermission {
+        permission: Permission,
+        address: String,
+    },
+
+    /// Revoke the permission explicitly granted to the address.
+    /// Message sender must be the governor.
+    RevokePermission {
+        permission: Permission,
+        address: String,
+    },
+
+    /// Enable or disable granting the permission to the holder of its default role,
+    /// e.g. to move a custodian power to a separate key. Message sender must be the governor.
+    SetDefaultPermissionGrant {
+        permission: Permission,
+        enabled: bool,
+    },
+
+    /// Enable or disable restricting token transfers to allowlisted addresses.
+    /// Message sender must be the governor.
+    SetTransferAllowlistEnabled { enabled: bool },
+
+    /// Add addresses to the transfer allowlist. Message sender must be the compliance officer.
+    AddToTransferAllowlist { addresses: Vec<String> },
+
+    /// Remove addresses from the transfer allowlist. Message sender must be the compliance officer.
+    RemoveFromTransferAllowlist { addresses: Vec<String> },
+
+    /// Set the maximum amount a single address can send within the transfer limit window.
+    /// Message sender must be the governor. Setting it to `None` removes the limit.
+    SetAddressTransferLimit { amount: Option<Uint128> },
+
+    /// Set the maximum total amount that can be sent by all non-exempt addresses within the transfer limit window.
+    /// Message sender must be the governor. Setting it to `None` removes the limit.
+    SetGlobalTransferLimit { amount: Option<Uint128> },
+
+    /// Exempt the address from transfer limits, e.g. merchants or known pools.
+    /// Message sender must be the governor.
+    AddTransferLimitExemption { address: String },
+
+    /// Remove the transfer limit exemption of the address. Message sender must be the governor.
+    RemoveTransferLimitExemption { address: String },
+
+    /// Freeze the account, blocking all token transfers from and to it.
+    /// Message sender must be the governor. The action is recorded in the enforcement log.
+    FreezeAccount {
+        account: String,
+        case_reference: String,
+    },
+
+    /// Unfreeze the account. Message sender must be the governor.
+    /// The action is recorded in the enforcement log.
+    UnfreezeAccount {
+        account: String,
+        case_reference: String,
+    },
+
+    /// Transfer tokens from any account using tokenfactory's force transfer,
+    /// even if the account is frozen or token transfers are paused.
+    /// Message sender must be the governor. The action is recorded in the enforcement log.
+    ForceTransfer {
+        from: String,
+        to: String,
+        amount: Uint128,
+        case_reference: String,
+    },
 }
 
 #[cw_serde]
@@ -152,19 +426,148 @@ pub enum QueryMsg {
     #[returns(GetMinBurnAmountResponse)]
     GetMinBurnAmount {},
 
+    /// List whitelisted payout addresses of the specified merchant.
+    #[returns(ListPayoutAddressesResponse)]
+    ListPayoutAddresses {
+        merchant: String,
+        /// Maximum number of results to return.
+        /// If not specified, default to 10.
+        limit: Option<u32>,
+        start_after: Option<String>,
+    },
+
+    /// Check if the specified merchant only allows burning to whitelisted payout addresses.
+    #[returns(IsPayoutAddressWhitelistEnforcedResponse)]
+    IsPayoutAddressWhitelistEnforced { merchant: String },
+
+    /// Get burn amount limits and the remaining burn amount of the specified merchant.
+    #[returns(GetBurnLimitsResponse)]
+    GetBurnLimits { merchant: String },
+
+    /// Get the current burn mode.
+    #[returns(GetBurnModeResponse)]
+    GetBurnMode {},
+
+    /// Get the burn confirmation tolerance.
+    #[returns(GetBurnConfirmationToleranceResponse)]
+    GetBurnConfirmationTolerance {},
+
+    /// Get BTC transaction details of a confirmed burn request.
+    #[returns(GetBurnConfirmationResponse)]
+    GetBurnConfirmation { request_hash: String },
+
+    /// Get redemption request by hash.
+    #[returns(GetRedemptionRequestByHashResponse)]
+    GetRedemptionRequestByHash { request_hash: String },
+
+    /// Count total redemption requests.
+    #[returns(GetRedemptionRequestsCountResponse)]
+    GetRedemptionRequestsCount {},
+
+    /// List redemption requests with filter and pagination.
+    /// Ordered by nonce.
+    #[returns(ListRedemptionRequestsResponse)]
+    ListRedemptionRequests {
+        /// Maximum number of results to return.
+        /// If not specified, default to 10.
+        /// Max limit is 100.
+        limit: Option<u32>,
+
+        /// Start after the specified nonce.
+        start_after_nonce: Option<Uint128>,
+
+        /// Filter by status.
+        /// If not specified, default to all statuses.
+        status: Option<RedemptionRequestStatus>,
+    },
+
+    /// Get redemption timeout in seconds.
+    #[returns(GetRedemptionTimeoutResponse)]
+    GetRedemptionTimeout {},
+
     /// Get token denom associated with this contract.
     #[returns(GetTokenDenomResponse)]
     GetTokenDenom {},
 
+    /// Get all contract settings and the contract version in a single query.
+    #[returns(GetConfigResponse)]
+    GetConfig {},
+
+    /// Check if the address has the permission, either explicitly granted or through its default role.
+    #[returns(HasPermissionResponse)]
+    HasPermission {
+        permission: Permission,
+        address: String,
+    },
+
+    /// List the addresses explicitly granted the permission and its default role.
+    #[returns(ListPermissionGrantsResponse)]
+    ListPermissionGrants {
+        permission: Permission,
+
+        /// Maximum number of grantees to return.
+        limit: Option<u32>,
+
+        /// Start after the specified grantee address.
+        start_after: Option<String>,
+    },
+
+    /// List the history of role and permission grants and revocations with pagination, ordered by id.
+    /// Optionally only the changes of the role and/or the address, permission changes are excluded when filtering by role.
+    #[returns(ListRoleHistoryResponse)]
+    ListRoleHistory {
+        role: Option<Role>,
+        address: Option<String>,
+        limit: Option<u32>,
+        start_after: Option<Uint128>,
+    },
+
+    /// Get the declared holder kinds of roles and whether the governor and the custodian must be contracts.
+    #[returns(GetRoleHolderKindsResponse)]
+    GetRoleHolderKinds {},
+
+    /// List the voters of the cw3 contract holding the role, e.g. the signers of a multisig governor.
+    /// Only roles held by a single address are supported.
+    #[returns(ListRoleHolderVotersResponse)]
+    ListRoleHolderVoters {
+        role: Role,
+
+        /// Maximum number of results to return, passed to the cw3 contract.
+        limit: Option<u32>,
+
+        /// Start after the specified voter address, passed to the cw3 contract.
+        start_after: Option<String>,
+    },
+
+    /// Get all role holders in a single query. Unset roles are returned as `None`.
+    /// Merchants are paginated, use `ListMerchants` for their status and profile.
+    #[returns(GetRolesResponse)]
+    GetRoles {
+        /// Maximum number of merchants to return.
+        limit: Option<u32>,
+
+        /// Start after the specified merchant address.
+        start_after: Option<String>,
+    },
+
+    /// Get all roles held by the address, with merchant specific data if it is a merchant.
+    #[returns(GetAddressRolesResponse)]
+    GetAddressRoles { address: String },
+
     /// Check if the specified address is a merchant.
     #[returns(IsMerchantResponse)]
     IsMerchant { address: String },
 
-    /// List merchants with pagination.
-    /// Ordered by address.
+    /// Get the status and profile of the specified merchant.
+    #[returns(GetMerchantResponse)]
+    GetMerchant { address: String },
+
+    /// List merchants with their status and profile with pagination.
+    /// Ordered by address. At most `limit` merchants are scanned, so a page filtered by status
+    /// may hold fewer merchants than `limit`, continue with `next_start_after` until it is `None`.
     #[returns(ListMerchantsResponse)]
     ListMerchants {
-        /// Maximum number of results to return.
+        /// Maximum number of merchants to scan.
         /// If not specified, default to 10.
         /// Max limit is 100.
         limit: Option<u32>,
@@ -172,6 +575,10 @@ pub enum QueryMsg {
         /// Start after the specified address.
         /// If not specified, default to the first address.
         start_after: Option<String>,
+
+        /// Only list merchants with the specified status.
+        /// If not specified, list all merchants.
+        status: Option<MerchantStatus>,
     },
 
     /// Check if the specified address is a member manager.
@@ -206,6 +613,22 @@ pub enum QueryMsg {
     #[returns(IsGovernorCandidateResponse)]
     IsGovernorCandidate { address: String },
 
+    /// Get member manager candidate address.
+    #[returns(GetMemberManagerCandidateResponse)]
+    GetMemberManagerCandidate {},
+
+    /// Check if the specified address is the member manager candidate.
+    #[returns(IsMemberManagerCandidateResponse)]
+    IsMemberManagerCandidate { address: String },
+
+    /// Get custodian candidate address.
+    #[returns(GetCustodianCandidateResponse)]
+    GetCustodianCandidate {},
+
+    /// Check if the specified address is the custodian candidate.
+    #[returns(IsCustodianCandidateResponse)]
+    IsCustodianCandidate { address: String },
+
     /// Get custodian deposit address of the specified merchant.
     #[returns(GetCustodianDepositAddressResponse)]
     GetCustodianDepositAddress { merchant: String },
@@ -214,9 +637,109 @@ pub enum QueryMsg {
     #[returns(GetMerchantDepositAddressResponse)]
     GetMerchantDepositAddress { merchant: String },
 
+    /// Get the operator and its grant.
+    #[returns(GetOperatorResponse)]
+    GetOperator { address: String },
+
+    /// List operators of the specified merchant with pagination, including expired ones.
+    /// Ordered by address.
+    #[returns(ListOperatorsResponse)]
+    ListOperators {
+        merchant: String,
+
+        /// Maximum number of results to return.
+        /// If not specified, default to 10.
+        /// Max limit is 100.
+        limit: Option<u32>,
+
+        /// Start after the specified address.
+        /// If not specified, default to the first address.
+        start_after: Option<String>,
+    },
+
+    /// Get the scheduled denom admin handover.
+    #[returns(GetDenomAdminHandoverResponse)]
+    GetDenomAdminHandover {},
+
+    /// Get the existing denom to be adopted that is waiting for `AcceptDenomAdmin`.
+    #[returns(GetPendingDenomResponse)]
+    GetPendingDenom {},
+
+    /// Check if the contract has handed over the denom admin.
+    #[returns(IsRetiredResponse)]
+    IsRetired {},
+
     /// Check if token transfers are paused.
     #[returns(IsPausedResponse)]
     IsPaused {},
+
+    /// Check if minting is paused.
+    #[returns(IsMintingPausedResponse)]
+    IsMintingPaused {},
+
+    /// Get compliance officer address.
+    #[returns(GetComplianceOfficerResponse)]
+    GetComplianceOfficer {},
+
+    /// Check if the specified address is the compliance officer.
+    #[returns(IsComplianceOfficerResponse)]
+    IsComplianceOfficer { address: String },
+
+    /// Check if token transfers are restricted to allowlisted addresses.
+    #[returns(IsTransferAllowlistEnabledResponse)]
+    IsTransferAllowlistEnabled {},
+
+    /// Check if the specified address is on the transfer allowlist.
+    #[returns(IsTransferAllowlistedResponse)]
+    IsTransferAllowlisted { address: String },
+
+    /// List transfer allowlisted addresses with pagination.
+    #[returns(ListTransferAllowlistResponse)]
+    ListTransferAllowlist {
+        limit: Option<u32>,
+        start_after: Option<String>,
+    },
+
+    /// List current holders, i.e. addresses with a non-zero balance, with their transfer activity, with pagination.
+    #[returns(ListHoldersResponse)]
+    ListHolders {
+        limit: Option<u32>,
+        start_after: Option<String>,
+    },
+
+    /// Count current holders, i.e. addresses with a non-zero balance.
+    #[returns(GetHolderCountResponse)]
+    GetHolderCount {},
+
+    /// Get transfer limits and the remaining amount the specified address and all addresses can send.
+    #[returns(GetTransferLimitsResponse)]
+    GetTransferLimits { address: String },
+
+    /// List addresses exempt from transfer limits with pagination.
+    #[returns(ListTransferLimitExemptionsResponse)]
+    ListTransferLimitExemptions {
+        limit: Option<u32>,
+        start_after: Option<String>,
+    },
+
+    /// Check if the account is frozen.
+    #[returns(IsFrozenResponse)]
+    IsFrozen { account: String },
+
+    /// List frozen accounts with pagination.
+    #[returns(ListFrozenAccountsResponse)]
+    ListFrozenAccounts {
+        limit: Option<u32>,
+        start_after: Option<String>,
+    },
+
+    /// List enforcement log records with pagination.
+    /// Ordered by id.
+    #[returns(ListEnforcementRecordsResponse)]
+    ListEnforcementRecords {
+        limit: Option<u32>,
+        start_after: Option<Uint128>,
+    },
 }
 
 #[cw_serde]
@@ -261,19 +784,128 @@ pub struct ListBurnRequestsResponse {
     pub requests: Vec<BurnRequestWithHash>,
 }
 
+#[cw_serde]
+pub struct GetRedemptionRequestByHashResponse {
+    pub request: RedemptionRequestInfo,
+}
+
+#[cw_serde]
+pub struct GetRedemptionRequestsCountResponse {
+    pub count: Uint128,
+}
+
+#[cw_serde]
+pub struct ListRedemptionRequestsResponse {
+    pub requests: Vec<RedemptionRequestInfo>,
+}
+
+#[cw_serde]
+pub struct GetRedemptionTimeoutResponse {
+    pub seconds: u64,
+}
+
 #[cw_serde]
 pub struct GetTokenDenomResponse {
     pub denom: String,
 }
 
+#[cw_serde]
+pub struct GetConfigResponse {
+    pub contract_version: ContractVersion,
+    /// `None` until the denom is created or adopted.
+    pub token_denom: Option<String>,
+    pub pending_denom: Option<String>,
+    pub is_paused: bool,
+    pub is_minting_paused: bool,
+    pub is_retired: bool,
+    pub min_burn_amount: Uint128,
+    pub max_burn_amount: Option<Uint128>,
+    pub merchant_burn_limit: Option<Uint128>,
+    pub burn_mode: BurnMode,
+    pub burn_confirmation_tolerance: Uint128,
+    pub redemption_timeout_seconds: u64,
+    pub transfer_allowlist_enabled: bool,
+    pub address_transfer_limit: Option<Uint128>,
+    pub global_transfer_limit: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct GetAddressRolesResponse {
+    pub roles: Vec<Role>,
+    /// `None` if the address is not a merchant.
+    pub merchant: Option<MerchantRoleInfo>,
+}
+
+#[cw_serde]
+pub struct MerchantRoleInfo {
+    pub status: MerchantStatus,
+    pub custodian_deposit_address: Option<String>,
+    pub merchant_deposit_address: Option<String>,
+}
+
+#[cw_serde]
+pub struct HasPermissionResponse {
+    pub has_permission: bool,
+}
+
+#[cw_serde]
+pub struct ListPermissionGrantsResponse {
+    pub grants: PermissionGrants,
+}
+
+#[cw_serde]
+pub struct ListRoleHistoryResponse {
+    pub entries: Vec<RoleHistoryEntryWithId>,
+}
+
+#[cw_serde]
+pub struct GetRoleHolderKindsResponse {
+    pub contract_holders_required: bool,
+    pub kinds: Vec<RoleHolderKind>,
+}
+
+#[cw_serde]
+pub struct ListRoleHolderVotersResponse {
+    pub voters: Vec<Voter>,
+}
+
+#[cw_serde]
+pub struct GetRolesResponse {
+    pub governor: Addr,
+    pub governor_candidate: Option<Addr>,
+    pub member_manager: Option<Addr>,
+    pub member_manager_candidate: Option<Addr>,
+    pub custodian: Option<Addr>,
+    pub custodian_candidate: Option<Addr>,
+    pub compliance_officer: Option<Addr>,
+    pub merchants: Vec<Addr>,
+}
+
 #[cw_serde]
 pub struct IsMerchantResponse {
     pub is_merchant: bool,
 }
 
+#[cw_serde]
+pub struct GetOperatorResponse {
+    pub operator: OperatorInfo,
+}
+
+#[cw_serde]
+pub struct ListOperatorsResponse {
+    pub operators: Vec<OperatorInfo>,
+}
+
+#[cw_serde]
+pub struct GetMerchantResponse {
+    pub merchant: MerchantInfo,
+}
+
 #[cw_serde]
 pub struct ListMerchantsResponse {
-    pub merchants: Vec<Addr>,
+    pub merchants: Vec<MerchantInfo>,
+    /// Address to continue listing after, `None` if there are no more merchants.
+    pub next_start_after: Option<Addr>,
 }
 
 #[cw_serde]
@@ -304,6 +936,8 @@ pub struct GetGovernorResponse {
 #[cw_serde]
 pub struct GetGovernorCandidateResponse {
     pub address: Option<Addr>,
+    /// Time after which the candidate can no longer claim the governorship.
+    pub expires_at: Option<Timestamp>,
 }
 
 #[cw_serde]
@@ -316,6 +950,26 @@ pub struct IsGovernorCandidateResponse {
     pub is_governor_candidate: bool,
 }
 
+#[cw_serde]
+pub struct GetMemberManagerCandidateResponse {
+    pub address: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct IsMemberManagerCandidateResponse {
+    pub is_member_manager_candidate: bool,
+}
+
+#[cw_serde]
+pub struct GetCustodianCandidateResponse {
+    pub address: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct IsCustodianCandidateResponse {
+    pub is_custodian_candidate: bool,
+}
+
 #[cw_serde]
 pub struct GetCustodianDepositAddressResponse {
     pub address: String,
@@ -326,16 +980,145 @@ pub struct GetMerchantDepositAddressResponse {
     pub address: String,
 }
 
+#[cw_serde]
+pub struct GetDenomAdminHandoverResponse {
+    pub handover: Option<DenomAdminHandover>,
+}
+
+#[cw_serde]
+pub struct GetPendingDenomResponse {
+    pub denom: Option<String>,
+}
+
+#[cw_serde]
+pub struct IsRetiredResponse {
+    pub is_retired: bool,
+}
+
 #[cw_serde]
 pub struct IsPausedResponse {
     pub is_paused: bool,
 }
 
+#[cw_serde]
+pub struct IsMintingPausedResponse {
+    pub is_minting_paused: bool,
+}
+
+#[cw_serde]
+pub struct GetComplianceOfficerResponse {
+    pub address: Addr,
+}
+
+#[cw_serde]
+pub struct IsComplianceOfficerResponse {
+    pub is_compliance_officer: bool,
+}
+
+#[cw_serde]
+pub struct IsTransferAllowlistEnabledResponse {
+    pub is_enabled: bool,
+}
+
+#[cw_serde]
+pub struct IsTransferAllowlistedResponse {
+    pub is_allowlisted: bool,
+}
+
+#[cw_serde]
+pub struct ListTransferAllowlistResponse {
+    pub addresses: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct ListHoldersResponse {
+    pub holders: Vec<HolderInfo>,
+}
+
+#[cw_serde]
+pub struct GetHolderCountResponse {
+    pub count: u64,
+}
+
+#[cw_serde]
+pub struct GetTransferLimitsResponse {
+    pub address_transfer_limit: Option<Uint128>,
+    pub global_transfer_limit: Option<Uint128>,
+    pub window_seconds: u64,
+    /// Whether the address is exempt from transfer limits.
+    pub is_exempt: bool,
+    /// Amount sent by the address within the current window.
+    pub sent_amount: Uint128,
+    /// Amount the address can still send within the current window, `None` if there is no limit.
+    pub remaining_amount: Option<Uint128>,
+    /// Amount sent by all non-exempt addresses within the current window.
+    pub globally_sent_amount: Uint128,
+    /// Amount all non-exempt addresses can still send within the current window, `None` if there is no limit.
+    pub globally_remaining_amount: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct ListTransferLimitExemptionsResponse {
+    pub addresses: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct IsFrozenResponse {
+    pub is_frozen: bool,
+}
+
+#[cw_serde]
+pub struct ListFrozenAccountsResponse {
+    pub accounts: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct ListEnforcementRecordsResponse {
+    pub records: Vec<EnforcementRecordWithId>,
+}
+
 #[cw_serde]
 pub struct GetMinBurnAmountResponse {
     pub amount: Uint128,
 }
 
+#[cw_serde]
+pub struct ListPayoutAddressesResponse {
+    pub addresses: Vec<String>,
+}
+
+#[cw_serde]
+pub struct IsPayoutAddressWhitelistEnforcedResponse {
+    pub is_enforced: bool,
+}
+
+#[cw_serde]
+pub struct GetBurnLimitsResponse {
+    pub min_burn_amount: Uint128,
+    pub max_burn_amount: Option<Uint128>,
+    pub merchant_burn_limit: Option<Uint128>,
+    pub window_seconds: u64,
+    /// Amount burned by the merchant within the current window.
+    pub burned_amount: Uint128,
+    /// Amount the merchant can still burn within the current window, `None` if there is no limit.
+    pub remaining_burn_amount: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct GetBurnModeResponse {
+    pub mode: BurnMode,
+}
+
+#[cw_serde]
+pub struct GetBurnConfirmationToleranceResponse {
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct GetBurnConfirmationResponse {
+    pub confirmation: BurnConfirmation,
+}
+
 /// SudoMsg is only exposed for internal Cosmos SDK modules to call.
 /// This is showing how we can expose "admin" functionality than can not be called by
 /// external users or contracts, but only trusted (native/Go) code in the blockchain
@@ -346,4 +1129,19 @@ pub enum SudoMsg {
         to: String,
         amount: Coin,
     },
+    TrackBeforeSend {
+        from: String,
+        to: String,
+        amount: Coin,
+    },
+
+    /// Set the governor, bypassing the role checks. Only callable by chain governance,
+    /// as the root of recovery if the governor key is lost.
+    ForceSetGovernor { address: String },
+
+    /// Pause token transfer, bypassing the role checks. Only callable by chain governance.
+    ForcePause {},
+
+    /// Set the custodian, bypassing the role checks. Only callable by chain governance.
+    ForceSetCustodian { address: String },
 }
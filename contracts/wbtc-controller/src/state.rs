@@ -1,30 +1,97 @@
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Timestamp, Uint128};
 use cw_storage_plus::{Item, Map};
 
 use crate::{
-    tokenfactory::{deposit_address::DepositAddressManager, RequestManager},
-    BurnRequestStatus, MintRequestStatus,
+    tokenfactory::{
+        deposit_address::DepositAddressManager, redemption::RedemptionRouting, RequestManager,
+        RollingWindow,
+    },
+    BurnMode, BurnRequestStatus, MintRequestStatus, RedemptionRequestStatus,
 };
 pub mod auth {
-    use crate::auth::governor::GovernorState;
+    use cw_storage_plus::{IndexedMap, MultiIndex};
+
+    use crate::{
+        auth::{
+            governor::GovernorState,
+            history::{entry_role_index_key, RoleHistoryEntry, RoleHistoryIndexes},
+            holder::HolderKind,
+            merchant::MerchantProfile,
+            operator::OperatorGrant,
+        },
+        tokenfactory::Nonce,
+    };
 
     use super::*;
 
     pub const CUSTODIAN: Item<Addr> = Item::new("custodian");
     pub const GOVERNOR: Item<GovernorState> = Item::new("governor");
     pub const MEMBER_MANAGER: Item<Addr> = Item::new("member_manager");
+    pub const MEMBER_MANAGER_CANDIDATE: Item<Addr> = Item::new("member_manager_candidate");
+    pub const CUSTODIAN_CANDIDATE: Item<Addr> = Item::new("custodian_candidate");
+    pub const COMPLIANCE_OFFICER: Item<Addr> = Item::new("compliance_officer");
+
+    /// Kind of address that must hold the role, keyed by role
+    pub const ROLE_HOLDER_KINDS: Map<&str, HolderKind> = Map::new("role_holder_kinds");
+
+    /// Whether the governor and the custodian are required to be contracts
+    pub const CONTRACT_HOLDERS_REQUIRED: Item<bool> = Item::new("contract_holders_required");
 
     /// Merchants storage is a map of merchant addresses to empty values
     /// This makes it efficient to check if a merchant exists while not storing any data as value
     pub const MERCHANTS: Map<Addr, ()> = Map::new("merchants");
+
+    /// Suspended merchants, kept apart from `MERCHANTS` so that suspension preserves the merchant's data
+    pub const SUSPENDED_MERCHANTS: Map<Addr, ()> = Map::new("suspended_merchants");
+
+    /// Merchant profiles, kept apart from `MERCHANTS` so that merchants can exist without a profile
+    pub const MERCHANT_PROFILES: Map<Addr, MerchantProfile> = Map::new("merchant_profiles");
+
+    /// Time each merchant was added, recorded by the contract rather than supplied with the profile
+    pub const MERCHANT_ONBOARDED_AT: Map<Addr, Timestamp> = Map::new("merchant_onboarded_at");
+
+    /// Operator grants keyed by merchant and operator address
+    pub const MERCHANT_OPERATORS: Map<(Addr, Addr), OperatorGrant> = Map::new("merchant_operators");
+
+    /// Merchant of each operator, an operator can only act on behalf of a single merchant
+    pub const OPERATOR_MERCHANT: Map<Addr, Addr> = Map::new("operator_merchant");
+
+    /// Explicit permission grants keyed by permission and grantee address
+    pub const PERMISSION_GRANTS: Map<(&str, Addr), ()> = Map::new("permission_grants");
+
+    /// Permissions which are not granted to the holder of their default role
+    pub const DISABLED_DEFAULT_GRANTS: Map<&str, ()> = Map::new("disabled_default_grants");
+
+    /// Append-only history of role changes, keyed by sequential id.
+    pub fn role_history<'a>() -> IndexedMap<'a, u128, RoleHistoryEntry, RoleHistoryIndexes<'a>> {
+        let indexes = RoleHistoryIndexes {
+            role: MultiIndex::new(
+                |_pk: &[u8], entry: &RoleHistoryEntry| entry_role_index_key(entry),
+                "role_history",
+                "role_history__role",
+            ),
+            address: MultiIndex::new(
+                |_pk: &[u8], entry: &RoleHistoryEntry| entry.address.clone(),
+                "role_history",
+                "role_history__address",
+            ),
+        };
+        IndexedMap::new("role_history", indexes)
+    }
+    pub const ROLE_HISTORY_NONCE: Nonce = Nonce::new("role_history_nonce");
 }
 
 pub mod token {
+    use osmosis_std::types::cosmos::bank::v1beta1::Metadata;
+
     use super::*;
 
     /// Token denom storage.
     pub const TOKEN_DENOM: Item<String> = Item::new("token_denom");
 
+    /// Denom metadata provided at instantiation, set once the contract is the denom admin.
+    pub const INITIAL_DENOM_METADATA: Item<Metadata> = Item::new("initial_denom_metadata");
+
     /// Pause status storage.
     pub const IS_PAUSED: Item<bool> = Item::new("is_paused");
 }
@@ -41,9 +108,17 @@ pub mod mint {
             "mint_nonce",
         )
     }
+
+    /// Minting pause status, independent from the token transfer pause.
+    pub const MINTING_PAUSED: Item<bool> = Item::new("minting_paused");
 }
 
 pub mod burn {
+    use crate::{
+        constants::{BURN_LIMIT_BUCKET_SECONDS, BURN_LIMIT_WINDOW_SECONDS},
+        tokenfactory::burn::BurnConfirmation,
+    };
+
     use super::*;
 
     /// Burn request manager.
@@ -57,6 +132,67 @@ pub mod burn {
     }
 
     pub const MIN_BURN_AMOUNT: Item<Uint128> = Item::new("min_burn_amount");
+    pub const BURN_MODE: Item<BurnMode> = Item::new("burn_mode");
+
+    /// BTC transaction details of confirmed burn requests, keyed by request hash.
+    pub const BURN_CONFIRMATIONS: Map<String, BurnConfirmation> = Map::new("burn_confirmations");
+
+    /// BTC transaction outputs used to confirm burn requests, keyed by (tx_id, vout)
+    /// with the confirmed request hash as value. Ensures an output is not reused.
+    pub const CONFIRMED_BTC_OUTPUTS: Map<(String, u32), String> = Map::new("confirmed_btc_outputs");
+
+    /// Maximum difference allowed between the burned amount and the confirmed BTC amount plus network fee.
+    pub const BURN_CONFIRMATION_TOLERANCE: Item<Uint128> = Item::new("burn_confirmation_tolerance");
+
+    /// Maximum amount of a single burn. No maximum if not set.
+    pub const MAX_BURN_AMOUNT: Item<Uint128> = Item::new("max_burn_amount");
+
+    /// Maximum amount each merchant can burn within the burn limit window. No limit if not set.
+    pub const MERCHANT_BURN_LIMIT: Item<Uint128> = Item::new("merchant_burn_limit");
+
+    /// Amount burned by each merchant within the burn limit window.
+    pub const BURNED_PER_MERCHANT: RollingWindow = RollingWindow::new(
+        "burned_per_merchant",
+        BURN_LIMIT_WINDOW_SECONDS,
+        BURN_LIMIT_BUCKET_SECONDS,
+    );
+}
+
+pub mod redemption {
+    use super::*;
+
+    /// Redemption request manager.
+    pub fn redemption_requests<'a>() -> RequestManager<'a, RedemptionRequestStatus> {
+        RequestManager::new(
+            "redemption_requests",
+            "redemption_requests__nonce",
+            "redemption_requests__status_and_nonce",
+            "redemption_nonce",
+        )
+    }
+
+    /// Mapping between redemption request hash to the merchant routing the redemption
+    /// and the burn request created once the merchant accepted it.
+    pub const REDEMPTION_ROUTING: Map<String, RedemptionRouting> = Map::new("redemption_routing");
+
+    /// Period in seconds after which a pending redemption request can be reclaimed by the holder.
+    pub const REDEMPTION_TIMEOUT: Item<u64> = Item::new("redemption_timeout");
+}
+
+pub mod denom_admin {
+    use crate::tokenfactory::denom_admin::DenomAdminHandover;
+
+    use super::*;
+
+    /// Scheduled handover of the denom admin to a new controller.
+    pub const PENDING_DENOM_ADMIN_HANDOVER: Item<DenomAdminHandover> =
+        Item::new("pending_denom_admin_handover");
+
+    /// Existing denom to be adopted once its admin has been changed to this contract.
+    pub const PENDING_DENOM: Item<String> = Item::new("pending_denom");
+
+    /// Whether the denom admin has been handed over, after which minting and burning are disabled.
+    pub const RETIRED: Item<bool> = Item::new("retired");
 }
 
 pub mod deposit_address {
@@ -80,3 +216,85 @@ pub mod deposit_address {
     pub const MERCHANT_DEPOSIT_ADDRESS: DepositAddressManager =
         DepositAddressManager::new("merchant_deposit_address", &DEPOSIT_ADDRESS_TRACKER);
 }
+
+pub mod enforcement {
+    use crate::tokenfactory::{
+        enforcement::{EnforcementRecord, PendingForceTransfer},
+        Nonce,
+    };
+
+    use super::*;
+
+    /// Frozen accounts, token transfers from and to these accounts are blocked.
+    pub const FROZEN_ACCOUNTS: Map<Addr, ()> = Map::new("frozen_accounts");
+
+    /// Append-only log of enforcement actions, keyed by sequential id.
+    pub const ENFORCEMENT_LOG: Map<u128, EnforcementRecord> = Map::new("enforcement_log");
+    pub const ENFORCEMENT_LOG_NONCE: Nonce = Nonce::new("enforcement_log_nonce");
+
+    /// Force transfer being executed, only set until the force transfer reply is handled.
+    pub const PENDING_FORCE_TRANSFER: Item<PendingForceTransfer> =
+        Item::new("pending_force_transfer");
+}
+
+pub mod transfer_allowlist {
+    use super::*;
+
+    /// Addresses allowed to send and receive the token when the transfer allowlist mode is enabled.
+    pub const TRANSFER_ALLOWLIST: Map<Addr, ()> = Map::new("transfer_allowlist");
+
+    /// Whether token transfers are restricted to allowlisted addresses.
+    pub const TRANSFER_ALLOWLIST_ENABLED: Item<bool> = Item::new("transfer_allowlist_enabled");
+}
+
+pub mod holders {
+    use crate::tokenfactory::holders::HolderActivity;
+
+    use super::*;
+
+    /// Transfer activity per holder, tracked by the `TrackBeforeSend` hook.
+    pub const HOLDERS: Map<Addr, HolderActivity> = Map::new("holders");
+
+    /// Number of tracked holders.
+    pub const HOLDER_COUNT: Item<u64> = Item::new("holder_count");
+}
+
+pub mod transfer_limit {
+    use crate::constants::{TRANSFER_LIMIT_BUCKET_SECONDS, TRANSFER_LIMIT_WINDOW_SECONDS};
+
+    use super::*;
+
+    /// Maximum amount a single address can send within the transfer limit window. No limit if not set.
+    pub const ADDRESS_TRANSFER_LIMIT: Item<Uint128> = Item::new("address_transfer_limit");
+
+    /// Maximum amount all non-exempt addresses can send within the transfer limit window. No limit if not set.
+    pub const GLOBAL_TRANSFER_LIMIT: Item<Uint128> = Item::new("global_transfer_limit");
+
+    /// Addresses exempt from the transfer limits.
+    pub const TRANSFER_LIMIT_EXEMPTIONS: Map<Addr, ()> = Map::new("transfer_limit_exemptions");
+
+    /// Amount sent by each address within the transfer limit window.
+    pub const SENT_PER_ADDRESS: RollingWindow = RollingWindow::new(
+        "sent_per_address",
+        TRANSFER_LIMIT_WINDOW_SECONDS,
+        TRANSFER_LIMIT_BUCKET_SECONDS,
+    );
+
+    /// Amount sent by all non-exempt addresses within the transfer limit window.
+    pub const SENT_GLOBALLY: RollingWindow = RollingWindow::new(
+        "sent_globally",
+        TRANSFER_LIMIT_WINDOW_SECONDS,
+        TRANSFER_LIMIT_BUCKET_SECONDS,
+    );
+}
+
+pub mod payout_address {
+    use super::*;
+
+    /// Whitelisted BTC payout addresses per merchant, keyed by (merchant, btc address).
+    pub const PAYOUT_ADDRESSES: Map<(Addr, String), ()> = Map::new("payout_addresses");
+
+    /// Whether the merchant only allows burning to whitelisted payout addresses.
+    pub const PAYOUT_ADDRESS_WHITELIST_ENFORCED: Map<Addr, bool> =
+        Map::new("payout_address_whitelist_enforced");
+}
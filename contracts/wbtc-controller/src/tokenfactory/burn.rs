@@ -3,20 +3,28 @@ use std::fmt::Display;
 
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    attr, ensure, Attribute, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
-    Uint128,
+    attr, ensure, Addr, Attribute, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response,
+    StdError, StdResult, Storage, Timestamp, Uint128,
 };
 use osmosis_std::types::osmosis::tokenfactory::v1beta1::MsgBurn;
 
 use crate::{
     attrs::action_attrs,
-    auth::{allow_only, Role},
-    state::burn::{burn_requests, MIN_BURN_AMOUNT},
+    auth::{
+        merchant,
+        operator::{self, operator_attrs, OperatorPermission},
+        permission::{allow_permission, Permission},
+    },
+    error::must_pay,
+    state::burn::{
+        burn_requests, BURNED_PER_MERCHANT, BURN_CONFIRMATIONS, BURN_CONFIRMATION_TOLERANCE,
+        BURN_MODE, CONFIRMED_BTC_OUTPUTS, MAX_BURN_AMOUNT, MERCHANT_BURN_LIMIT, MIN_BURN_AMOUNT,
+    },
     ContractError,
 };
 
 use super::{
-    deposit_address,
+    deposit_address, payout_address,
     request::{Request, RequestWithHash, Status},
     token,
 };
@@ -30,6 +38,41 @@ pub enum BurnRequestStatus {
     Confirmed,
 }
 
+/// Determines how the tokens to be burned are collected from the merchant.
+#[cw_serde]
+#[derive(Default)]
+pub enum BurnMode {
+    /// Burn directly from the merchant's balance using `burn_from_address`. This is the default mode.
+    #[default]
+    BurnFromAddress,
+    /// The merchant attaches the tokens to be burned with the message
+    /// and the contract burns them from its own balance.
+    /// Use this mode when `burn_from_address` is disabled on the chain.
+    AttachedFunds,
+}
+
+impl Display for BurnMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BurnMode::BurnFromAddress => write!(f, "BurnFromAddress"),
+            BurnMode::AttachedFunds => write!(f, "AttachedFunds"),
+        }
+    }
+}
+
+/// Details of the BTC transaction output that pays out a burn request.
+#[cw_serde]
+pub struct BurnConfirmation {
+    /// BTC transaction id.
+    pub tx_id: String,
+    /// Index of the output paying to the request's deposit address.
+    pub vout: u32,
+    /// BTC amount actually sent to the request's deposit address.
+    pub btc_amount_sent: Uint128,
+    /// Network fee deducted from the burned amount.
+    pub network_fee: Uint128,
+}
+
 pub type BurnRequest = Request<BurnRequestStatus>;
 pub type BurnRequestWithHash = RequestWithHash<BurnRequestStatus>;
 
@@ -62,88 +105,244 @@ impl Status for BurnRequestStatus {
 /// This will be executed immediately and created an `Executed` burn request.
 /// The custodian will later transfer the burn amount
 /// from custodian deposit address to merchant deposit address and confirm the burn request.
+///
+/// In `AttachedFunds` burn mode, the attached funds must match the burn amount and token denom.
+///
+/// If `btc_address` is specified, it is used as the request's deposit address
+/// instead of the merchant's deposit address.
 pub fn burn(
     mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     amount: Uint128,
+    btc_address: Option<String>,
 ) -> Result<Response, ContractError> {
-    allow_only(&[Role::Merchant], &info.sender, deps.as_ref())?;
+    let merchant = operator::allow_merchant_or_operator(
+        OperatorPermission::Burn,
+        &info.sender,
+        &env,
+        deps.as_ref(),
+    )?;
+    merchant::ensure_merchant_not_suspended(deps.as_ref(), &merchant)?;
 
-    // ensure the requested amount is greater than the min burn amount
-    let min_burn_amount = get_min_burn_amount(deps.as_ref())?;
-    ensure!(
-        amount >= min_burn_amount,
-        ContractError::BurnAmountTooSmall {
-            requested_burn_amount: amount,
-            min_burn_amount
+    let deposit_address = match btc_address {
+        Some(btc_address) => {
+            payout_address::ensure_valid_payout_address(deps.as_ref(), &merchant, &btc_address)?
         }
-    );
+        None => deposit_address::get_merchant_deposit_address(deps.as_ref(), &merchant)?,
+    };
 
-    let deposit_address =
-        deposit_address::get_merchant_deposit_address(deps.as_ref(), &info.sender)?;
+    let denom = token::get_token_denom(deps.storage)?;
+
+    let burn_from_address = match get_burn_mode(deps.as_ref())? {
+        // burn the requested amount of tokens from the merchant, even if an operator is the sender
+        BurnMode::BurnFromAddress => merchant.to_string(),
+        // burn the attached tokens, which are now held by the contract
+        BurnMode::AttachedFunds => {
+            let paid_amount = must_pay(&info, &denom)?;
+            ensure!(
+                paid_amount == amount,
+                ContractError::FundsAmountMismatch {
+                    expected: amount,
+                    got: paid_amount
+                }
+            );
+
+            // empty `burn_from_address` burns from the sender, which is the contract
+            String::new()
+        }
+    };
 
     // record burn request
-    let (request_hash, request) = burn_requests().issue(
+    let (request_hash, request) = issue_burn_request(
         deps.branch(),
-        info.sender.clone(),
+        merchant.clone(),
         amount,
-        // tx_id will later be confirmed by the custodian
-        None,
         deposit_address,
         env.block.time,
     )?;
 
     // construct burn message
-    let denom = token::get_token_denom(deps.storage)?;
     let token_to_burn = Coin::new(request.amount.u128(), denom);
-
-    // burn the requested amount of tokens from sender, which can only be the merchant
     let burn_msg: CosmosMsg = MsgBurn {
         sender: env.contract.address.to_string(),
         amount: Some(token_to_burn.into()),
-        burn_from_address: info.sender.to_string(),
+        burn_from_address,
     }
     .into();
 
     // construct attributes
     let mut attrs = action_attrs("burn", <Vec<Attribute>>::from(&request.data()));
     attrs.extend(vec![attr("request_hash", request_hash)]);
+    attrs.extend(operator_attrs(&info.sender, &merchant));
 
     Ok(Response::new().add_message(burn_msg).add_attributes(attrs))
 }
 
+/// Validate the burn amount against the burn limits and record a `Pending` burn request.
+/// This does not burn any tokens, the caller is responsible for constructing the burn message.
+pub fn issue_burn_request(
+    mut deps: DepsMut,
+    requester: Addr,
+    amount: Uint128,
+    deposit_address: String,
+    timestamp: Timestamp,
+) -> Result<(String, BurnRequest), ContractError> {
+    ensure_min_burn_amount(deps.as_ref(), amount)?;
+    ensure_max_burn_amount(deps.as_ref(), amount)?;
+    record_merchant_burn(deps.branch(), &requester, amount, timestamp)?;
+
+    burn_requests().issue(
+        deps,
+        requester,
+        amount,
+        // tx_id will later be confirmed by the custodian
+        None,
+        deposit_address,
+        timestamp,
+    )
+}
+
 /// Confirm the burn request. Only the custodian can confirm the burn request.
 /// This will be called after the custodian has transferred the burn amount
 /// from custodian deposit address to merchant deposit address.
-/// And confirm that with `tx_id`.
+/// And confirm that with the BTC transaction output details.
+///
+/// The BTC amount sent plus the network fee must match the burned amount within the burn confirmation tolerance
+/// and the same BTC transaction output can not be used to confirm more than one burn request.
 pub fn confirm_burn_request(
     mut deps: DepsMut,
+    env: &Env,
 
     info: MessageInfo,
     request_hash: String,
-    tx_id: String,
+    confirmation: BurnConfirmation,
 ) -> Result<Response, ContractError> {
-    allow_only(&[Role::Custodian], &info.sender, deps.as_ref())?;
+    allow_permission(Permission::BurnConfirm, &info.sender, deps.as_ref(), env)?;
+
+    let confirmation = BurnConfirmation {
+        tx_id: normalize_btc_tx_id(&confirmation.tx_id)?,
+        ..confirmation
+    };
+
+    // ensure that the BTC transaction output has not been used to confirm another burn request
+    let btc_output = (confirmation.tx_id.clone(), confirmation.vout);
+    if let Some(confirmed_request_hash) =
+        CONFIRMED_BTC_OUTPUTS.may_load(deps.storage, btc_output.clone())?
+    {
+        return Err(ContractError::BtcOutputAlreadyConfirmed {
+            tx_id: confirmation.tx_id,
+            vout: confirmation.vout,
+            request_hash: confirmed_request_hash,
+        });
+    }
+
+    let tolerance = get_burn_confirmation_tolerance(deps.as_ref())?;
 
     burn_requests().check_and_update_request_status(
         deps.branch(),
         request_hash.as_str(),
         BurnRequestStatus::Confirmed,
-        |_, _| Ok(()),
+        |_, request| ensure_confirmed_amount(request.amount, &confirmation, tolerance),
     )?;
 
-    let request = burn_requests().confirm_tx(deps, request_hash.as_str(), tx_id)?;
+    CONFIRMED_BTC_OUTPUTS.save(deps.storage, btc_output, &request_hash)?;
+    BURN_CONFIRMATIONS.save(deps.storage, request_hash.clone(), &confirmation)?;
+
+    let request =
+        burn_requests().confirm_tx(deps, request_hash.as_str(), confirmation.tx_id.clone())?;
 
     let mut attrs = action_attrs(
         "confirm_burn_request",
         <Vec<Attribute>>::from(&request.data()),
     );
-    attrs.extend(vec![attr("request_hash", request_hash)]);
+    attrs.extend(vec![
+        attr("request_hash", request_hash),
+        attr("vout", confirmation.vout.to_string()),
+        attr("btc_amount_sent", confirmation.btc_amount_sent),
+        attr("network_fee", confirmation.network_fee),
+    ]);
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Validate that the BTC transaction id is 64 hex characters and lowercase it,
+/// so that the same transaction output can not be confirmed twice by changing the case of the hex.
+fn normalize_btc_tx_id(tx_id: &str) -> Result<String, ContractError> {
+    ensure!(
+        tx_id.len() == 64 && tx_id.chars().all(|c| c.is_ascii_hexdigit()),
+        ContractError::InvalidBtcTxId {
+            tx_id: tx_id.to_string()
+        }
+    );
+
+    Ok(tx_id.to_ascii_lowercase())
+}
+
+/// Ensure that the BTC amount sent plus the network fee matches the burned amount within the tolerance.
+fn ensure_confirmed_amount(
+    burned_amount: Uint128,
+    confirmation: &BurnConfirmation,
+    tolerance: Uint128,
+) -> Result<(), ContractError> {
+    let confirmed_amount = confirmation
+        .btc_amount_sent
+        .checked_add(confirmation.network_fee)
+        .map_err(StdError::from)?;
+
+    let difference = if confirmed_amount > burned_amount {
+        confirmed_amount - burned_amount
+    } else {
+        burned_amount - confirmed_amount
+    };
+
+    ensure!(
+        difference <= tolerance,
+        ContractError::BurnConfirmationAmountMismatch {
+            burned_amount,
+            btc_amount_sent: confirmation.btc_amount_sent,
+            network_fee: confirmation.network_fee,
+            tolerance
+        }
+    );
+
+    Ok(())
+}
+
+pub fn get_burn_confirmation(deps: Deps, request_hash: &str) -> StdResult<BurnConfirmation> {
+    BURN_CONFIRMATIONS
+        .may_load(deps.storage, request_hash.to_string())?
+        .ok_or_else(|| {
+            StdError::not_found(format!("No burn confirmation found for `{request_hash}`"))
+        })
+}
+
+/// Set the maximum difference allowed between the burned amount
+/// and the BTC amount sent plus the network fee when confirming a burn request.
+/// Only the governor can set the burn confirmation tolerance.
+pub fn set_burn_confirmation_tolerance(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    allow_permission(Permission::BurnConfigure, &info.sender, deps.as_ref(), env)?;
+
+    BURN_CONFIRMATION_TOLERANCE.save(deps.storage, &amount)?;
 
+    let attrs = action_attrs(
+        "set_burn_confirmation_tolerance",
+        vec![attr("amount", amount)],
+    );
     Ok(Response::new().add_attributes(attrs))
 }
 
+pub fn get_burn_confirmation_tolerance(deps: Deps) -> StdResult<Uint128> {
+    Ok(BURN_CONFIRMATION_TOLERANCE
+        .may_load(deps.storage)?
+        .unwrap_or_default())
+}
+
 pub fn get_burn_request_by_nonce(deps: Deps, nonce: &Uint128) -> StdResult<(String, BurnRequest)> {
     burn_requests().get_request_by_nonce(deps, nonce)
 }
@@ -159,10 +358,11 @@ pub fn get_burn_request_count(deps: Deps) -> StdResult<Uint128> {
 /// Set the minimum burn amount. Only the custodian can set the minimum burn amount.
 pub fn set_min_burn_amount(
     deps: DepsMut,
+    env: &Env,
     info: &MessageInfo,
     amount: Uint128,
 ) -> Result<Response, ContractError> {
-    allow_only(&[Role::Custodian], &info.sender, deps.as_ref())?;
+    allow_permission(Permission::BurnSetLimits, &info.sender, deps.as_ref(), env)?;
 
     MIN_BURN_AMOUNT.save(deps.storage, &amount)?;
 
@@ -170,10 +370,152 @@ pub fn set_min_burn_amount(
     Ok(Response::new().add_attributes(attrs))
 }
 
+/// Initialize the minimum burn amount, can only be called at contract instantiation
+pub fn initialize_min_burn_amount(storage: &mut dyn Storage, amount: Uint128) -> StdResult<()> {
+    MIN_BURN_AMOUNT.save(storage, &amount)
+}
+
+/// Set the burn mode. Only the governor can set the burn mode.
+pub fn set_burn_mode(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    mode: BurnMode,
+) -> Result<Response, ContractError> {
+    allow_permission(Permission::BurnConfigure, &info.sender, deps.as_ref(), env)?;
+
+    BURN_MODE.save(deps.storage, &mode)?;
+
+    let attrs = action_attrs("set_burn_mode", vec![attr("mode", mode.to_string())]);
+    Ok(Response::new().add_attributes(attrs))
+}
+
+pub fn get_burn_mode(deps: Deps) -> StdResult<BurnMode> {
+    Ok(BURN_MODE.may_load(deps.storage)?.unwrap_or_default())
+}
+
 pub fn get_min_burn_amount(deps: Deps) -> StdResult<Uint128> {
     Ok(MIN_BURN_AMOUNT.may_load(deps.storage)?.unwrap_or_default())
 }
 
+/// Ensure the requested amount is greater than the min burn amount.
+pub fn ensure_min_burn_amount(deps: Deps, amount: Uint128) -> Result<(), ContractError> {
+    let min_burn_amount = get_min_burn_amount(deps)?;
+    ensure!(
+        amount >= min_burn_amount,
+        ContractError::BurnAmountTooSmall {
+            requested_burn_amount: amount,
+            min_burn_amount
+        }
+    );
+
+    Ok(())
+}
+
+/// Set the maximum amount of a single burn. Only the custodian can set the maximum burn amount.
+/// Setting it to `None` removes the maximum.
+pub fn set_max_burn_amount(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    amount: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    allow_permission(Permission::BurnSetLimits, &info.sender, deps.as_ref(), env)?;
+
+    let attrs = match amount {
+        Some(amount) => {
+            MAX_BURN_AMOUNT.save(deps.storage, &amount)?;
+            vec![attr("amount", amount)]
+        }
+        None => {
+            MAX_BURN_AMOUNT.remove(deps.storage);
+            vec![]
+        }
+    };
+
+    Ok(Response::new().add_attributes(action_attrs("set_max_burn_amount", attrs)))
+}
+
+pub fn get_max_burn_amount(deps: Deps) -> StdResult<Option<Uint128>> {
+    MAX_BURN_AMOUNT.may_load(deps.storage)
+}
+
+/// Ensure the requested amount does not exceed the max burn amount, if set.
+pub fn ensure_max_burn_amount(deps: Deps, amount: Uint128) -> Result<(), ContractError> {
+    if let Some(max_burn_amount) = get_max_burn_amount(deps)? {
+        ensure!(
+            amount <= max_burn_amount,
+            ContractError::BurnAmountTooLarge {
+                requested_burn_amount: amount,
+                max_burn_amount
+            }
+        );
+    }
+
+    Ok(())
+}
+
+/// Set the maximum amount each merchant can burn within the burn limit window.
+/// Only the custodian can set the merchant burn limit.
+/// Setting it to `None` removes the limit.
+pub fn set_merchant_burn_limit(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    amount: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    allow_permission(Permission::BurnSetLimits, &info.sender, deps.as_ref(), env)?;
+
+    let attrs = match amount {
+        Some(amount) => {
+            MERCHANT_BURN_LIMIT.save(deps.storage, &amount)?;
+            vec![attr("amount", amount)]
+        }
+        None => {
+            MERCHANT_BURN_LIMIT.remove(deps.storage);
+            vec![]
+        }
+    };
+
+    Ok(Response::new().add_attributes(action_attrs("set_merchant_burn_limit", attrs)))
+}
+
+pub fn get_merchant_burn_limit(deps: Deps) -> StdResult<Option<Uint128>> {
+    MERCHANT_BURN_LIMIT.may_load(deps.storage)
+}
+
+/// Get the amount burned by the merchant within the burn limit window ending at `now`.
+pub fn get_burned_amount(deps: Deps, merchant: &Addr, now: Timestamp) -> StdResult<Uint128> {
+    BURNED_PER_MERCHANT.total(deps.storage, merchant.as_str(), now)
+}
+
+/// Ensure the requested amount stays within the merchant burn limit, if set,
+/// and record it as burned by the merchant.
+fn record_merchant_burn(
+    deps: DepsMut,
+    merchant: &Addr,
+    amount: Uint128,
+    timestamp: Timestamp,
+) -> Result<(), ContractError> {
+    if let Some(limit) = get_merchant_burn_limit(deps.as_ref())? {
+        let burned_amount = get_burned_amount(deps.as_ref(), merchant, timestamp)?;
+        let remaining_burn_amount = limit.saturating_sub(burned_amount);
+
+        ensure!(
+            amount <= remaining_burn_amount,
+            ContractError::MerchantBurnLimitExceeded {
+                merchant: merchant.to_string(),
+                requested_burn_amount: amount,
+                remaining_burn_amount
+            }
+        );
+    }
+
+    BURNED_PER_MERCHANT.record(deps.storage, merchant.as_str(), timestamp, amount)?;
+
+    Ok(())
+}
+
 pub fn list_burn_requests(
     deps: Deps,
     limit: Option<u32>,
@@ -194,10 +536,19 @@ mod tests {
     use osmosis_std::types::osmosis::tokenfactory::v1beta1::MsgBurn;
 
     use crate::{
-        auth::{custodian, governor, member_manager, merchant},
+        auth::{
+            custodian, governor, member_manager, merchant,
+            operator::{self, OperatorPermission},
+        },
         tokenfactory::{
-            burn::{burn_requests, confirm_burn_request, set_min_burn_amount, BurnRequestStatus},
-            deposit_address,
+            burn::{
+                burn_requests, confirm_burn_request, get_burn_confirmation,
+                get_burn_confirmation_tolerance, get_burn_mode, get_burned_amount,
+                set_burn_confirmation_tolerance, set_burn_mode, set_max_burn_amount,
+                set_merchant_burn_limit, set_min_burn_amount, BurnConfirmation, BurnMode,
+                BurnRequestStatus,
+            },
+            deposit_address, payout_address,
             request::RequestData,
             token,
         },
@@ -219,19 +570,39 @@ mod tests {
         let mut deps = mock_dependencies();
 
         // setup
-        governor::initialize_governor(deps.as_mut(), governor).unwrap();
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
         member_manager::set_member_manager(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(governor, &[]),
             member_manager,
         )
         .unwrap();
-        custodian::set_custodian(deps.as_mut(), &mock_info(member_manager, &[]), custodian)
-            .unwrap();
-        merchant::add_merchant(deps.as_mut(), &mock_info(member_manager, &[]), merchant).unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant,
+            None,
+        )
+        .unwrap();
 
         deposit_address::set_merchant_deposit_address(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(merchant, &[]),
             Some(deposit_address),
         )
@@ -261,7 +632,8 @@ mod tests {
             },
         };
 
-        let burn_fixture = |deps: DepsMut, info: MessageInfo| burn(deps, env.clone(), info, amount);
+        let burn_fixture =
+            |deps: DepsMut, info: MessageInfo| burn(deps, env.clone(), info, amount, None);
 
         // burn request count = 0
         assert_eq!(
@@ -328,102 +700,105 @@ mod tests {
     }
 
     #[test]
-    fn test_confirm_burn() {
+    fn test_suspended_merchant_cannot_burn() {
         let governor = "osmo1governor";
         let member_manager = "osmo1membermanager";
-        let custodian = "osmo1custodian";
         let merchant = "osmo1merchant";
-
-        let deposit_address = "bc1depositaddress";
-        let contract_addr =
-            Addr::unchecked("osmo14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9sq2r9g9");
+        let operator = "osmo1operator";
+        let env = mock_env();
         let mut deps = mock_dependencies();
 
         // setup
-        governor::initialize_governor(deps.as_mut(), governor).unwrap();
+        governor::initialize_governor(deps.as_mut(), &env, &mock_info("creator", &[]), governor)
+            .unwrap();
         member_manager::set_member_manager(
             deps.as_mut(),
+            &env,
             &mock_info(governor, &[]),
             member_manager,
         )
         .unwrap();
-        custodian::set_custodian(deps.as_mut(), &mock_info(member_manager, &[]), custodian)
-            .unwrap();
-        merchant::add_merchant(deps.as_mut(), &mock_info(member_manager, &[]), merchant).unwrap();
-
+        merchant::add_merchant(
+            deps.as_mut(),
+            &env,
+            &mock_info(member_manager, &[]),
+            merchant,
+            None,
+        )
+        .unwrap();
         deposit_address::set_merchant_deposit_address(
             deps.as_mut(),
+            &env,
             &mock_info(merchant, &[]),
-            Some(deposit_address),
+            Some("bc1depositaddress"),
+        )
+        .unwrap();
+        operator::set_operator(
+            deps.as_mut(),
+            &env,
+            &mock_info(merchant, &[]),
+            operator,
+            vec![OperatorPermission::Burn],
+            None,
         )
         .unwrap();
-
         token::set_token_denom(
             deps.as_mut().storage,
-            &format!("factory/{}/wbtc", contract_addr),
+            &format!("factory/{}/wbtc", env.contract.address),
         )
         .unwrap();
 
-        let amount = Uint128::new(100_000_000);
-
-        let env = Env {
-            block: BlockInfo {
-                height: 1,
-                time: Timestamp::from_seconds(1689069540),
-                chain_id: "osmosis-1".to_string(),
-            },
-            transaction: Some(TransactionInfo { index: 1 }),
-            contract: cosmwasm_std::ContractInfo {
-                address: contract_addr,
-            },
+        let burn_fixture = |deps: DepsMut, sender: &str| {
+            burn(
+                deps,
+                env.clone(),
+                mock_info(sender, &[]),
+                Uint128::new(100_000_000),
+                None,
+            )
         };
 
-        let res = burn(deps.as_mut(), env, mock_info(merchant, &[]), amount).unwrap();
-
-        let request_hash = res
-            .attributes
-            .iter()
-            .find(|attr| attr.key == "request_hash")
-            .unwrap()
-            .value
-            .clone();
-
-        let request_before = burn_requests()
-            .get_request(deps.as_ref(), request_hash.as_str())
-            .unwrap();
-
-        assert_eq!(request_before.status, BurnRequestStatus::Pending);
-        assert_eq!(request_before.tx_id, None);
-
-        let res = confirm_burn_request(
+        merchant::suspend_merchant(
             deps.as_mut(),
-            mock_info(custodian, &[]),
-            request_hash.clone(),
-            "btc_tx_id".to_string(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant,
         )
         .unwrap();
 
-        let request_after = burn_requests()
-            .get_request(deps.as_ref(), request_hash.as_str())
-            .unwrap();
-
+        // neither the suspended merchant nor its operator can burn
+        for sender in [merchant, operator] {
+            assert_eq!(
+                burn_fixture(deps.as_mut(), sender).unwrap_err(),
+                ContractError::MerchantSuspended {
+                    address: merchant.to_string()
+                }
+            );
+        }
         assert_eq!(
-            res.attributes
-                .into_iter()
-                .skip(1) // remove "method"
-                .rev()
-                .skip(1) // remove ""
-                .rev()
-                .collect::<Vec<_>>(),
-            <Vec<Attribute>>::from(&request_after.clone().data())
+            burn_requests().get_request_count(deps.as_ref()).unwrap(),
+            Uint128::zero()
         );
 
-        assert_eq!(request_after.status, BurnRequestStatus::Confirmed);
-        assert_eq!(request_after.tx_id, Some("btc_tx_id".to_string()));
+        // reinstated merchant and its operator can burn
+        merchant::reinstate_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant,
+        )
+        .unwrap();
+        for sender in [merchant, operator] {
+            burn_fixture(deps.as_mut(), sender).unwrap();
+        }
+        assert_eq!(
+            burn_requests().get_request_count(deps.as_ref()).unwrap(),
+            Uint128::new(2)
+        );
     }
 
     #[test]
-    fn test_min_burn_amount() {
+    fn test_burn_with_attached_funds() {
         let governor = "osmo1governor";
         let member_manager = "osmo1membermanager";
         let custodian = "osmo1custodian";
@@ -435,19 +810,39 @@ mod tests {
         let mut deps = mock_dependencies();
 
         // setup
-        governor::initialize_governor(deps.as_mut(), governor).unwrap();
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
         member_manager::set_member_manager(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(governor, &[]),
             member_manager,
         )
         .unwrap();
-        custodian::set_custodian(deps.as_mut(), &mock_info(member_manager, &[]), custodian)
-            .unwrap();
-        merchant::add_merchant(deps.as_mut(), &mock_info(member_manager, &[]), merchant).unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant,
+            None,
+        )
+        .unwrap();
 
         deposit_address::set_merchant_deposit_address(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(merchant, &[]),
             Some(deposit_address),
         )
@@ -459,32 +854,506 @@ mod tests {
         )
         .unwrap();
 
-        // burn small amount
-        let requested_burn_amount = Uint128::new(1);
-        let min_burn_amount = Uint128::new(100);
+        let amount = Uint128::new(100_000_000);
+        let denom = token::get_token_denom(deps.as_ref().storage).unwrap();
 
-        // if nothing is set, min burn amount is 0
-        assert!(burn(
-            deps.as_mut(),
-            mock_env(),
-            mock_info(merchant, &[]),
-            requested_burn_amount
-        )
-        .is_ok());
+        let mut env = mock_env();
+        env.contract.address = contract_addr.clone();
 
-        // set min burn amount
-        // only custodian can set
+        // default burn mode is `BurnFromAddress`
+        assert_eq!(
+            get_burn_mode(deps.as_ref()).unwrap(),
+            BurnMode::BurnFromAddress
+        );
+
+        // only governor can set burn mode
         assert_eq!(
-            set_min_burn_amount(deps.as_mut(), &mock_info(governor, &[]), min_burn_amount)
-                .unwrap_err(),
+            set_burn_mode(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(custodian, &[]),
+                BurnMode::AttachedFunds
+            )
+            .unwrap_err(),
             ContractError::Unauthorized {}
         );
 
         assert_eq!(
-            set_min_burn_amount(deps.as_mut(), &mock_info(custodian, &[]), min_burn_amount)
-                .unwrap()
-                .attributes,
-            vec![attr("action", "set_min_burn_amount"), attr("amount", "100"),]
+            set_burn_mode(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(governor, &[]),
+                BurnMode::AttachedFunds
+            )
+            .unwrap()
+            .attributes,
+            vec![
+                attr("action", "set_burn_mode"),
+                attr("mode", "AttachedFunds")
+            ]
+        );
+
+        assert_eq!(
+            get_burn_mode(deps.as_ref()).unwrap(),
+            BurnMode::AttachedFunds
+        );
+
+        // burn without funds fails
+        assert_eq!(
+            burn(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(merchant, &[]),
+                amount,
+                None
+            )
+            .unwrap_err(),
+            ContractError::NoFundsSent {}
+        );
+
+        // burn with wrong denom fails
+        assert_eq!(
+            burn(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(merchant, &[Coin::new(amount.u128(), "uosmo")]),
+                amount,
+                None
+            )
+            .unwrap_err(),
+            ContractError::InvalidFundsDenom {
+                expected: denom.clone(),
+                got: "uosmo".to_string()
+            }
+        );
+
+        // burn with mismatched amount fails
+        assert_eq!(
+            burn(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(merchant, &[Coin::new(amount.u128() - 1, &denom)]),
+                amount,
+                None
+            )
+            .unwrap_err(),
+            ContractError::FundsAmountMismatch {
+                expected: amount,
+                got: amount - Uint128::one()
+            }
+        );
+
+        // no burn request is recorded for failed burns
+        assert_eq!(
+            burn_requests().get_request_count(deps.as_ref()).unwrap(),
+            Uint128::zero()
+        );
+
+        // burn success burns attached funds from the contract
+        let token_to_burn = Coin::new(amount.u128(), &denom);
+        let res = burn(
+            deps.as_mut(),
+            env,
+            mock_info(merchant, &[Coin::new(amount.u128(), &denom)]),
+            amount,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(MsgBurn {
+                sender: contract_addr.to_string(),
+                amount: Some(token_to_burn.into()),
+                burn_from_address: String::new(),
+            })]
+        );
+
+        assert_eq!(
+            burn_requests().get_request_count(deps.as_ref()).unwrap(),
+            Uint128::one()
+        );
+    }
+
+    #[test]
+    fn test_confirm_burn() {
+        let governor = "osmo1governor";
+        let member_manager = "osmo1membermanager";
+        let custodian = "osmo1custodian";
+        let merchant = "osmo1merchant";
+
+        let deposit_address = "bc1depositaddress";
+        let contract_addr =
+            Addr::unchecked("osmo14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9sq2r9g9");
+        let mut deps = mock_dependencies();
+
+        // setup
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
+        member_manager::set_member_manager(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            member_manager,
+        )
+        .unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant,
+            None,
+        )
+        .unwrap();
+
+        deposit_address::set_merchant_deposit_address(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(merchant, &[]),
+            Some(deposit_address),
+        )
+        .unwrap();
+
+        token::set_token_denom(
+            deps.as_mut().storage,
+            &format!("factory/{}/wbtc", contract_addr),
+        )
+        .unwrap();
+
+        let amount = Uint128::new(100_000_000);
+
+        let env = Env {
+            block: BlockInfo {
+                height: 1,
+                time: Timestamp::from_seconds(1689069540),
+                chain_id: "osmosis-1".to_string(),
+            },
+            transaction: Some(TransactionInfo { index: 1 }),
+            contract: cosmwasm_std::ContractInfo {
+                address: contract_addr,
+            },
+        };
+
+        let res = burn(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(merchant, &[]),
+            amount,
+            None,
+        )
+        .unwrap();
+
+        let request_hash = res
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "request_hash")
+            .unwrap()
+            .value
+            .clone();
+
+        let request_before = burn_requests()
+            .get_request(deps.as_ref(), request_hash.as_str())
+            .unwrap();
+
+        assert_eq!(request_before.status, BurnRequestStatus::Pending);
+        assert_eq!(request_before.tx_id, None);
+
+        let tx_id = "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b";
+        let confirmation = BurnConfirmation {
+            tx_id: tx_id.to_string(),
+            vout: 1,
+            btc_amount_sent: Uint128::new(99_990_000),
+            network_fee: Uint128::new(10_000),
+        };
+
+        // amount sent plus fee must match the burned amount
+        let err = confirm_burn_request(
+            deps.as_mut(),
+            &mock_env(),
+            mock_info(custodian, &[]),
+            request_hash.clone(),
+            BurnConfirmation {
+                network_fee: Uint128::new(9_000),
+                ..confirmation.clone()
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err,
+            ContractError::BurnConfirmationAmountMismatch {
+                burned_amount: amount,
+                btc_amount_sent: Uint128::new(99_990_000),
+                network_fee: Uint128::new(9_000),
+                tolerance: Uint128::zero()
+            }
+        );
+
+        // only governor can set the tolerance
+        let err = set_burn_confirmation_tolerance(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(custodian, &[]),
+            Uint128::new(1_000),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        set_burn_confirmation_tolerance(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            Uint128::new(1_000),
+        )
+        .unwrap();
+        assert_eq!(
+            get_burn_confirmation_tolerance(deps.as_ref()).unwrap(),
+            Uint128::new(1_000)
+        );
+
+        // mismatch within tolerance is accepted
+        let res = confirm_burn_request(
+            deps.as_mut(),
+            &mock_env(),
+            mock_info(custodian, &[]),
+            request_hash.clone(),
+            BurnConfirmation {
+                network_fee: Uint128::new(9_000),
+                ..confirmation.clone()
+            },
+        )
+        .unwrap();
+
+        let request_after = burn_requests()
+            .get_request(deps.as_ref(), request_hash.as_str())
+            .unwrap();
+
+        let data_attrs = <Vec<Attribute>>::from(&request_after.clone().data());
+        assert_eq!(
+            res.attributes
+                .iter()
+                .skip(1) // remove "method"
+                .take(data_attrs.len())
+                .cloned()
+                .collect::<Vec<_>>(),
+            data_attrs
+        );
+        assert_eq!(
+            res.attributes
+                .into_iter()
+                .skip(1 + data_attrs.len())
+                .collect::<Vec<_>>(),
+            vec![
+                attr("request_hash", request_hash.clone()),
+                attr("vout", "1"),
+                attr("btc_amount_sent", "99990000"),
+                attr("network_fee", "9000"),
+            ]
+        );
+
+        assert_eq!(request_after.status, BurnRequestStatus::Confirmed);
+        assert_eq!(request_after.tx_id, Some(tx_id.to_string()));
+        assert_eq!(
+            get_burn_confirmation(deps.as_ref(), &request_hash).unwrap(),
+            BurnConfirmation {
+                network_fee: Uint128::new(9_000),
+                ..confirmation.clone()
+            }
+        );
+
+        // the same BTC output can not confirm another burn request
+        let res = burn(deps.as_mut(), env, mock_info(merchant, &[]), amount, None).unwrap();
+        let other_request_hash = res
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "request_hash")
+            .unwrap()
+            .value
+            .clone();
+
+        let err = confirm_burn_request(
+            deps.as_mut(),
+            &mock_env(),
+            mock_info(custodian, &[]),
+            other_request_hash.clone(),
+            confirmation.clone(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err,
+            ContractError::BtcOutputAlreadyConfirmed {
+                tx_id: tx_id.to_string(),
+                vout: 1,
+                request_hash: request_hash.clone()
+            }
+        );
+
+        // nor when the case of the transaction id is changed
+        let err = confirm_burn_request(
+            deps.as_mut(),
+            &mock_env(),
+            mock_info(custodian, &[]),
+            other_request_hash.clone(),
+            BurnConfirmation {
+                tx_id: tx_id.to_uppercase(),
+                ..confirmation.clone()
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err,
+            ContractError::BtcOutputAlreadyConfirmed {
+                tx_id: tx_id.to_string(),
+                vout: 1,
+                request_hash
+            }
+        );
+
+        // transaction id must be 64 hex characters
+        for invalid_tx_id in ["btc_tx_id", &tx_id[1..], &format!("{}z", &tx_id[1..])] {
+            let err = confirm_burn_request(
+                deps.as_mut(),
+                &mock_env(),
+                mock_info(custodian, &[]),
+                other_request_hash.clone(),
+                BurnConfirmation {
+                    tx_id: invalid_tx_id.to_string(),
+                    vout: 2,
+                    ..confirmation.clone()
+                },
+            )
+            .unwrap_err();
+
+            assert_eq!(
+                err,
+                ContractError::InvalidBtcTxId {
+                    tx_id: invalid_tx_id.to_string()
+                }
+            );
+        }
+
+        // another output of the same transaction can
+        confirm_burn_request(
+            deps.as_mut(),
+            &mock_env(),
+            mock_info(custodian, &[]),
+            other_request_hash,
+            BurnConfirmation {
+                vout: 2,
+                ..confirmation
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_min_burn_amount() {
+        let governor = "osmo1governor";
+        let member_manager = "osmo1membermanager";
+        let custodian = "osmo1custodian";
+        let merchant = "osmo1merchant";
+
+        let deposit_address = "bc1depositaddress";
+        let contract_addr =
+            Addr::unchecked("osmo14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9sq2r9g9");
+        let mut deps = mock_dependencies();
+
+        // setup
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
+        member_manager::set_member_manager(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            member_manager,
+        )
+        .unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant,
+            None,
+        )
+        .unwrap();
+
+        deposit_address::set_merchant_deposit_address(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(merchant, &[]),
+            Some(deposit_address),
+        )
+        .unwrap();
+
+        token::set_token_denom(
+            deps.as_mut().storage,
+            &format!("factory/{}/wbtc", contract_addr),
+        )
+        .unwrap();
+
+        // burn small amount
+        let requested_burn_amount = Uint128::new(1);
+        let min_burn_amount = Uint128::new(100);
+
+        // if nothing is set, min burn amount is 0
+        assert!(burn(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(merchant, &[]),
+            requested_burn_amount,
+            None
+        )
+        .is_ok());
+
+        // set min burn amount
+        // only custodian can set
+        assert_eq!(
+            set_min_burn_amount(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(governor, &[]),
+                min_burn_amount
+            )
+            .unwrap_err(),
+            ContractError::Unauthorized {}
+        );
+
+        assert_eq!(
+            set_min_burn_amount(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(custodian, &[]),
+                min_burn_amount
+            )
+            .unwrap()
+            .attributes,
+            vec![attr("action", "set_min_burn_amount"), attr("amount", "100"),]
         );
 
         assert_eq!(
@@ -492,7 +1361,8 @@ mod tests {
                 deps.as_mut(),
                 mock_env(),
                 mock_info(merchant, &[]),
-                requested_burn_amount
+                requested_burn_amount,
+                None
             )
             .unwrap_err(),
             ContractError::BurnAmountTooSmall {
@@ -506,7 +1376,8 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             mock_info(merchant, &[]),
-            min_burn_amount
+            min_burn_amount,
+            None
         )
         .is_ok());
 
@@ -515,7 +1386,8 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             mock_info(merchant, &[]),
-            min_burn_amount + Uint128::new(1)
+            min_burn_amount + Uint128::new(1),
+            None
         )
         .is_ok());
 
@@ -525,7 +1397,8 @@ mod tests {
                 deps.as_mut(),
                 mock_env(),
                 mock_info(merchant, &[]),
-                min_burn_amount - Uint128::new(1)
+                min_burn_amount - Uint128::new(1),
+                None
             )
             .unwrap_err(),
             ContractError::BurnAmountTooSmall {
@@ -534,4 +1407,387 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_burn_limits() {
+        let governor = "osmo1governor";
+        let member_manager = "osmo1membermanager";
+        let custodian = "osmo1custodian";
+        let merchant = "osmo1merchant";
+
+        let deposit_address = "bc1depositaddress";
+        let contract_addr =
+            Addr::unchecked("osmo14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9sq2r9g9");
+        let mut deps = mock_dependencies();
+
+        // setup
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
+        member_manager::set_member_manager(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            member_manager,
+        )
+        .unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant,
+            None,
+        )
+        .unwrap();
+
+        deposit_address::set_merchant_deposit_address(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(merchant, &[]),
+            Some(deposit_address),
+        )
+        .unwrap();
+
+        token::set_token_denom(
+            deps.as_mut().storage,
+            &format!("factory/{}/wbtc", contract_addr),
+        )
+        .unwrap();
+
+        let max_burn_amount = Uint128::new(100);
+        let merchant_burn_limit = Uint128::new(250);
+
+        let env_at = |seconds: u64| {
+            let mut env = mock_env();
+            env.block.time = Timestamp::from_seconds(1689069540).plus_seconds(seconds);
+            env
+        };
+
+        // only custodian can set limits
+        assert_eq!(
+            set_max_burn_amount(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(governor, &[]),
+                Some(max_burn_amount)
+            )
+            .unwrap_err(),
+            ContractError::Unauthorized {}
+        );
+        assert_eq!(
+            set_merchant_burn_limit(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(governor, &[]),
+                Some(merchant_burn_limit)
+            )
+            .unwrap_err(),
+            ContractError::Unauthorized {}
+        );
+
+        assert_eq!(
+            set_max_burn_amount(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(custodian, &[]),
+                Some(max_burn_amount)
+            )
+            .unwrap()
+            .attributes,
+            vec![attr("action", "set_max_burn_amount"), attr("amount", "100")]
+        );
+        assert_eq!(
+            set_merchant_burn_limit(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(custodian, &[]),
+                Some(merchant_burn_limit)
+            )
+            .unwrap()
+            .attributes,
+            vec![
+                attr("action", "set_merchant_burn_limit"),
+                attr("amount", "250")
+            ]
+        );
+
+        // burn more than max burn amount should fail
+        assert_eq!(
+            burn(
+                deps.as_mut(),
+                env_at(0),
+                mock_info(merchant, &[]),
+                max_burn_amount + Uint128::one(),
+                None
+            )
+            .unwrap_err(),
+            ContractError::BurnAmountTooLarge {
+                requested_burn_amount: max_burn_amount + Uint128::one(),
+                max_burn_amount,
+            }
+        );
+
+        // burn up to the merchant burn limit should succeed
+        burn(
+            deps.as_mut(),
+            env_at(0),
+            mock_info(merchant, &[]),
+            max_burn_amount,
+            None,
+        )
+        .unwrap();
+        burn(
+            deps.as_mut(),
+            env_at(60 * 60),
+            mock_info(merchant, &[]),
+            max_burn_amount,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_burned_amount(
+                deps.as_ref(),
+                &Addr::unchecked(merchant),
+                env_at(60 * 60).block.time
+            )
+            .unwrap(),
+            Uint128::new(200)
+        );
+
+        // burn exceeding the merchant burn limit within the window should fail
+        assert_eq!(
+            burn(
+                deps.as_mut(),
+                env_at(2 * 60 * 60),
+                mock_info(merchant, &[]),
+                max_burn_amount,
+                None
+            )
+            .unwrap_err(),
+            ContractError::MerchantBurnLimitExceeded {
+                merchant: merchant.to_string(),
+                requested_burn_amount: max_burn_amount,
+                remaining_burn_amount: Uint128::new(50),
+            }
+        );
+
+        burn(
+            deps.as_mut(),
+            env_at(2 * 60 * 60),
+            mock_info(merchant, &[]),
+            Uint128::new(50),
+            None,
+        )
+        .unwrap();
+
+        // burned amount is still within the window
+        assert_eq!(
+            burn(
+                deps.as_mut(),
+                env_at(24 * 60 * 60),
+                mock_info(merchant, &[]),
+                max_burn_amount,
+                None
+            )
+            .unwrap_err(),
+            ContractError::MerchantBurnLimitExceeded {
+                merchant: merchant.to_string(),
+                requested_burn_amount: max_burn_amount,
+                remaining_burn_amount: Uint128::zero(),
+            }
+        );
+
+        // burned amount is released once it falls out of the window
+        burn(
+            deps.as_mut(),
+            env_at(25 * 60 * 60),
+            mock_info(merchant, &[]),
+            max_burn_amount,
+            None,
+        )
+        .unwrap();
+
+        // removing limits allows any amount
+        set_max_burn_amount(deps.as_mut(), &mock_env(), &mock_info(custodian, &[]), None).unwrap();
+        set_merchant_burn_limit(deps.as_mut(), &mock_env(), &mock_info(custodian, &[]), None)
+            .unwrap();
+
+        burn(
+            deps.as_mut(),
+            env_at(25 * 60 * 60),
+            mock_info(merchant, &[]),
+            Uint128::new(1_000_000),
+            None,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_burn_to_btc_address() {
+        let governor = "osmo1governor";
+        let member_manager = "osmo1membermanager";
+        let custodian = "osmo1custodian";
+        let merchant = "osmo1merchant";
+
+        let deposit_address = "bc1depositaddress";
+        let payout_address = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+        let other_payout_address = "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy";
+        let contract_addr =
+            Addr::unchecked("osmo14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9sq2r9g9");
+        let mut deps = mock_dependencies();
+
+        // setup
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
+        member_manager::set_member_manager(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            member_manager,
+        )
+        .unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant,
+            None,
+        )
+        .unwrap();
+
+        deposit_address::set_merchant_deposit_address(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(merchant, &[]),
+            Some(deposit_address),
+        )
+        .unwrap();
+
+        token::set_token_denom(
+            deps.as_mut().storage,
+            &format!("factory/{}/wbtc", contract_addr),
+        )
+        .unwrap();
+
+        let amount = Uint128::new(100_000_000);
+
+        // invalid btc address is rejected
+        assert_eq!(
+            burn(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(merchant, &[]),
+                amount,
+                Some("bc1invalid".to_string())
+            )
+            .unwrap_err(),
+            ContractError::InvalidBtcAddress {
+                address: "bc1invalid".to_string()
+            }
+        );
+
+        // btc address is stored as the request's deposit address
+        let res = burn(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(merchant, &[]),
+            amount,
+            Some(payout_address.to_string()),
+        )
+        .unwrap();
+
+        let request_hash = res
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "request_hash")
+            .unwrap()
+            .value
+            .clone();
+
+        assert_eq!(
+            burn_requests()
+                .get_request(deps.as_ref(), request_hash.as_str())
+                .unwrap()
+                .deposit_address,
+            payout_address
+        );
+
+        // enforced whitelist only allows whitelisted payout addresses
+        payout_address::add_payout_address(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(merchant, &[]),
+            payout_address,
+        )
+        .unwrap();
+        payout_address::set_payout_address_whitelist_enforced(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(merchant, &[]),
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(
+            burn(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(merchant, &[]),
+                amount,
+                Some(other_payout_address.to_string())
+            )
+            .unwrap_err(),
+            ContractError::PayoutAddressNotWhitelisted {
+                merchant: merchant.to_string(),
+                address: other_payout_address.to_string()
+            }
+        );
+
+        burn(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(merchant, &[]),
+            amount,
+            Some(payout_address.to_string()),
+        )
+        .unwrap();
+
+        // registered deposit address is still used if btc address is not specified
+        let res = burn(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(merchant, &[]),
+            amount,
+            None,
+        )
+        .unwrap();
+
+        assert!(res
+            .attributes
+            .contains(&attr("deposit_address", deposit_address)));
+    }
 }
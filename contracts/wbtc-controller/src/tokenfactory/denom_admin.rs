@@ -0,0 +1,578 @@
+/// `denom_admin` module provides a timelocked handover of the tokenfactory denom admin
+/// to a new controller contract, after which this contract is retired,
+/// and the adoption of an existing denom whose admin is handed over to this contract.
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    attr, ensure, Addr, Attribute, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+    Storage, Timestamp,
+};
+use osmosis_std::types::osmosis::tokenfactory::v1beta1::{
+    MsgChangeAdmin, MsgSetBeforeSendHook, TokenfactoryQuerier,
+};
+
+use crate::{
+    attrs::action_attrs,
+    auth::{
+        holder::{self, HolderKind},
+        require_any, Role,
+    },
+    constants::DENOM_ADMIN_HANDOVER_DELAY_SECONDS,
+    state::denom_admin::{PENDING_DENOM, PENDING_DENOM_ADMIN_HANDOVER, RETIRED},
+    ContractError,
+};
+
+use super::token::{self, get_token_denom};
+
+#[cw_serde]
+pub struct DenomAdminHandover {
+    /// New admin of the denom, expected to be the new controller contract.
+    pub new_admin: Addr,
+
+    /// Time after which the handover can be completed.
+    pub executable_at: Timestamp,
+}
+
+/// Schedule handing over the denom admin to `new_admin`.
+/// The handover can be completed once the handover delay has passed.
+/// Scheduling a new handover replaces the pending one. Only the governor can schedule a handover.
+/// `new_admin` must be a contract, since it becomes the before-send hook of the denom.
+pub fn handover_denom_admin(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    new_admin: &str,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::Governor], &info.sender, deps.as_ref(), env)?;
+
+    let new_admin = deps.api.addr_validate(new_admin)?;
+    ensure!(
+        holder::holder_kind_of(deps.as_ref(), &new_admin)? == HolderKind::Contract,
+        ContractError::DenomAdminNotAContract {
+            address: new_admin.to_string()
+        }
+    );
+
+    let handover = DenomAdminHandover {
+        new_admin,
+        executable_at: env
+            .block
+            .time
+            .plus_seconds(DENOM_ADMIN_HANDOVER_DELAY_SECONDS),
+    };
+    PENDING_DENOM_ADMIN_HANDOVER.save(deps.storage, &handover)?;
+
+    let attrs = action_attrs(
+        "handover_denom_admin",
+        vec![
+            attr("new_admin", handover.new_admin),
+            attr("executable_at", handover.executable_at.to_string()),
+        ],
+    );
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Cancel the pending denom admin handover. Only the governor can cancel the handover.
+pub fn cancel_denom_admin_handover(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::Governor], &info.sender, deps.as_ref(), env)?;
+
+    ensure!(
+        get_pending_denom_admin_handover(deps.as_ref())?.is_some(),
+        ContractError::NoPendingDenomAdminHandover {}
+    );
+    PENDING_DENOM_ADMIN_HANDOVER.remove(deps.storage);
+
+    let attrs = action_attrs("cancel_denom_admin_handover", vec![] as Vec<Attribute>);
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Complete the pending denom admin handover once the handover delay has passed.
+/// The before-send hook is moved to the new admin and the denom admin is changed to the new admin,
+/// after which this contract is retired. Only the governor can complete the handover.
+pub fn complete_denom_admin_handover(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::Governor], &info.sender, deps.as_ref(), env)?;
+
+    let handover = get_pending_denom_admin_handover(deps.as_ref())?
+        .ok_or(ContractError::NoPendingDenomAdminHandover {})?;
+
+    ensure!(
+        env.block.time >= handover.executable_at,
+        ContractError::DenomAdminHandoverTimelocked {
+            executable_at: handover.executable_at
+        }
+    );
+
+    PENDING_DENOM_ADMIN_HANDOVER.remove(deps.storage);
+    RETIRED.save(deps.storage, &true)?;
+
+    let denom = get_token_denom(deps.storage)?;
+
+    // hook must be moved before changing admin since only the admin can set it
+    let msg_set_beforesend_hook: CosmosMsg = MsgSetBeforeSendHook {
+        sender: env.contract.address.to_string(),
+        denom: denom.clone(),
+        cosmwasm_address: handover.new_admin.to_string(),
+    }
+    .into();
+
+    let msg_change_admin: CosmosMsg = MsgChangeAdmin {
+        sender: env.contract.address.to_string(),
+        denom,
+        new_admin: handover.new_admin.to_string(),
+    }
+    .into();
+
+    let attrs = action_attrs(
+        "complete_denom_admin_handover",
+        vec![attr("new_admin", handover.new_admin)],
+    );
+    Ok(Response::new()
+        .add_attributes(attrs)
+        .add_message(msg_set_beforesend_hook)
+        .add_message(msg_change_admin))
+}
+
+/// Register an existing tokenfactory denom to be adopted once its admin has been changed to this contract.
+pub fn set_pending_denom(storage: &mut dyn Storage, denom: &str) -> Result<(), ContractError> {
+    ensure!(
+        denom.starts_with("factory/"),
+        ContractError::InvalidTokenfactoryDenom {
+            denom: denom.to_string()
+        }
+    );
+
+    PENDING_DENOM.save(storage, &denom.to_string())?;
+
+    Ok(())
+}
+
+pub fn get_pending_denom(deps: Deps) -> StdResult<Option<String>> {
+    PENDING_DENOM.may_load(deps.storage)
+}
+
+/// Activate the existing denom registered at instantiation.
+/// Verifies that this contract has become the denom admin, then registers the denom
+/// and sets this contract as the before-send hook. Only the governor can accept the denom admin.
+pub fn accept_denom_admin(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::Governor], &info.sender, deps.as_ref(), env)?;
+
+    let denom = get_pending_denom(deps.as_ref())?.ok_or(ContractError::NoPendingDenom {})?;
+
+    let admin = TokenfactoryQuerier::new(&deps.querier)
+        .denom_authority_metadata(denom.clone())?
+        .authority_metadata
+        .map(|metadata| metadata.admin);
+
+    ensure!(
+        admin.as_deref() == Some(env.contract.address.as_str()),
+        ContractError::NotDenomAdmin { denom }
+    );
+
+    PENDING_DENOM.remove(deps.storage);
+
+    let msgs = token::register_token_denom(deps.storage, env, &denom)?;
+
+    let attrs = action_attrs("accept_denom_admin", vec![attr("denom", denom)]);
+    Ok(Response::new().add_attributes(attrs).add_messages(msgs))
+}
+
+pub fn get_pending_denom_admin_handover(deps: Deps) -> StdResult<Option<DenomAdminHandover>> {
+    PENDING_DENOM_ADMIN_HANDOVER.may_load(deps.storage)
+}
+
+/// Check if the contract has handed over the denom admin.
+pub fn is_retired(deps: Deps) -> StdResult<bool> {
+    Ok(RETIRED.may_load(deps.storage)?.unwrap_or(false))
+}
+
+/// Ensure that the contract is still the denom admin.
+pub fn ensure_not_retired(deps: Deps) -> Result<(), ContractError> {
+    ensure!(!is_retired(deps)?, ContractError::ContractRetired {});
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::marker::PhantomData;
+
+    use cosmwasm_std::{
+        from_slice,
+        testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage},
+        to_binary, ContractInfoResponse, ContractResult, Empty, OwnedDeps, Querier, QuerierResult,
+        QueryRequest, SystemError, SystemResult, WasmQuery,
+    };
+    use osmosis_std::types::{
+        cosmos::bank::v1beta1::{DenomUnit, Metadata},
+        osmosis::tokenfactory::v1beta1::{
+            DenomAuthorityMetadata, MsgSetDenomMetadata, QueryDenomAuthorityMetadataResponse,
+        },
+    };
+
+    use crate::{auth::governor, tokenfactory::token};
+
+    use super::*;
+
+    /// Querier answering the tokenfactory denom authority metadata query with `admin`
+    /// and delegating other queries to `MockQuerier`.
+    struct DenomAdminQuerier {
+        base: MockQuerier,
+        admin: Option<String>,
+    }
+
+    impl Querier for DenomAdminQuerier {
+        fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+            let request: QueryRequest<Empty> = match from_slice(bin_request) {
+                Ok(request) => request,
+                Err(e) => {
+                    return SystemResult::Err(SystemError::InvalidRequest {
+                        error: e.to_string(),
+                        request: bin_request.into(),
+                    })
+                }
+            };
+
+            match request {
+                QueryRequest::Stargate { path, .. }
+                    if path == "/osmosis.tokenfactory.v1beta1.Query/DenomAuthorityMetadata" =>
+                {
+                    SystemResult::Ok(ContractResult::Ok(
+                        to_binary(&QueryDenomAuthorityMetadataResponse {
+                            authority_metadata: self
+                                .admin
+                                .clone()
+                                .map(|admin| DenomAuthorityMetadata { admin }),
+                        })
+                        .unwrap(),
+                    ))
+                }
+                _ => self.base.raw_query(bin_request),
+            }
+        }
+    }
+
+    fn mock_dependencies_with_denom_admin(
+        admin: Option<&str>,
+    ) -> OwnedDeps<MockStorage, MockApi, DenomAdminQuerier> {
+        OwnedDeps {
+            storage: MockStorage::default(),
+            api: MockApi::default(),
+            querier: DenomAdminQuerier {
+                base: MockQuerier::default(),
+                admin: admin.map(ToString::to_string),
+            },
+            custom_query_type: PhantomData,
+        }
+    }
+
+    #[test]
+    fn test_denom_admin_handover() {
+        let governor = "osmo1governor";
+        let new_controller = "osmo1newcontroller";
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let denom = format!("factory/{}/wbtc", env.contract.address);
+
+        // setup
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
+        token::set_token_denom(deps.as_mut().storage, &denom).unwrap();
+
+        // nothing to complete or cancel
+        assert_eq!(
+            complete_denom_admin_handover(deps.as_mut(), &env, &mock_info(governor, &[]))
+                .unwrap_err(),
+            ContractError::NoPendingDenomAdminHandover {}
+        );
+        assert_eq!(
+            cancel_denom_admin_handover(deps.as_mut(), &mock_env(), &mock_info(governor, &[]))
+                .unwrap_err(),
+            ContractError::NoPendingDenomAdminHandover {}
+        );
+
+        // only governor can handover
+        assert_eq!(
+            handover_denom_admin(
+                deps.as_mut(),
+                &env,
+                &mock_info(new_controller, &[]),
+                new_controller
+            )
+            .unwrap_err(),
+            ContractError::Unauthorized {}
+        );
+
+        // new admin must be a contract
+        assert_eq!(
+            handover_denom_admin(
+                deps.as_mut(),
+                &env,
+                &mock_info(governor, &[]),
+                new_controller
+            )
+            .unwrap_err(),
+            ContractError::DenomAdminNotAContract {
+                address: new_controller.to_string()
+            }
+        );
+        deps.querier.update_wasm(move |query| match query {
+            WasmQuery::ContractInfo { contract_addr } if contract_addr == new_controller => {
+                SystemResult::Ok(ContractResult::Ok(
+                    to_binary(&ContractInfoResponse::new(1, "osmo1creator")).unwrap(),
+                ))
+            }
+            WasmQuery::ContractInfo { contract_addr } | WasmQuery::Smart { contract_addr, .. } => {
+                SystemResult::Err(SystemError::NoSuchContract {
+                    addr: contract_addr.clone(),
+                })
+            }
+            _ => SystemResult::Err(SystemError::Unknown {}),
+        });
+
+        // schedule and cancel
+        handover_denom_admin(
+            deps.as_mut(),
+            &env,
+            &mock_info(governor, &[]),
+            new_controller,
+        )
+        .unwrap();
+        cancel_denom_admin_handover(deps.as_mut(), &mock_env(), &mock_info(governor, &[])).unwrap();
+        assert_eq!(
+            get_pending_denom_admin_handover(deps.as_ref()).unwrap(),
+            None
+        );
+
+        // schedule
+        handover_denom_admin(
+            deps.as_mut(),
+            &env,
+            &mock_info(governor, &[]),
+            new_controller,
+        )
+        .unwrap();
+
+        let executable_at = env
+            .block
+            .time
+            .plus_seconds(DENOM_ADMIN_HANDOVER_DELAY_SECONDS);
+        assert_eq!(
+            get_pending_denom_admin_handover(deps.as_ref()).unwrap(),
+            Some(DenomAdminHandover {
+                new_admin: Addr::unchecked(new_controller),
+                executable_at
+            })
+        );
+
+        // timelocked
+        env.block.time = executable_at.minus_seconds(1);
+        assert_eq!(
+            complete_denom_admin_handover(deps.as_mut(), &env, &mock_info(governor, &[]))
+                .unwrap_err(),
+            ContractError::DenomAdminHandoverTimelocked { executable_at }
+        );
+        ensure_not_retired(deps.as_ref()).unwrap();
+
+        // complete
+        env.block.time = executable_at;
+        let res =
+            complete_denom_admin_handover(deps.as_mut(), &env, &mock_info(governor, &[])).unwrap();
+
+        assert_eq!(
+            res.messages
+                .into_iter()
+                .map(|msg| msg.msg)
+                .collect::<Vec<CosmosMsg>>(),
+            vec![
+                MsgSetBeforeSendHook {
+                    sender: env.contract.address.to_string(),
+                    denom: denom.clone(),
+                    cosmwasm_address: new_controller.to_string(),
+                }
+                .into(),
+                MsgChangeAdmin {
+                    sender: env.contract.address.to_string(),
+                    denom,
+                    new_admin: new_controller.to_string(),
+                }
+                .into(),
+            ]
+        );
+
+        assert!(is_retired(deps.as_ref()).unwrap());
+        assert_eq!(
+            ensure_not_retired(deps.as_ref()).unwrap_err(),
+            ContractError::ContractRetired {}
+        );
+        assert_eq!(
+            get_pending_denom_admin_handover(deps.as_ref()).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_accept_denom_admin_preconditions() {
+        let governor = "osmo1governor";
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        // setup
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
+
+        // nothing to accept
+        assert_eq!(
+            accept_denom_admin(deps.as_mut(), &env, &mock_info(governor, &[])).unwrap_err(),
+            ContractError::NoPendingDenom {}
+        );
+
+        // only tokenfactory denoms can be adopted
+        assert_eq!(
+            set_pending_denom(deps.as_mut().storage, "uosmo").unwrap_err(),
+            ContractError::InvalidTokenfactoryDenom {
+                denom: "uosmo".to_string()
+            }
+        );
+        assert_eq!(get_pending_denom(deps.as_ref()).unwrap(), None);
+
+        let denom = "factory/osmo1creator/wbtc";
+        set_pending_denom(deps.as_mut().storage, denom).unwrap();
+        assert_eq!(
+            get_pending_denom(deps.as_ref()).unwrap(),
+            Some(denom.to_string())
+        );
+
+        // only governor can accept the denom admin
+        assert_eq!(
+            accept_denom_admin(deps.as_mut(), &env, &mock_info("osmo1nongovernor", &[]))
+                .unwrap_err(),
+            ContractError::Unauthorized {}
+        );
+    }
+
+    #[test]
+    fn test_accept_denom_admin() {
+        let governor = "osmo1governor";
+        let env = mock_env();
+        let contract_address = env.contract.address.to_string();
+        let denom = "factory/osmo1creator/wbtc";
+        let mut deps = mock_dependencies_with_denom_admin(Some("osmo1creator"));
+
+        // setup
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
+        let metadata = Metadata {
+            description: "Wrapped Bitcoin".to_string(),
+            denom_units: vec![DenomUnit {
+                denom: String::new(),
+                exponent: 0,
+                aliases: vec!["sat".to_string()],
+            }],
+            base: String::new(),
+            display: "wbtc".to_string(),
+            name: "Wrapped Bitcoin".to_string(),
+            symbol: "WBTC".to_string(),
+        };
+        token::initialize_denom_metadata(deps.as_mut().storage, &metadata).unwrap();
+        set_pending_denom(deps.as_mut().storage, denom).unwrap();
+
+        // the admin has not been changed to this contract yet
+        assert_eq!(
+            accept_denom_admin(deps.as_mut(), &env, &mock_info(governor, &[])).unwrap_err(),
+            ContractError::NotDenomAdmin {
+                denom: denom.to_string()
+            }
+        );
+
+        // the denom has no admin
+        deps.querier.admin = None;
+        assert_eq!(
+            accept_denom_admin(deps.as_mut(), &env, &mock_info(governor, &[])).unwrap_err(),
+            ContractError::NotDenomAdmin {
+                denom: denom.to_string()
+            }
+        );
+        assert_eq!(
+            get_pending_denom(deps.as_ref()).unwrap(),
+            Some(denom.to_string())
+        );
+        assert_eq!(
+            token::may_get_token_denom(deps.as_ref().storage).unwrap(),
+            None
+        );
+
+        // the admin has been changed to this contract
+        deps.querier.admin = Some(contract_address.clone());
+        let res = accept_denom_admin(deps.as_mut(), &env, &mock_info(governor, &[])).unwrap();
+
+        assert_eq!(
+            res.attributes,
+            action_attrs("accept_denom_admin", vec![attr("denom", denom)])
+        );
+        assert_eq!(
+            res.messages
+                .into_iter()
+                .map(|msg| msg.msg)
+                .collect::<Vec<CosmosMsg>>(),
+            vec![
+                MsgSetBeforeSendHook {
+                    sender: contract_address.clone(),
+                    denom: denom.to_string(),
+                    cosmwasm_address: contract_address.clone(),
+                }
+                .into(),
+                MsgSetDenomMetadata {
+                    sender: contract_address,
+                    metadata: Some(Metadata {
+                        denom_units: vec![DenomUnit {
+                            denom: denom.to_string(),
+                            exponent: 0,
+                            aliases: vec!["sat".to_string()],
+                        }],
+                        base: denom.to_string(),
+                        ..metadata
+                    }),
+                }
+                .into(),
+            ]
+        );
+
+        assert_eq!(
+            token::get_token_denom(deps.as_ref().storage).unwrap(),
+            denom
+        );
+        assert_eq!(get_pending_denom(deps.as_ref()).unwrap(), None);
+
+        // can not be accepted twice
+        assert_eq!(
+            accept_denom_admin(deps.as_mut(), &env, &mock_info(governor, &[])).unwrap_err(),
+            ContractError::NoPendingDenom {}
+        );
+    }
+}
@@ -1,11 +1,18 @@
 /// `deposit_address` module provides a way to manage deposit addresses for merchants and custodian.
-use cosmwasm_std::{attr, ensure, Addr, Attribute, Deps, DepsMut, MessageInfo, Response, StdError};
+use cosmwasm_std::{
+    attr, ensure, Addr, Attribute, Deps, DepsMut, Env, MessageInfo, Response, StdError,
+};
 use cw_storage_plus::Map;
 
 use crate::{
     attrs::action_attrs,
-    auth::{allow_only, merchant, Role},
+    auth::{
+        merchant,
+        operator::{self, OperatorPermission},
+        permission::{allow_permission, Permission},
+    },
     state::deposit_address::{CUSTODIAN_DEPOSIT_ADDRESS_PER_MERCHANT, MERCHANT_DEPOSIT_ADDRESS},
+    tokenfactory::payout_address::canonical_btc_address,
     ContractError,
 };
 
@@ -75,23 +82,25 @@ impl<'a> DepositAddressManager<'a> {
         match deposit_address {
             // set deposit address if it's not None
             Some(deposit_address) => {
+                let deposit_address = canonical_btc_address(deposit_address);
+
                 // ensure that the deposit address is not associated as any kind of deposit address
                 ensure!(
                     !self
                         .deposit_address_tracker
-                        .is_associated(deps.as_ref(), deposit_address.to_string())?,
+                        .is_associated(deps.as_ref(), deposit_address.clone())?,
                     ContractError::DepositAddressAlreadyAssociated {
-                        address: deposit_address.to_string()
+                        address: deposit_address.clone()
                     }
                 );
 
                 self.deposit_address
-                    .save(deps.storage, merchant, &deposit_address.to_string())?;
+                    .save(deps.storage, merchant, &deposit_address)?;
 
                 // track if deposit address is associated and should not be used again
                 // except already deassociated
                 self.deposit_address_tracker
-                    .track_associated(deps, deposit_address.to_string())?;
+                    .track_associated(deps, deposit_address.clone())?;
 
                 // add deposit address to the attributes
                 Ok(attrs
@@ -130,11 +139,17 @@ impl<'a> DepositAddressManager<'a> {
 
 pub fn set_custodian_deposit_address(
     deps: DepsMut,
+    env: &Env,
     info: &MessageInfo,
     merchant: &str,
     deposit_address: Option<&str>,
 ) -> Result<Response, ContractError> {
-    allow_only(&[Role::Custodian], &info.sender, deps.as_ref())?;
+    allow_permission(
+        Permission::DepositSetCustodian,
+        &info.sender,
+        deps.as_ref(),
+        env,
+    )?;
 
     // ensure that the merchant to be associated with the deposit address really has a merchant role.
     // since `set_deposit_address` only checks if sender is custodian.
@@ -157,50 +172,68 @@ pub fn set_custodian_deposit_address(
 }
 
 pub fn get_custodian_deposit_address(deps: Deps, merchant: &Addr) -> Result<String, StdError> {
-    CUSTODIAN_DEPOSIT_ADDRESS_PER_MERCHANT
-        .get_deposit_address(deps, merchant)?
-        .ok_or_else(|| {
-            StdError::not_found(format!(
-                "No custodian deposit address found for `{merchant}`"
-            ))
-        })
+    may_get_custodian_deposit_address(deps, merchant)?.ok_or_else(|| {
+        StdError::not_found(format!(
+            "No custodian deposit address found for `{merchant}`"
+        ))
+    })
 }
 
+/// Set the merchant's own deposit address. This can be done by the merchant or its operator.
 pub fn set_merchant_deposit_address(
     deps: DepsMut,
+    env: &Env,
     info: &MessageInfo,
     deposit_address: Option<&str>,
 ) -> Result<Response, ContractError> {
-    allow_only(&[Role::Merchant], &info.sender, deps.as_ref())?;
-
     // no need to ensure that the merchant to be associated with the deposit address really has a merchant role.
-    // since it sets to the sender address, which is already checked to be a merchant in `set_deposit_address`.
+    // since it is the sender or the merchant of the sender operator, which is checked here.
+    let merchant = operator::allow_merchant_or_operator(
+        OperatorPermission::SetDepositAddress,
+        &info.sender,
+        env,
+        deps.as_ref(),
+    )?;
 
     Ok(Response::new().add_attributes(action_attrs(
         "set_merchant_deposit_address",
         MERCHANT_DEPOSIT_ADDRESS.set_deposit_address(
             deps,
             info,
-            info.sender.as_ref(),
+            merchant.as_str(),
             deposit_address,
         )?,
     )))
 }
 
 pub fn get_merchant_deposit_address(deps: Deps, merchant: &Addr) -> Result<String, StdError> {
-    MERCHANT_DEPOSIT_ADDRESS
-        .get_deposit_address(deps, merchant)?
-        .ok_or_else(|| {
-            StdError::not_found(format!(
-                "No merchant deposit address found for `{merchant}`"
-            ))
-        })
+    may_get_merchant_deposit_address(deps, merchant)?.ok_or_else(|| {
+        StdError::not_found(format!(
+            "No merchant deposit address found for `{merchant}`"
+        ))
+    })
+}
+
+/// Get the custodian deposit address of the merchant if it has been set.
+pub fn may_get_custodian_deposit_address(
+    deps: Deps,
+    merchant: &Addr,
+) -> Result<Option<String>, StdError> {
+    CUSTODIAN_DEPOSIT_ADDRESS_PER_MERCHANT.get_deposit_address(deps, merchant)
+}
+
+/// Get the merchant deposit address if it has been set.
+pub fn may_get_merchant_deposit_address(
+    deps: Deps,
+    merchant: &Addr,
+) -> Result<Option<String>, StdError> {
+    MERCHANT_DEPOSIT_ADDRESS.get_deposit_address(deps, merchant)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use cosmwasm_std::testing::{mock_dependencies, mock_info};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
 
     use crate::auth::{custodian, governor, member_manager, merchant};
 
@@ -217,17 +250,43 @@ mod tests {
         let deposit_address_2 = "bc1q35rayrk92pvwamwm4n2hsd3epez2g2tqcqa0fx";
 
         // setup
-        governor::initialize_governor(deps.as_mut(), governor).unwrap();
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
         member_manager::set_member_manager(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(governor, &[]),
             member_manager,
         )
         .unwrap();
-        custodian::set_custodian(deps.as_mut(), &mock_info(member_manager, &[]), custodian)
-            .unwrap();
-        merchant::add_merchant(deps.as_mut(), &mock_info(member_manager, &[]), merchant_1).unwrap();
-        merchant::add_merchant(deps.as_mut(), &mock_info(member_manager, &[]), merchant_2).unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant_1,
+            None,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant_2,
+            None,
+        )
+        .unwrap();
 
         // no custodian deposit address set yet
         assert_eq!(
@@ -247,6 +306,7 @@ mod tests {
         assert_eq!(
             set_custodian_deposit_address(
                 deps.as_mut(),
+                &mock_env(),
                 &mock_info(merchant_1, &[]),
                 merchant_1,
                 Some(deposit_address_1),
@@ -259,6 +319,7 @@ mod tests {
         assert_eq!(
             set_custodian_deposit_address(
                 deps.as_mut(),
+                &mock_env(),
                 &mock_info(custodian, &[]),
                 non_merchant,
                 Some(deposit_address_1),
@@ -272,6 +333,7 @@ mod tests {
         // set custodian deposit address for merchant 1
         set_custodian_deposit_address(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(custodian, &[]),
             merchant_1,
             Some(deposit_address_1),
@@ -287,6 +349,7 @@ mod tests {
         assert_eq!(
             set_custodian_deposit_address(
                 deps.as_mut(),
+                &mock_env(),
                 &mock_info(custodian, &[]),
                 merchant_2,
                 Some(deposit_address_1),
@@ -300,6 +363,7 @@ mod tests {
         // set custodian deposit address for merchant 2
         set_custodian_deposit_address(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(custodian, &[]),
             merchant_2,
             Some(deposit_address_2),
@@ -312,8 +376,14 @@ mod tests {
         );
 
         // remove custodian deposit address for merchant 1
-        set_custodian_deposit_address(deps.as_mut(), &mock_info(custodian, &[]), merchant_1, None)
-            .unwrap();
+        set_custodian_deposit_address(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(custodian, &[]),
+            merchant_1,
+            None,
+        )
+        .unwrap();
 
         assert_eq!(
             get_custodian_deposit_address(deps.as_ref(), &Addr::unchecked(merchant_1)).unwrap_err(),
@@ -334,17 +404,43 @@ mod tests {
         let deposit_address_2 = "bc1q35rayrk92pvwamwm4n2hsd3epez2g2tqcqa0fx";
 
         // setup
-        governor::initialize_governor(deps.as_mut(), governor).unwrap();
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
         member_manager::set_member_manager(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(governor, &[]),
             member_manager,
         )
         .unwrap();
-        custodian::set_custodian(deps.as_mut(), &mock_info(member_manager, &[]), custodian)
-            .unwrap();
-        merchant::add_merchant(deps.as_mut(), &mock_info(member_manager, &[]), merchant_1).unwrap();
-        merchant::add_merchant(deps.as_mut(), &mock_info(member_manager, &[]), merchant_2).unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant_1,
+            None,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant_2,
+            None,
+        )
+        .unwrap();
 
         // no merchant deposit address set yet
         assert_eq!(
@@ -364,6 +460,7 @@ mod tests {
         assert_eq!(
             set_merchant_deposit_address(
                 deps.as_mut(),
+                &mock_env(),
                 &mock_info(governor, &[]),
                 Some(deposit_address_1),
             )
@@ -373,6 +470,7 @@ mod tests {
         assert_eq!(
             set_merchant_deposit_address(
                 deps.as_mut(),
+                &mock_env(),
                 &mock_info(custodian, &[]),
                 Some(deposit_address_1),
             )
@@ -382,6 +480,7 @@ mod tests {
         assert_eq!(
             set_merchant_deposit_address(
                 deps.as_mut(),
+                &mock_env(),
                 &mock_info("anyone", &[]),
                 Some(deposit_address_1),
             )
@@ -392,6 +491,7 @@ mod tests {
         // set merchant deposit address for merchant 1
         set_merchant_deposit_address(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(merchant_1, &[]),
             Some(deposit_address_1),
         )
@@ -406,6 +506,7 @@ mod tests {
         assert_eq!(
             set_merchant_deposit_address(
                 deps.as_mut(),
+                &mock_env(),
                 &mock_info(merchant_2, &[]),
                 Some(deposit_address_1),
             )
@@ -418,6 +519,7 @@ mod tests {
         // set merchant deposit address for merchant 2
         set_merchant_deposit_address(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(merchant_2, &[]),
             Some(deposit_address_2),
         )
@@ -429,7 +531,13 @@ mod tests {
         );
 
         // remove merchant deposit address for merchant 1
-        set_merchant_deposit_address(deps.as_mut(), &mock_info(merchant_1, &[]), None).unwrap();
+        set_merchant_deposit_address(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(merchant_1, &[]),
+            None,
+        )
+        .unwrap();
 
         assert_eq!(
             get_merchant_deposit_address(deps.as_ref(), &Addr::unchecked(merchant_1)).unwrap_err(),
@@ -451,21 +559,48 @@ mod tests {
         let deposit_address_2 = "bc1q35rayrk92pvwamwm4n2hsd3epez2g2tqcqa0fx";
 
         // setup
-        governor::initialize_governor(deps.as_mut(), governor).unwrap();
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
         member_manager::set_member_manager(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(governor, &[]),
             member_manager,
         )
         .unwrap();
-        custodian::set_custodian(deps.as_mut(), &mock_info(member_manager, &[]), custodian)
-            .unwrap();
-        merchant::add_merchant(deps.as_mut(), &mock_info(member_manager, &[]), merchant_1).unwrap();
-        merchant::add_merchant(deps.as_mut(), &mock_info(member_manager, &[]), merchant_2).unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant_1,
+            None,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant_2,
+            None,
+        )
+        .unwrap();
 
         // set merchant deposit address for merchant 1
         set_merchant_deposit_address(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(merchant_1, &[]),
             Some(deposit_address_1),
         )
@@ -475,6 +610,7 @@ mod tests {
         assert_eq!(
             set_custodian_deposit_address(
                 deps.as_mut(),
+                &mock_env(),
                 &mock_info(custodian, &[]),
                 merchant_2,
                 Some(deposit_address_1),
@@ -486,11 +622,18 @@ mod tests {
         );
 
         // remove merchant 1's merchant deposit address
-        set_merchant_deposit_address(deps.as_mut(), &mock_info(merchant_1, &[]), None).unwrap();
+        set_merchant_deposit_address(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(merchant_1, &[]),
+            None,
+        )
+        .unwrap();
 
         // set old merchant 1's merchant deposit address as custodian deposit address for merchant 2 should succeed
         set_custodian_deposit_address(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(custodian, &[]),
             merchant_2,
             Some(deposit_address_1),
@@ -505,34 +648,50 @@ mod tests {
         // set custodian deposit address for merchant 1
         set_custodian_deposit_address(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(custodian, &[]),
             merchant_1,
             Some(deposit_address_2),
         )
         .unwrap();
 
-        // set merchant 1's custodian deposit address as merchant deposit address for merchant 2 should fail
-        assert_eq!(
-            set_merchant_deposit_address(
-                deps.as_mut(),
-                &mock_info(merchant_2, &[]),
-                Some(deposit_address_2),
-            )
-            .unwrap_err(),
-            ContractError::DepositAddressAlreadyAssociated {
-                address: deposit_address_2.to_string()
-            }
-        );
+        // set merchant 1's custodian deposit address as merchant deposit address for merchant 2 should fail,
+        // regardless of the case of the bech32 address
+        for deposit_address in [
+            deposit_address_2.to_string(),
+            deposit_address_2.to_uppercase(),
+        ] {
+            assert_eq!(
+                set_merchant_deposit_address(
+                    deps.as_mut(),
+                    &mock_env(),
+                    &mock_info(merchant_2, &[]),
+                    Some(&deposit_address),
+                )
+                .unwrap_err(),
+                ContractError::DepositAddressAlreadyAssociated {
+                    address: deposit_address_2.to_string()
+                }
+            );
+        }
 
         // remove custodian deposit address for merchant 1
-        set_custodian_deposit_address(deps.as_mut(), &mock_info(custodian, &[]), merchant_1, None)
-            .unwrap();
+        set_custodian_deposit_address(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(custodian, &[]),
+            merchant_1,
+            None,
+        )
+        .unwrap();
 
-        // set old custodian deposit address for merchant 1 as merchant deposit address for merchant 2 should succeed
+        // set old custodian deposit address for merchant 1 as merchant deposit address for merchant 2 should succeed,
+        // it is stored lowercased
         set_merchant_deposit_address(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(merchant_2, &[]),
-            Some(deposit_address_2),
+            Some(&deposit_address_2.to_uppercase()),
         )
         .unwrap();
 
@@ -552,23 +711,61 @@ mod tests {
         let merchant_2 = "osmo1merchant2";
 
         // setup
-        governor::initialize_governor(deps.as_mut(), governor).unwrap();
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
         member_manager::set_member_manager(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(governor, &[]),
             member_manager,
         )
         .unwrap();
-        custodian::set_custodian(deps.as_mut(), &mock_info(member_manager, &[]), custodian)
-            .unwrap();
-        merchant::add_merchant(deps.as_mut(), &mock_info(member_manager, &[]), merchant_1).unwrap();
-        merchant::add_merchant(deps.as_mut(), &mock_info(member_manager, &[]), merchant_2).unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant_1,
+            None,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant_2,
+            None,
+        )
+        .unwrap();
 
         // set merchant deposit address for merchant 1 to None
-        set_merchant_deposit_address(deps.as_mut(), &mock_info(merchant_1, &[]), None).unwrap();
+        set_merchant_deposit_address(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(merchant_1, &[]),
+            None,
+        )
+        .unwrap();
 
         // set custodian deposit address for merchant 2 to None
-        set_custodian_deposit_address(deps.as_mut(), &mock_info(custodian, &[]), merchant_2, None)
-            .unwrap();
+        set_custodian_deposit_address(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(custodian, &[]),
+            merchant_2,
+            None,
+        )
+        .unwrap();
     }
 }
@@ -0,0 +1,568 @@
+/// `enforcement` module provides account freezing and forced transfers for legally mandated recoveries.
+/// Every enforcement action requires a case reference and is recorded in an append-only enforcement log.
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    attr, ensure, Addr, Coin, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, Storage,
+    SubMsg, Timestamp, Uint128,
+};
+use cw_storage_plus::Bound;
+use osmosis_std::types::osmosis::tokenfactory::v1beta1::MsgForceTransfer;
+
+use crate::{
+    attrs::action_attrs,
+    auth::permission::{allow_permission, Permission},
+    constants::{DEFAULT_LIMIT, MAX_LIMIT},
+    state::enforcement::{
+        ENFORCEMENT_LOG, ENFORCEMENT_LOG_NONCE, FROZEN_ACCOUNTS, PENDING_FORCE_TRANSFER,
+    },
+    ContractError,
+};
+
+use super::token::get_token_denom;
+
+pub const FORCE_TRANSFER_REPLY_ID: u64 = 2;
+
+#[cw_serde]
+pub enum EnforcementAction {
+    FreezeAccount {
+        account: Addr,
+    },
+    UnfreezeAccount {
+        account: Addr,
+    },
+    ForceTransfer {
+        from: Addr,
+        to: Addr,
+        amount: Uint128,
+    },
+}
+
+#[cw_serde]
+pub struct EnforcementRecord {
+    pub action: EnforcementAction,
+
+    /// Reference to the legal case that mandates the action.
+    pub case_reference: String,
+
+    /// Address that executed the action.
+    pub executor: Addr,
+
+    pub timestamp: Timestamp,
+}
+
+#[cw_serde]
+pub struct EnforcementRecordWithId {
+    pub id: Uint128,
+    pub record: EnforcementRecord,
+}
+
+/// Force transfer that is currently being executed.
+/// Transfers matching it bypass the send restrictions of the `BlockBeforeSend` hook.
+#[cw_serde]
+pub struct PendingForceTransfer {
+    pub from: Addr,
+    pub to: Addr,
+}
+
+/// Freeze the account, blocking all token transfers from and to it.
+/// Only the governor can freeze an account.
+pub fn freeze_account(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    account: &str,
+    case_reference: String,
+) -> Result<Response, ContractError> {
+    allow_permission(
+        Permission::EnforcementFreeze,
+        &info.sender,
+        deps.as_ref(),
+        env,
+    )?;
+    ensure_case_reference(&case_reference)?;
+
+    let account = deps.api.addr_validate(account)?;
+    FROZEN_ACCOUNTS.save(deps.storage, account.clone(), &())?;
+
+    let attrs = action_attrs(
+        "freeze_account",
+        vec![
+            attr("account", account.as_str()),
+            attr("case_reference", &case_reference),
+        ],
+    );
+
+    record_enforcement(
+        deps,
+        env,
+        info,
+        EnforcementAction::FreezeAccount { account },
+        case_reference,
+    )?;
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Unfreeze the account. Only the governor can unfreeze an account.
+pub fn unfreeze_account(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    account: &str,
+    case_reference: String,
+) -> Result<Response, ContractError> {
+    allow_permission(
+        Permission::EnforcementFreeze,
+        &info.sender,
+        deps.as_ref(),
+        env,
+    )?;
+    ensure_case_reference(&case_reference)?;
+
+    let account = deps.api.addr_validate(account)?;
+    ensure!(
+        is_frozen(deps.storage, &account)?,
+        ContractError::AccountNotFrozen {
+            address: account.to_string()
+        }
+    );
+    FROZEN_ACCOUNTS.remove(deps.storage, account.clone());
+
+    let attrs = action_attrs(
+        "unfreeze_account",
+        vec![
+            attr("account", account.as_str()),
+            attr("case_reference", &case_reference),
+        ],
+    );
+
+    record_enforcement(
+        deps,
+        env,
+        info,
+        EnforcementAction::UnfreezeAccount { account },
+        case_reference,
+    )?;
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Force transfer `amount` of the token from `from` to `to` using tokenfactory's force transfer.
+/// The transfer is allowed even if `from` is frozen or token transfer is paused.
+/// Only the governor can force transfer.
+pub fn force_transfer(
+    mut deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    from: &str,
+    to: &str,
+    amount: Uint128,
+    case_reference: String,
+) -> Result<Response, ContractError> {
+    allow_permission(
+        Permission::EnforcementForceTransfer,
+        &info.sender,
+        deps.as_ref(),
+        env,
+    )?;
+    ensure_case_reference(&case_reference)?;
+
+    let from = deps.api.addr_validate(from)?;
+    let to = deps.api.addr_validate(to)?;
+
+    PENDING_FORCE_TRANSFER.save(
+        deps.storage,
+        &PendingForceTransfer {
+            from: from.clone(),
+            to: to.clone(),
+        },
+    )?;
+
+    let denom = get_token_denom(deps.storage)?;
+    let msg_force_transfer = SubMsg::reply_on_success(
+        MsgForceTransfer {
+            sender: env.contract.address.to_string(),
+            amount: Some(Coin::new(amount.u128(), denom).into()),
+            transfer_from_address: from.to_string(),
+            transfer_to_address: to.to_string(),
+        },
+        FORCE_TRANSFER_REPLY_ID,
+    );
+
+    let attrs = action_attrs(
+        "force_transfer",
+        vec![
+            attr("from", from.as_str()),
+            attr("to", to.as_str()),
+            attr("amount", amount),
+            attr("case_reference", &case_reference),
+        ],
+    );
+
+    record_enforcement(
+        deps.branch(),
+        env,
+        info,
+        EnforcementAction::ForceTransfer { from, to, amount },
+        case_reference,
+    )?;
+
+    Ok(Response::new()
+        .add_attributes(attrs)
+        .add_submessage(msg_force_transfer))
+}
+
+/// Clear the pending force transfer once it has been executed.
+pub fn clear_pending_force_transfer(storage: &mut dyn Storage) {
+    PENDING_FORCE_TRANSFER.remove(storage);
+}
+
+/// Check if the transfer is the force transfer currently being executed.
+pub fn is_pending_force_transfer(storage: &dyn Storage, from: &str, to: &str) -> StdResult<bool> {
+    Ok(PENDING_FORCE_TRANSFER
+        .may_load(storage)?
+        .map(|pending| pending.from.as_str() == from && pending.to.as_str() == to)
+        .unwrap_or(false))
+}
+
+pub fn is_frozen(storage: &dyn Storage, account: &Addr) -> StdResult<bool> {
+    Ok(FROZEN_ACCOUNTS
+        .may_load(storage, account.clone())?
+        .is_some())
+}
+
+/// Ensure that neither the sender nor the recipient of a token transfer is frozen.
+pub fn ensure_not_frozen(storage: &dyn Storage, from: &str, to: &str) -> Result<(), ContractError> {
+    for address in [from, to] {
+        ensure!(
+            !is_frozen(storage, &Addr::unchecked(address))?,
+            ContractError::AccountFrozen {
+                address: address.to_string()
+            }
+        );
+    }
+
+    Ok(())
+}
+
+pub fn list_frozen_accounts(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Addr>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    FROZEN_ACCOUNTS
+        .keys(
+            deps.storage,
+            start_after.map(|address| Bound::exclusive(Addr::unchecked(address))),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect()
+}
+
+pub fn list_enforcement_records(
+    deps: Deps,
+    start_after: Option<Uint128>,
+    limit: Option<u32>,
+) -> StdResult<Vec<EnforcementRecordWithId>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    ENFORCEMENT_LOG
+        .range(
+            deps.storage,
+            start_after.map(|id| Bound::exclusive(id.u128())),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| {
+            let (id, record) = item?;
+            Ok(EnforcementRecordWithId {
+                id: Uint128::new(id),
+                record,
+            })
+        })
+        .collect()
+}
+
+fn ensure_case_reference(case_reference: &str) -> Result<(), ContractError> {
+    ensure!(
+        !case_reference.trim().is_empty(),
+        ContractError::CaseReferenceRequired {}
+    );
+
+    Ok(())
+}
+
+fn record_enforcement(
+    mut deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    action: EnforcementAction,
+    case_reference: String,
+) -> Result<(), ContractError> {
+    let id = ENFORCEMENT_LOG_NONCE.get_then_increase(deps.branch())?;
+    ENFORCEMENT_LOG.save(
+        deps.storage,
+        id.u128(),
+        &EnforcementRecord {
+            action,
+            case_reference,
+            executor: info.sender.clone(),
+            timestamp: env.block.time,
+        },
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    use crate::{
+        auth::{custodian, governor, member_manager},
+        tokenfactory::token,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_freeze_account() {
+        let governor = "osmo1governor";
+        let member_manager = "osmo1membermanager";
+        let custodian = "osmo1custodian";
+        let holder = "osmo1holder";
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        // setup
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
+        member_manager::set_member_manager(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            member_manager,
+        )
+        .unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+
+        // only governor can freeze
+        let err = freeze_account(
+            deps.as_mut(),
+            &env,
+            &mock_info(custodian, &[]),
+            holder,
+            "case-1".to_string(),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // case reference is required
+        let err = freeze_account(
+            deps.as_mut(),
+            &env,
+            &mock_info(governor, &[]),
+            holder,
+            " ".to_string(),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::CaseReferenceRequired {});
+        assert!(!is_frozen(&deps.storage, &Addr::unchecked(holder)).unwrap());
+
+        let res = freeze_account(
+            deps.as_mut(),
+            &env,
+            &mock_info(governor, &[]),
+            holder,
+            "case-1".to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            res.attributes,
+            vec![
+                attr("action", "freeze_account"),
+                attr("account", holder),
+                attr("case_reference", "case-1"),
+            ]
+        );
+        assert!(is_frozen(&deps.storage, &Addr::unchecked(holder)).unwrap());
+        assert_eq!(
+            list_frozen_accounts(deps.as_ref(), None, None).unwrap(),
+            vec![Addr::unchecked(holder)]
+        );
+
+        // transfers from and to the frozen account are blocked
+        assert_eq!(
+            ensure_not_frozen(&deps.storage, holder, "osmo1someone").unwrap_err(),
+            ContractError::AccountFrozen {
+                address: holder.to_string()
+            }
+        );
+        assert_eq!(
+            ensure_not_frozen(&deps.storage, "osmo1someone", holder).unwrap_err(),
+            ContractError::AccountFrozen {
+                address: holder.to_string()
+            }
+        );
+        ensure_not_frozen(&deps.storage, "osmo1someone", "osmo1other").unwrap();
+
+        // unfreeze
+        let err = unfreeze_account(
+            deps.as_mut(),
+            &env,
+            &mock_info(governor, &[]),
+            "osmo1other",
+            "case-1".to_string(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::AccountNotFrozen {
+                address: "osmo1other".to_string()
+            }
+        );
+
+        unfreeze_account(
+            deps.as_mut(),
+            &env,
+            &mock_info(governor, &[]),
+            holder,
+            "case-1".to_string(),
+        )
+        .unwrap();
+        assert!(!is_frozen(&deps.storage, &Addr::unchecked(holder)).unwrap());
+        ensure_not_frozen(&deps.storage, holder, "osmo1someone").unwrap();
+
+        // actions are logged
+        assert_eq!(
+            list_enforcement_records(deps.as_ref(), None, None).unwrap(),
+            vec![
+                EnforcementRecordWithId {
+                    id: Uint128::new(0),
+                    record: EnforcementRecord {
+                        action: EnforcementAction::FreezeAccount {
+                            account: Addr::unchecked(holder)
+                        },
+                        case_reference: "case-1".to_string(),
+                        executor: Addr::unchecked(governor),
+                        timestamp: env.block.time,
+                    }
+                },
+                EnforcementRecordWithId {
+                    id: Uint128::new(1),
+                    record: EnforcementRecord {
+                        action: EnforcementAction::UnfreezeAccount {
+                            account: Addr::unchecked(holder)
+                        },
+                        case_reference: "case-1".to_string(),
+                        executor: Addr::unchecked(governor),
+                        timestamp: env.block.time,
+                    }
+                },
+            ]
+        );
+        assert_eq!(
+            list_enforcement_records(deps.as_ref(), Some(Uint128::new(0)), None)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_force_transfer() {
+        let governor = "osmo1governor";
+        let thief = "osmo1thief";
+        let victim = "osmo1victim";
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let denom = format!("factory/{}/wbtc", env.contract.address);
+
+        // setup
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
+        token::set_token_denom(deps.as_mut().storage, &denom).unwrap();
+
+        // only governor can force transfer
+        let err = force_transfer(
+            deps.as_mut(),
+            &env,
+            &mock_info(thief, &[]),
+            thief,
+            victim,
+            Uint128::new(100),
+            "case-2".to_string(),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        let res = force_transfer(
+            deps.as_mut(),
+            &env,
+            &mock_info(governor, &[]),
+            thief,
+            victim,
+            Uint128::new(100),
+            "case-2".to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::reply_on_success(
+                MsgForceTransfer {
+                    sender: env.contract.address.to_string(),
+                    amount: Some(Coin::new(100, denom).into()),
+                    transfer_from_address: thief.to_string(),
+                    transfer_to_address: victim.to_string(),
+                },
+                FORCE_TRANSFER_REPLY_ID
+            )]
+        );
+
+        // only the exact transfer is pending
+        assert!(is_pending_force_transfer(&deps.storage, thief, victim).unwrap());
+        assert!(!is_pending_force_transfer(&deps.storage, thief, governor).unwrap());
+
+        clear_pending_force_transfer(&mut deps.storage);
+        assert!(!is_pending_force_transfer(&deps.storage, thief, victim).unwrap());
+
+        assert_eq!(
+            list_enforcement_records(deps.as_ref(), None, None).unwrap(),
+            vec![EnforcementRecordWithId {
+                id: Uint128::new(0),
+                record: EnforcementRecord {
+                    action: EnforcementAction::ForceTransfer {
+                        from: Addr::unchecked(thief),
+                        to: Addr::unchecked(victim),
+                        amount: Uint128::new(100),
+                    },
+                    case_reference: "case-2".to_string(),
+                    executor: Addr::unchecked(governor),
+                    timestamp: env.block.time,
+                }
+            }]
+        );
+    }
+}
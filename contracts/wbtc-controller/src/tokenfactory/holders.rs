@@ -0,0 +1,197 @@
+/// `holders` module maintains an index of current token holders from the `TrackBeforeSend` hook.
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Deps, DepsMut, Env, Order, StdResult, Storage, Timestamp};
+use cw_storage_plus::Bound;
+
+use crate::{
+    constants::{DEFAULT_LIMIT, MAX_LIMIT},
+    state::holders::{HOLDERS, HOLDER_COUNT},
+};
+
+use super::transfer_allowlist::is_module_account;
+
+#[cw_serde]
+pub struct HolderActivity {
+    /// Block time of the last transfer sent or received by the holder.
+    pub last_active: Timestamp,
+
+    /// Number of transfers sent or received by the holder.
+    pub transfer_count: u64,
+}
+
+#[cw_serde]
+pub struct HolderInfo {
+    pub address: Addr,
+    pub activity: HolderActivity,
+}
+
+/// Record the transfer activity of both parties of a token transfer, called before the transfer is executed.
+/// The sender is removed from the index if the transfer leaves it with no balance,
+/// so that only current holders are listed and counted.
+/// The contract itself and module accounts are not tracked as holders.
+pub fn track_transfer(
+    deps: DepsMut,
+    env: &Env,
+    from: &str,
+    to: &str,
+    amount: &Coin,
+) -> StdResult<()> {
+    if is_tracked(deps.as_ref(), env, from) {
+        let balance = deps.querier.query_balance(from, &amount.denom)?;
+        if from != to && balance.amount <= amount.amount {
+            remove_holder(deps.storage, Addr::unchecked(from))?;
+        } else {
+            record_activity(deps.storage, env, Addr::unchecked(from))?;
+        }
+    }
+
+    if from != to && is_tracked(deps.as_ref(), env, to) && !amount.amount.is_zero() {
+        record_activity(deps.storage, env, Addr::unchecked(to))?;
+    }
+
+    Ok(())
+}
+
+fn is_tracked(deps: Deps, env: &Env, address: &str) -> bool {
+    !(address.is_empty()
+        || address == env.contract.address.as_str()
+        || is_module_account(deps.api, address))
+}
+
+fn record_activity(storage: &mut dyn Storage, env: &Env, address: Addr) -> StdResult<()> {
+    let activity = match HOLDERS.may_load(storage, address.clone())? {
+        Some(activity) => HolderActivity {
+            last_active: env.block.time,
+            transfer_count: activity.transfer_count + 1,
+        },
+        None => {
+            let count = HOLDER_COUNT.may_load(storage)?.unwrap_or_default();
+            HOLDER_COUNT.save(storage, &(count + 1))?;
+            HolderActivity {
+                last_active: env.block.time,
+                transfer_count: 1,
+            }
+        }
+    };
+
+    HOLDERS.save(storage, address, &activity)
+}
+
+fn remove_holder(storage: &mut dyn Storage, address: Addr) -> StdResult<()> {
+    if HOLDERS.has(storage, address.clone()) {
+        HOLDERS.remove(storage, address);
+        let count = HOLDER_COUNT.may_load(storage)?.unwrap_or_default();
+        HOLDER_COUNT.save(storage, &count.saturating_sub(1))?;
+    }
+
+    Ok(())
+}
+
+/// Number of addresses currently holding the token.
+pub fn get_holder_count(deps: Deps) -> StdResult<u64> {
+    Ok(HOLDER_COUNT.may_load(deps.storage)?.unwrap_or_default())
+}
+
+/// List the addresses currently holding the token with their transfer activity.
+pub fn list_holders(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<HolderInfo>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    HOLDERS
+        .range(
+            deps.storage,
+            start_after.map(|address| Bound::exclusive(Addr::unchecked(address))),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| {
+            let (address, activity) = item?;
+            Ok(HolderInfo { address, activity })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        coin,
+        testing::{mock_dependencies_with_balances, mock_env},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_track_transfer() {
+        let denom = "factory/osmo1contract/wbtc";
+        let alice = "osmo1alice";
+        let bob = "osmo1bob";
+        let carol = "osmo1carol";
+        let mut deps = mock_dependencies_with_balances(&[(alice, &[coin(100, denom)])]);
+        let mut env = mock_env();
+
+        assert_eq!(get_holder_count(deps.as_ref()).unwrap(), 0);
+
+        track_transfer(deps.as_mut(), &env, alice, bob, &coin(40, denom)).unwrap();
+        deps.querier.update_balance(alice, vec![coin(60, denom)]);
+        deps.querier.update_balance(bob, vec![coin(40, denom)]);
+
+        assert_eq!(get_holder_count(deps.as_ref()).unwrap(), 2);
+
+        // bob sends its whole balance and is no longer a holder
+        let first_transfer_time = env.block.time;
+        env.block.time = env.block.time.plus_seconds(60);
+        track_transfer(deps.as_mut(), &env, bob, carol, &coin(40, denom)).unwrap();
+        deps.querier.update_balance(bob, vec![]);
+        deps.querier.update_balance(carol, vec![coin(40, denom)]);
+
+        // transfers from and to the contract are not tracked for the contract
+        let contract_address = env.contract.address.to_string();
+        track_transfer(
+            deps.as_mut(),
+            &env,
+            &contract_address,
+            carol,
+            &coin(10, denom),
+        )
+        .unwrap();
+
+        assert_eq!(get_holder_count(deps.as_ref()).unwrap(), 2);
+        assert_eq!(
+            list_holders(deps.as_ref(), None, None).unwrap(),
+            vec![
+                HolderInfo {
+                    address: Addr::unchecked(alice),
+                    activity: HolderActivity {
+                        last_active: first_transfer_time,
+                        transfer_count: 1
+                    }
+                },
+                HolderInfo {
+                    address: Addr::unchecked(carol),
+                    activity: HolderActivity {
+                        last_active: env.block.time,
+                        transfer_count: 2
+                    }
+                },
+            ]
+        );
+
+        // transferring the whole balance to itself keeps the holder
+        track_transfer(deps.as_mut(), &env, alice, alice, &coin(60, denom)).unwrap();
+        assert_eq!(get_holder_count(deps.as_ref()).unwrap(), 2);
+
+        // pagination
+        assert_eq!(
+            list_holders(deps.as_ref(), Some(alice.to_string()), Some(1))
+                .unwrap()
+                .into_iter()
+                .map(|holder| holder.address)
+                .collect::<Vec<_>>(),
+            vec![Addr::unchecked(carol)]
+        );
+    }
+}
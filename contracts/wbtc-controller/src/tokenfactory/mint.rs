@@ -3,8 +3,13 @@ use std::fmt::Display;
 
 use crate::{
     attrs::action_attrs,
-    auth::{allow_only, merchant, Role},
-    state::mint::mint_requests,
+    auth::{
+        merchant,
+        operator::{self, operator_attrs, OperatorPermission},
+        permission::{allow_permission, Permission},
+        require_any, Role,
+    },
+    state::mint::{mint_requests, MINTING_PAUSED},
     tokenfactory::request::RequestData,
     ContractError,
 };
@@ -67,7 +72,7 @@ impl Status for MintRequestStatus {
     }
 }
 
-/// Issue a mint request. This can only be done by the merchant.
+/// Issue a mint request. This can only be done by the merchant or its operator.
 /// This will create a new mint request with `Pending` status.
 /// The mint request can be approved or rejected by the custodian.
 /// The mint request can be cancelled by the merchant.
@@ -78,14 +83,20 @@ pub fn issue_mint_request(
     amount: Uint128,
     tx_id: String,
 ) -> Result<Response, ContractError> {
-    allow_only(&[Role::Merchant], &info.sender, deps.as_ref())?;
+    let merchant = operator::allow_merchant_or_operator(
+        OperatorPermission::IssueMintRequest,
+        &info.sender,
+        &env,
+        deps.as_ref(),
+    )?;
+    merchant::ensure_merchant_not_suspended(deps.as_ref(), &merchant)?;
+    ensure_minting_not_paused(deps.as_ref())?;
 
-    let deposit_address =
-        deposit_address::get_custodian_deposit_address(deps.as_ref(), &info.sender)?;
+    let deposit_address = deposit_address::get_custodian_deposit_address(deps.as_ref(), &merchant)?;
 
     let (request_hash, request) = mint_requests().issue(
         deps,
-        info.sender,
+        merchant.clone(),
         amount,
         Some(tx_id),
         deposit_address,
@@ -97,27 +108,34 @@ pub fn issue_mint_request(
         <Vec<Attribute>>::from(&request.data()),
     );
     attrs.extend(vec![attr("request_hash", request_hash)]);
+    attrs.extend(operator_attrs(&info.sender, &merchant));
 
     Ok(Response::new().add_attributes(attrs))
 }
 
-/// Cancel a mint request. This can only be done by the merchant.
+/// Cancel a mint request. This can only be done by the merchant or its operator.
 /// This will update the mint request status to `Cancelled`.
 pub fn cancel_mint_request(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     request_hash: String,
 ) -> Result<Response, ContractError> {
-    allow_only(&[Role::Merchant], &info.sender, deps.as_ref())?;
+    let merchant = operator::allow_merchant_or_operator(
+        OperatorPermission::CancelMintRequest,
+        &info.sender,
+        &env,
+        deps.as_ref(),
+    )?;
     // update request status to `Cancelled`
     let request = mint_requests().check_and_update_request_status(
         deps,
         &request_hash,
         MintRequestStatus::Cancelled,
         |_, request| {
-            // ensure sender is the requester
+            // ensure sender is the requester or its operator
             ensure!(
-                request.requester == info.sender,
+                request.requester == merchant,
                 ContractError::Unauthorized {}
             );
 
@@ -131,6 +149,7 @@ pub fn cancel_mint_request(
         <Vec<Attribute>>::from(&request.data()),
     );
     attrs.extend(vec![attr("request_hash", request_hash)]);
+    attrs.extend(operator_attrs(&info.sender, &merchant));
 
     Ok(Response::new().add_attributes(attrs))
 }
@@ -139,11 +158,13 @@ pub fn cancel_mint_request(
 /// This will update the mint request status to `Approved` and mint the requested amount of tokens to the merchant address.
 pub fn approve_mint_request(
     mut deps: DepsMut,
+    env: &Env,
     info: MessageInfo,
     contract_address: Addr,
     request_hash: String,
 ) -> Result<Response, ContractError> {
-    allow_only(&[Role::Custodian], &info.sender, deps.as_ref())?;
+    allow_permission(Permission::MintApprove, &info.sender, deps.as_ref(), env)?;
+    ensure_minting_not_paused(deps.as_ref())?;
 
     let request_data = mint_requests()
         .check_and_update_request_status(
@@ -158,6 +179,7 @@ pub fn approve_mint_request(
                         address: request.requester.to_string()
                     }
                 );
+                merchant::ensure_merchant_not_suspended(deps, &request.requester)?;
 
                 Ok(())
             },
@@ -192,11 +214,12 @@ pub fn approve_mint_request(
 /// Reject a mint request. This can only be done by the custodian after custodian has validated the request.
 pub fn reject_mint_request(
     deps: DepsMut,
+    env: &Env,
     info: MessageInfo,
 
     request_hash: String,
 ) -> Result<Response, ContractError> {
-    allow_only(&[Role::Custodian], &info.sender, deps.as_ref())?;
+    allow_permission(Permission::MintReject, &info.sender, deps.as_ref(), env)?;
     let request_data = mint_requests()
         .check_and_update_request_status(
             deps,
@@ -212,6 +235,55 @@ pub fn reject_mint_request(
     Ok(Response::new().add_attributes(attrs))
 }
 
+/// Pause issuing and approving mint requests, while burning and token transfers keep working.
+/// Either the governor or the custodian can pause minting, e.g. when the custodian suspects a compromised deposit address.
+pub fn pause_minting(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+) -> Result<Response, ContractError> {
+    require_any(
+        &[Role::Governor, Role::Custodian],
+        &info.sender,
+        deps.as_ref(),
+        env,
+    )?;
+
+    MINTING_PAUSED.save(deps.storage, &true)?;
+
+    let attrs = action_attrs("pause_minting", vec![attr("sender", &info.sender)]);
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Unpause minting. Either the governor or the custodian can unpause minting.
+pub fn unpause_minting(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+) -> Result<Response, ContractError> {
+    require_any(
+        &[Role::Governor, Role::Custodian],
+        &info.sender,
+        deps.as_ref(),
+        env,
+    )?;
+
+    MINTING_PAUSED.save(deps.storage, &false)?;
+
+    let attrs = action_attrs("unpause_minting", vec![attr("sender", &info.sender)]);
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Check if minting is paused.
+pub fn is_minting_paused(deps: Deps) -> StdResult<bool> {
+    Ok(MINTING_PAUSED.may_load(deps.storage)?.unwrap_or(false))
+}
+
+fn ensure_minting_not_paused(deps: Deps) -> Result<(), ContractError> {
+    ensure!(!is_minting_paused(deps)?, ContractError::MintingPaused {});
+    Ok(())
+}
+
 pub fn get_mint_request_by_nonce(deps: Deps, nonce: &Uint128) -> StdResult<(String, MintRequest)> {
     mint_requests().get_request_by_nonce(deps, nonce)
 }
@@ -244,7 +316,7 @@ mod tests {
 
     use crate::{
         attrs::tests::setup_contract,
-        auth::{custodian, governor, member_manager, merchant},
+        auth::{self, custodian, governor, member_manager, merchant},
         contract, ContractError,
     };
 
@@ -258,20 +330,40 @@ mod tests {
         let mut deps = mock_dependencies();
 
         // setup
-        governor::initialize_governor(deps.as_mut(), governor).unwrap();
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
         member_manager::set_member_manager(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(governor, &[]),
             member_manager,
         )
         .unwrap();
-        custodian::set_custodian(deps.as_mut(), &mock_info(member_manager, &[]), custodian)
-            .unwrap();
-        merchant::add_merchant(deps.as_mut(), &mock_info(member_manager, &[]), merchant).unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant,
+            None,
+        )
+        .unwrap();
 
         // set custodian deposit address
         deposit_address::set_custodian_deposit_address(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(custodian, &[]),
             merchant,
             Some(custodian_deposit_address),
@@ -391,18 +483,32 @@ mod tests {
         setup_contract(deps.as_mut(), contract, governor, "wbtc").unwrap();
         member_manager::set_member_manager(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(governor, &[]),
             member_manager,
         )
         .unwrap();
 
-        custodian::set_custodian(deps.as_mut(), &mock_info(member_manager, &[]), custodian)
-            .unwrap();
-        merchant::add_merchant(deps.as_mut(), &mock_info(member_manager, &[]), merchant).unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant,
+            None,
+        )
+        .unwrap();
 
         // set custodian deposit address
         deposit_address::set_custodian_deposit_address(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(custodian, &[]),
             merchant,
             Some(custodian_deposit_address),
@@ -430,6 +536,7 @@ mod tests {
         // cancel mint request fail with unauthorized if not requester
         let err = cancel_mint_request(
             deps.as_mut(),
+            mock_env(),
             mock_info(governor, &[]),
             request_hash.clone(),
         )
@@ -438,7 +545,13 @@ mod tests {
         assert_eq!(err, ContractError::Unauthorized {});
 
         // cancel mint request succeed if requester
-        cancel_mint_request(deps.as_mut(), mock_info(merchant, &[]), request_hash).unwrap();
+        cancel_mint_request(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(merchant, &[]),
+            request_hash,
+        )
+        .unwrap();
     }
 
     #[test]
@@ -457,18 +570,32 @@ mod tests {
         let denom = setup_contract(deps.as_mut(), contract, governor, "wbtc").unwrap();
         member_manager::set_member_manager(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(governor, &[]),
             member_manager,
         )
         .unwrap();
 
-        custodian::set_custodian(deps.as_mut(), &mock_info(member_manager, &[]), custodian)
-            .unwrap();
-        merchant::add_merchant(deps.as_mut(), &mock_info(member_manager, &[]), merchant).unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant,
+            None,
+        )
+        .unwrap();
 
         // set custodian deposit address
         deposit_address::set_custodian_deposit_address(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(custodian, &[]),
             merchant,
             Some(custodian_deposit_address),
@@ -496,6 +623,7 @@ mod tests {
         // approve mint request with non existing request hash by custodian should fail
         let err = approve_mint_request(
             deps.as_mut(),
+            &mock_env(),
             mock_info(custodian, &[]),
             Addr::unchecked(contract),
             "non-existing-request-hash".to_string(),
@@ -510,6 +638,7 @@ mod tests {
         // approve mint request with non existing request hash by merchant should fail
         let err = approve_mint_request(
             deps.as_mut(),
+            &mock_env(),
             mock_info(merchant, &[]),
             Addr::unchecked(contract),
             "non-existing-request-hash".to_string(),
@@ -521,6 +650,7 @@ mod tests {
         // approve mint request with exising request hash by merchant should fail
         let err = approve_mint_request(
             deps.as_mut(),
+            &mock_env(),
             mock_info(merchant, &[]),
             Addr::unchecked(contract),
             request_hash.clone(),
@@ -530,12 +660,18 @@ mod tests {
         assert_eq!(err, ContractError::Unauthorized {});
 
         // remove merchant
-        merchant::remove_merchant(deps.as_mut(), &mock_info(member_manager, &[]), merchant)
-            .unwrap();
+        merchant::remove_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant,
+        )
+        .unwrap();
 
         // approve mint request with exising request hash by custodian but merchant is removed should fail
         let err = approve_mint_request(
             deps.as_mut(),
+            &mock_env(),
             mock_info(custodian, &[]),
             Addr::unchecked(contract),
             request_hash.clone(),
@@ -550,11 +686,19 @@ mod tests {
         );
 
         // add merchant
-        merchant::add_merchant(deps.as_mut(), &mock_info(member_manager, &[]), merchant).unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant,
+            None,
+        )
+        .unwrap();
 
         // approve mint request with exising request hash by custodian should succeed
         let res = approve_mint_request(
             deps.as_mut(),
+            &mock_env(),
             mock_info(custodian, &[]),
             Addr::unchecked(contract),
             request_hash.clone(),
@@ -579,6 +723,268 @@ mod tests {
         assert_eq!(request.status, MintRequestStatus::Approved);
     }
 
+    #[test]
+    fn test_suspended_merchant_cannot_mint() {
+        let governor = "osmo1governor";
+        let member_manager = "osmo1membermanager";
+        let custodian = "osmo1custodian";
+        let merchant = "osmo1merchant";
+        let contract = "osmo14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9sq2r9g9";
+        let mut deps = mock_dependencies();
+
+        // setup
+        setup_contract(deps.as_mut(), contract, governor, "wbtc").unwrap();
+        member_manager::set_member_manager(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            member_manager,
+        )
+        .unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant,
+            None,
+        )
+        .unwrap();
+        deposit_address::set_custodian_deposit_address(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(custodian, &[]),
+            merchant,
+            Some("bc1qzmylp874rg2st6pdlt8yjga3ek9pr96wuzelun"),
+        )
+        .unwrap();
+
+        let issue_mint_request_fixture = |deps: DepsMut, tx_id: &str| {
+            issue_mint_request(
+                deps,
+                mock_env(),
+                mock_info(merchant, &[]),
+                Uint128::new(100_000_000),
+                tx_id.to_string(),
+            )
+        };
+
+        let res = issue_mint_request_fixture(
+            deps.as_mut(),
+            "44e25bc0ed840f9bf0e58d6227db15192d5b89e79ba4304da16b09703f68ceaf",
+        )
+        .unwrap();
+        let request_hash = res
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "request_hash")
+            .unwrap()
+            .value
+            .clone();
+
+        merchant::suspend_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant,
+        )
+        .unwrap();
+
+        // suspended merchant can not issue mint request
+        assert_eq!(
+            issue_mint_request_fixture(
+                deps.as_mut(),
+                "d9a5e8ec2a8b6c0ccb7b3e36a1b5c1cf4e0f41f4f1c2c8d9e5a6c6d4b1e3f2a1",
+            )
+            .unwrap_err(),
+            ContractError::MerchantSuspended {
+                address: merchant.to_string()
+            }
+        );
+
+        // pending mint request of suspended merchant can not be approved
+        let approve = |deps: DepsMut| {
+            approve_mint_request(
+                deps,
+                &mock_env(),
+                mock_info(custodian, &[]),
+                Addr::unchecked(contract),
+                request_hash.clone(),
+            )
+        };
+        assert_eq!(
+            approve(deps.as_mut()).unwrap_err(),
+            ContractError::MerchantSuspended {
+                address: merchant.to_string()
+            }
+        );
+
+        // reinstated merchant's request can be approved
+        merchant::reinstate_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant,
+        )
+        .unwrap();
+        approve(deps.as_mut()).unwrap();
+    }
+
+    #[test]
+    fn test_pause_minting() {
+        let governor = "osmo1governor";
+        let member_manager = "osmo1membermanager";
+        let custodian = "osmo1custodian";
+        let merchant = "osmo1merchant";
+        let contract = "osmo14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9sq2r9g9";
+        let mut deps = mock_dependencies();
+
+        // setup
+        setup_contract(deps.as_mut(), contract, governor, "wbtc").unwrap();
+        member_manager::set_member_manager(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            member_manager,
+        )
+        .unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant,
+            None,
+        )
+        .unwrap();
+        deposit_address::set_custodian_deposit_address(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(custodian, &[]),
+            merchant,
+            Some("bc1qzmylp874rg2st6pdlt8yjga3ek9pr96wuzelun"),
+        )
+        .unwrap();
+
+        let issue_mint_request_fixture = |deps: DepsMut, tx_id: &str| {
+            issue_mint_request(
+                deps,
+                mock_env(),
+                mock_info(merchant, &[]),
+                Uint128::new(100_000_000),
+                tx_id.to_string(),
+            )
+        };
+
+        let res = issue_mint_request_fixture(
+            deps.as_mut(),
+            "44e25bc0ed840f9bf0e58d6227db15192d5b89e79ba4304da16b09703f68ceaf",
+        )
+        .unwrap();
+        let request_hash = res
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "request_hash")
+            .unwrap()
+            .value
+            .clone();
+
+        // only the governor or the custodian can pause minting
+        for sender in [member_manager, merchant] {
+            assert_eq!(
+                pause_minting(deps.as_mut(), &mock_env(), &mock_info(sender, &[])).unwrap_err(),
+                ContractError::Unauthorized {}
+            );
+            assert_eq!(
+                unpause_minting(deps.as_mut(), &mock_env(), &mock_info(sender, &[])).unwrap_err(),
+                ContractError::Unauthorized {}
+            );
+        }
+
+        let approve = |deps: DepsMut| {
+            approve_mint_request(
+                deps,
+                &mock_env(),
+                mock_info(custodian, &[]),
+                Addr::unchecked(contract),
+                request_hash.clone(),
+            )
+        };
+
+        for pauser in [governor, custodian] {
+            let res = pause_minting(deps.as_mut(), &mock_env(), &mock_info(pauser, &[])).unwrap();
+            assert_eq!(
+                res.attributes,
+                vec![attr("action", "pause_minting"), attr("sender", pauser)]
+            );
+            assert!(is_minting_paused(deps.as_ref()).unwrap());
+
+            // mint requests can neither be issued nor approved
+            assert_eq!(
+                issue_mint_request_fixture(
+                    deps.as_mut(),
+                    "d9a5e8ec2a8b6c0ccb7b3e36a1b5c1cf4e0f41f4f1c2c8d9e5a6c6d4b1e3f2a1",
+                )
+                .unwrap_err(),
+                ContractError::MintingPaused {}
+            );
+            assert_eq!(
+                approve(deps.as_mut()).unwrap_err(),
+                ContractError::MintingPaused {}
+            );
+
+            // token transfer is not paused
+            assert!(!token::is_paused(deps.as_ref()).unwrap());
+
+            let res = unpause_minting(deps.as_mut(), &mock_env(), &mock_info(pauser, &[])).unwrap();
+            assert_eq!(
+                res.attributes,
+                vec![attr("action", "unpause_minting"), attr("sender", pauser)]
+            );
+            assert!(!is_minting_paused(deps.as_ref()).unwrap());
+        }
+
+        approve(deps.as_mut()).unwrap();
+    }
+
+    #[test]
+    fn test_pause_minting_roles() {
+        let mut deps = mock_dependencies();
+        let holders = auth::tests::setup_all_roles(deps.as_mut());
+
+        // exactly the governor and the custodian can pause and unpause minting
+        for (role, address) in holders {
+            let info = mock_info(address.as_str(), &[]);
+            if [Role::Governor, Role::Custodian].contains(&role) {
+                pause_minting(deps.as_mut(), &mock_env(), &info).unwrap();
+                assert!(is_minting_paused(deps.as_ref()).unwrap());
+                unpause_minting(deps.as_mut(), &mock_env(), &info).unwrap();
+                assert!(!is_minting_paused(deps.as_ref()).unwrap());
+            } else {
+                assert_eq!(
+                    pause_minting(deps.as_mut(), &mock_env(), &info).unwrap_err(),
+                    ContractError::Unauthorized {}
+                );
+                assert_eq!(
+                    unpause_minting(deps.as_mut(), &mock_env(), &info).unwrap_err(),
+                    ContractError::Unauthorized {}
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_reject_mint_request() {
         let governor = "osmo1governor";
@@ -599,24 +1005,45 @@ mod tests {
             crate::msg::InstantiateMsg {
                 governor: governor.to_string(),
                 subdenom: denom.to_string(),
+                existing_denom: None,
+                member_manager: None,
+                custodian: None,
+                merchants: None,
+                min_burn_amount: None,
+                denom_metadata: None,
+                require_contract_holders: None,
             },
         )
         .unwrap();
 
         member_manager::set_member_manager(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(governor, &[]),
             member_manager,
         )
         .unwrap();
 
-        custodian::set_custodian(deps.as_mut(), &mock_info(member_manager, &[]), custodian)
-            .unwrap();
-        merchant::add_merchant(deps.as_mut(), &mock_info(member_manager, &[]), merchant).unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant,
+            None,
+        )
+        .unwrap();
 
         // set custodian deposit address
         deposit_address::set_custodian_deposit_address(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(custodian, &[]),
             merchant,
             Some(custodian_deposit_address),
@@ -644,6 +1071,7 @@ mod tests {
         // reject mint request with non existing request hash by custodian should fail
         let err = reject_mint_request(
             deps.as_mut(),
+            &mock_env(),
             mock_info(custodian, &[]),
             "non-existing-request-hash".to_string(),
         )
@@ -657,6 +1085,7 @@ mod tests {
         // reject mint request with non existing request hash by merchant should fail
         let err = reject_mint_request(
             deps.as_mut(),
+            &mock_env(),
             mock_info(merchant, &[]),
             "non-existing-request-hash".to_string(),
         )
@@ -667,6 +1096,7 @@ mod tests {
         // reject mint request with exising request hash by merchant should fail
         let err = reject_mint_request(
             deps.as_mut(),
+            &mock_env(),
             mock_info(merchant, &[]),
             request_hash.clone(),
         )
@@ -677,6 +1107,7 @@ mod tests {
         // reject mint request with exising request hash by custodian should succeed
         let _res = reject_mint_request(
             deps.as_mut(),
+            &mock_env(),
             mock_info(custodian, &[]),
             request_hash.clone(),
         )
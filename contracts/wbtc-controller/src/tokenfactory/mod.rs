@@ -1,8 +1,18 @@
 pub mod burn;
+pub mod denom_admin;
 pub mod deposit_address;
+pub mod enforcement;
+pub mod holders;
 pub mod mint;
 mod nonce;
+pub mod payout_address;
+pub mod redemption;
 mod request;
+mod rolling_window;
 pub mod token;
+pub mod transfer_allowlist;
+pub mod transfer_limit;
 
+pub use nonce::Nonce;
 pub use request::{Request, RequestData, RequestManager, Status};
+pub use rolling_window::RollingWindow;
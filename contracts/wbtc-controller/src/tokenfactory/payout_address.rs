@@ -0,0 +1,698 @@
+/// `payout_address` module provides BTC address validation
+/// and a merchant-managed whitelist of BTC addresses that burned tokens can be paid out to.
+use cosmwasm_std::{
+    attr, ensure, Addr, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, Storage,
+};
+use cw_storage_plus::Bound;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    attrs::action_attrs,
+    auth::{require_any, Role},
+    constants::{DEFAULT_LIMIT, MAX_LIMIT},
+    state::{
+        deposit_address::{DEPOSIT_ADDRESS_TRACKER, MERCHANT_DEPOSIT_ADDRESS},
+        payout_address::{PAYOUT_ADDRESSES, PAYOUT_ADDRESS_WHITELIST_ENFORCED},
+    },
+    ContractError,
+};
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Base58check version bytes of P2PKH and P2SH addresses for mainnet and testnet / regtest.
+const BASE58_VERSIONS: [u8; 4] = [0x00, 0x05, 0x6f, 0xc4];
+
+const BECH32_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Human readable parts of segwit addresses for mainnet, testnet and regtest.
+const BECH32_HRPS: [&str; 3] = ["bc", "tb", "bcrt"];
+
+const BECH32_CONST: u32 = 1;
+const BECH32M_CONST: u32 = 0x2bc8_30a3;
+
+/// Validate that the given string is a well-formed Bitcoin address,
+/// either a base58check P2PKH / P2SH address or a bech32 / bech32m segwit address,
+/// including checksum.
+pub fn validate_btc_address(address: &str) -> Result<(), ContractError> {
+    ensure!(
+        is_valid_segwit_address(address) || is_valid_base58_address(address),
+        ContractError::InvalidBtcAddress {
+            address: address.to_string()
+        }
+    );
+
+    Ok(())
+}
+
+/// Validate the BTC address and return its canonical form, see `canonical_btc_address`.
+pub fn normalize_btc_address(address: &str) -> Result<String, ContractError> {
+    validate_btc_address(address)?;
+    Ok(canonical_btc_address(address))
+}
+
+/// Canonical form of a BTC address used for storage and comparison.
+/// Bech32 addresses are case insensitive and lowercased, base58 addresses are case sensitive and kept as is.
+pub fn canonical_btc_address(address: &str) -> String {
+    if is_valid_segwit_address(address) {
+        address.to_ascii_lowercase()
+    } else {
+        address.to_string()
+    }
+}
+
+fn is_valid_base58_address(address: &str) -> bool {
+    let decoded = match decode_base58(address) {
+        Some(decoded) => decoded,
+        None => return false,
+    };
+
+    if decoded.len() != 25 || !BASE58_VERSIONS.contains(&decoded[0]) {
+        return false;
+    }
+
+    let (payload, checksum) = decoded.split_at(21);
+    Sha256::digest(Sha256::digest(payload))[..4] == *checksum
+}
+
+fn decode_base58(s: &str) -> Option<Vec<u8>> {
+    let mut bytes: Vec<u8> = vec![];
+
+    for c in s.bytes() {
+        let mut carry = BASE58_ALPHABET.iter().position(|&a| a == c)? as u32;
+        for byte in bytes.iter_mut().rev() {
+            carry += (*byte as u32) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.insert(0, (carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+
+    // each leading '1' represents a leading zero byte
+    let leading_zeros = s.bytes().take_while(|&c| c == b'1').count();
+    Some([vec![0; leading_zeros], bytes].concat())
+}
+
+fn is_valid_segwit_address(address: &str) -> bool {
+    // mixed case is not allowed
+    let lowercase = address.to_lowercase();
+    if address.len() > 90 || (address != lowercase && address != address.to_uppercase()) {
+        return false;
+    }
+
+    let (hrp, data) = match lowercase.rsplit_once('1') {
+        Some(parts) => parts,
+        None => return false,
+    };
+
+    if !BECH32_HRPS.contains(&hrp) {
+        return false;
+    }
+
+    let data = match data
+        .bytes()
+        .map(|c| BECH32_CHARSET.iter().position(|&x| x == c).map(|p| p as u8))
+        .collect::<Option<Vec<u8>>>()
+    {
+        Some(data) => data,
+        None => return false,
+    };
+
+    // witness version and 6 characters checksum
+    if data.len() < 7 {
+        return false;
+    }
+
+    let checksum = bech32_polymod(
+        hrp.bytes()
+            .map(|c| c >> 5)
+            .chain([0])
+            .chain(hrp.bytes().map(|c| c & 31))
+            .chain(data.iter().copied()),
+    );
+
+    // witness version 0 uses bech32, later versions use bech32m
+    let witness_version = data[0];
+    let is_valid_checksum = match witness_version {
+        0 => checksum == BECH32_CONST,
+        1..=16 => checksum == BECH32M_CONST,
+        _ => false,
+    };
+
+    // witness program is converted from 5-bit groups to bytes, padding must be less than 5 zero bits
+    let program = &data[1..data.len() - 6];
+    let padding_bits = program.len() * 5 % 8;
+    let program_len = program.len() * 5 / 8;
+    let last = program.last().copied().unwrap_or_default();
+    let is_valid_padding = padding_bits < 5 && last & ((1 << padding_bits) - 1) == 0;
+
+    let is_valid_program_len = (2..=40).contains(&program_len)
+        && (witness_version != 0 || program_len == 20 || program_len == 32);
+
+    is_valid_checksum && is_valid_padding && is_valid_program_len
+}
+
+fn bech32_polymod(values: impl Iterator<Item = u8>) -> u32 {
+    const GENERATOR: [u32; 5] = [
+        0x3b6a_57b2,
+        0x2650_8e6d,
+        0x1ea1_19fa,
+        0x3d42_33dd,
+        0x2a14_62b3,
+    ];
+
+    values.fold(1, |chk, value| {
+        let top = chk >> 25;
+        GENERATOR
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| (top >> i) & 1 == 1)
+            .fold(((chk & 0x01ff_ffff) << 5) ^ value as u32, |chk, (_, g)| {
+                chk ^ g
+            })
+    })
+}
+
+/// Add a BTC address to the sender's payout address whitelist.
+/// Only the merchant can manage its own payout addresses.
+pub fn add_payout_address(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    btc_address: &str,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::Merchant], &info.sender, deps.as_ref(), env)?;
+
+    let btc_address = normalize_btc_address(btc_address)?;
+
+    PAYOUT_ADDRESSES.save(
+        deps.storage,
+        (info.sender.clone(), btc_address.clone()),
+        &(),
+    )?;
+
+    let attrs = action_attrs(
+        "add_payout_address",
+        vec![
+            attr("merchant", info.sender.as_str()),
+            attr("btc_address", btc_address),
+        ],
+    );
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Remove a BTC address from the sender's payout address whitelist.
+pub fn remove_payout_address(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    btc_address: &str,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::Merchant], &info.sender, deps.as_ref(), env)?;
+
+    let btc_address = canonical_btc_address(btc_address);
+
+    ensure!(
+        is_payout_address(deps.as_ref(), &info.sender, &btc_address)?,
+        ContractError::PayoutAddressNotWhitelisted {
+            merchant: info.sender.to_string(),
+            address: btc_address
+        }
+    );
+
+    PAYOUT_ADDRESSES.remove(deps.storage, (info.sender.clone(), btc_address.clone()));
+
+    let attrs = action_attrs(
+        "remove_payout_address",
+        vec![
+            attr("merchant", info.sender.as_str()),
+            attr("btc_address", btc_address),
+        ],
+    );
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Set whether burns of the sender to an ad-hoc BTC address must use a whitelisted payout address.
+pub fn set_payout_address_whitelist_enforced(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    enforced: bool,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::Merchant], &info.sender, deps.as_ref(), env)?;
+
+    PAYOUT_ADDRESS_WHITELIST_ENFORCED.save(deps.storage, info.sender.clone(), &enforced)?;
+
+    let attrs = action_attrs(
+        "set_payout_address_whitelist_enforced",
+        vec![
+            attr("merchant", info.sender.as_str()),
+            attr("enforced", enforced.to_string()),
+        ],
+    );
+    Ok(Response::new().add_attributes(attrs))
+}
+
+pub fn is_payout_address(deps: Deps, merchant: &Addr, btc_address: &str) -> StdResult<bool> {
+    Ok(PAYOUT_ADDRESSES
+        .may_load(
+            deps.storage,
+            (merchant.clone(), canonical_btc_address(btc_address)),
+        )?
+        .is_some())
+}
+
+pub fn is_payout_address_whitelist_enforced(deps: Deps, merchant: &Addr) -> StdResult<bool> {
+    Ok(PAYOUT_ADDRESS_WHITELIST_ENFORCED
+        .may_load(deps.storage, merchant.clone())?
+        .unwrap_or(false))
+}
+
+/// List payout addresses of the merchant with pagination.
+pub fn list_payout_addresses(
+    deps: Deps,
+    merchant: &Addr,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<String>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    PAYOUT_ADDRESSES
+        .prefix(merchant.clone())
+        .keys(
+            deps.storage,
+            start_after
+                .map(|address| canonical_btc_address(&address))
+                .map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect()
+}
+
+/// Remove all payout addresses and the whitelist setting of the merchant.
+pub fn clear_payout_addresses(storage: &mut dyn Storage, merchant: &Addr) -> StdResult<()> {
+    let addresses = PAYOUT_ADDRESSES
+        .prefix(merchant.clone())
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for address in addresses {
+        PAYOUT_ADDRESSES.remove(storage, (merchant.clone(), address));
+    }
+
+    PAYOUT_ADDRESS_WHITELIST_ENFORCED.remove(storage, merchant.clone());
+
+    Ok(())
+}
+
+/// Ensure the BTC address is not associated as a custodian or merchant deposit address,
+/// so that paid out BTC is not sent back into the mint flow.
+pub fn ensure_not_deposit_address(deps: Deps, btc_address: &str) -> Result<(), ContractError> {
+    ensure!(
+        !DEPOSIT_ADDRESS_TRACKER.is_associated(deps, canonical_btc_address(btc_address))?,
+        ContractError::DepositAddressAlreadyAssociated {
+            address: btc_address.to_string()
+        }
+    );
+
+    Ok(())
+}
+
+/// Ensure the BTC address can be used as the deposit address of the merchant's burn request:
+/// - it must be a valid BTC address
+/// - it must not be associated as another deposit address, except the merchant's own
+/// - it must be whitelisted if the merchant enforces the payout address whitelist
+///
+/// Returns the canonical form of the BTC address.
+pub fn ensure_valid_payout_address(
+    deps: Deps,
+    merchant: &Addr,
+    btc_address: &str,
+) -> Result<String, ContractError> {
+    let btc_address = normalize_btc_address(btc_address)?;
+
+    let is_own_deposit_address = MERCHANT_DEPOSIT_ADDRESS
+        .get_deposit_address(deps, merchant)?
+        .as_deref()
+        == Some(btc_address.as_str());
+
+    if !is_own_deposit_address {
+        ensure_not_deposit_address(deps, &btc_address)?;
+    }
+
+    if is_payout_address_whitelist_enforced(deps, merchant)? {
+        ensure!(
+            is_payout_address(deps, merchant, &btc_address)?,
+            ContractError::PayoutAddressNotWhitelisted {
+                merchant: merchant.to_string(),
+                address: btc_address
+            }
+        );
+    }
+
+    Ok(btc_address)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    use crate::{
+        auth::{custodian, governor, member_manager, merchant},
+        tokenfactory::deposit_address,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_validate_btc_address() {
+        let valid_addresses = [
+            // P2PKH
+            "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2",
+            // P2SH
+            "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy",
+            // testnet P2PKH
+            "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn",
+            // P2WPKH
+            "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq",
+            "BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4",
+            // P2WSH
+            "bc1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3qccfmv3",
+            // P2TR
+            "bc1p5d7rjq7g6rdk2yhzks9smlaqtedr4dekq08ge8ztwac72sfr9rusxg3297",
+            // testnet P2WPKH
+            "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx",
+        ];
+
+        for address in valid_addresses {
+            assert_eq!(validate_btc_address(address), Ok(()), "{address}");
+        }
+
+        let invalid_addresses = [
+            "",
+            "bc1depositaddress",
+            // bad checksum
+            "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3",
+            "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdr",
+            // mixed case
+            "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdQ",
+            // unknown hrp
+            "ltc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq",
+            // witness version 1 with bech32 checksum
+            "bc1pw508d6qejxtdg4y5r3zarvary0c5xw7kw508d6qejxtdg4y5r3zarvary0c5xw7k7grplx",
+            // character not in base58 alphabet
+            "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN0",
+        ];
+
+        // bech32 addresses are lowercased, base58 addresses are case sensitive
+        assert_eq!(
+            normalize_btc_address("BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4").unwrap(),
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"
+        );
+        assert_eq!(
+            normalize_btc_address("3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy").unwrap(),
+            "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy"
+        );
+
+        for address in invalid_addresses {
+            assert_eq!(
+                validate_btc_address(address).unwrap_err(),
+                ContractError::InvalidBtcAddress {
+                    address: address.to_string()
+                },
+                "{address}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_payout_address_whitelist() {
+        let mut deps = mock_dependencies();
+        let governor = "osmo1governor";
+        let member_manager = "osmo1membermanager";
+        let custodian = "osmo1custodian";
+        let merchant_1 = "osmo1merchant1";
+        let merchant_2 = "osmo1merchant2";
+        let payout_address_1 = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+        let payout_address_2 = "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy";
+        let custodian_deposit_address = "bc1q35rayrk92pvwamwm4n2hsd3epez2g2tqcqa0fx";
+        let merchant_deposit_address = "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2";
+
+        // setup
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
+        member_manager::set_member_manager(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            member_manager,
+        )
+        .unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant_1,
+            None,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant_2,
+            None,
+        )
+        .unwrap();
+
+        deposit_address::set_custodian_deposit_address(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(custodian, &[]),
+            merchant_1,
+            Some(custodian_deposit_address),
+        )
+        .unwrap();
+        deposit_address::set_merchant_deposit_address(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(merchant_1, &[]),
+            Some(merchant_deposit_address),
+        )
+        .unwrap();
+
+        let merchant_1_addr = Addr::unchecked(merchant_1);
+        let merchant_2_addr = Addr::unchecked(merchant_2);
+
+        // only merchant can manage payout addresses
+        assert_eq!(
+            add_payout_address(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(custodian, &[]),
+                payout_address_1
+            )
+            .unwrap_err(),
+            ContractError::Unauthorized {}
+        );
+        assert_eq!(
+            set_payout_address_whitelist_enforced(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(custodian, &[]),
+                true
+            )
+            .unwrap_err(),
+            ContractError::Unauthorized {}
+        );
+
+        // invalid address can not be added
+        assert_eq!(
+            add_payout_address(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(merchant_1, &[]),
+                "bc1depositaddress"
+            )
+            .unwrap_err(),
+            ContractError::InvalidBtcAddress {
+                address: "bc1depositaddress".to_string()
+            }
+        );
+
+        assert_eq!(
+            add_payout_address(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(merchant_1, &[]),
+                payout_address_1
+            )
+            .unwrap()
+            .attributes,
+            vec![
+                attr("action", "add_payout_address"),
+                attr("merchant", merchant_1),
+                attr("btc_address", payout_address_1),
+            ]
+        );
+
+        // bech32 address is stored lowercased, adding it in upper case does not duplicate it
+        add_payout_address(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(merchant_1, &[]),
+            &payout_address_1.to_uppercase(),
+        )
+        .unwrap();
+        assert_eq!(
+            list_payout_addresses(deps.as_ref(), &merchant_1_addr, None, None).unwrap(),
+            vec![payout_address_1.to_string()]
+        );
+        assert_eq!(
+            list_payout_addresses(deps.as_ref(), &merchant_2_addr, None, None).unwrap(),
+            Vec::<String>::new()
+        );
+
+        // any valid address can be used when whitelist is not enforced
+        assert!(!is_payout_address_whitelist_enforced(deps.as_ref(), &merchant_1_addr).unwrap());
+        ensure_valid_payout_address(deps.as_ref(), &merchant_1_addr, payout_address_2).unwrap();
+
+        // deposit addresses can not be used, except the merchant's own
+        assert_eq!(
+            ensure_valid_payout_address(deps.as_ref(), &merchant_1_addr, custodian_deposit_address)
+                .unwrap_err(),
+            ContractError::DepositAddressAlreadyAssociated {
+                address: custodian_deposit_address.to_string()
+            }
+        );
+        assert_eq!(
+            ensure_valid_payout_address(
+                deps.as_ref(),
+                &merchant_1_addr,
+                &custodian_deposit_address.to_uppercase()
+            )
+            .unwrap_err(),
+            ContractError::DepositAddressAlreadyAssociated {
+                address: custodian_deposit_address.to_string()
+            }
+        );
+        ensure_valid_payout_address(deps.as_ref(), &merchant_1_addr, merchant_deposit_address)
+            .unwrap();
+        assert_eq!(
+            ensure_valid_payout_address(deps.as_ref(), &merchant_2_addr, merchant_deposit_address)
+                .unwrap_err(),
+            ContractError::DepositAddressAlreadyAssociated {
+                address: merchant_deposit_address.to_string()
+            }
+        );
+
+        // only whitelisted address can be used when whitelist is enforced
+        assert_eq!(
+            set_payout_address_whitelist_enforced(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(merchant_1, &[]),
+                true
+            )
+            .unwrap()
+            .attributes,
+            vec![
+                attr("action", "set_payout_address_whitelist_enforced"),
+                attr("merchant", merchant_1),
+                attr("enforced", "true"),
+            ]
+        );
+        assert!(is_payout_address_whitelist_enforced(deps.as_ref(), &merchant_1_addr).unwrap());
+
+        ensure_valid_payout_address(deps.as_ref(), &merchant_1_addr, payout_address_1).unwrap();
+        assert_eq!(
+            ensure_valid_payout_address(
+                deps.as_ref(),
+                &merchant_1_addr,
+                &payout_address_1.to_uppercase()
+            )
+            .unwrap(),
+            payout_address_1
+        );
+        assert_eq!(
+            ensure_valid_payout_address(deps.as_ref(), &merchant_1_addr, payout_address_2)
+                .unwrap_err(),
+            ContractError::PayoutAddressNotWhitelisted {
+                merchant: merchant_1.to_string(),
+                address: payout_address_2.to_string()
+            }
+        );
+
+        // whitelist of other merchants is not affected
+        ensure_valid_payout_address(deps.as_ref(), &merchant_2_addr, payout_address_2).unwrap();
+
+        // remove payout address
+        assert_eq!(
+            remove_payout_address(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(merchant_2, &[]),
+                payout_address_1
+            )
+            .unwrap_err(),
+            ContractError::PayoutAddressNotWhitelisted {
+                merchant: merchant_2.to_string(),
+                address: payout_address_1.to_string()
+            }
+        );
+        remove_payout_address(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(merchant_1, &[]),
+            &payout_address_1.to_uppercase(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            ensure_valid_payout_address(deps.as_ref(), &merchant_1_addr, payout_address_1)
+                .unwrap_err(),
+            ContractError::PayoutAddressNotWhitelisted {
+                merchant: merchant_1.to_string(),
+                address: payout_address_1.to_string()
+            }
+        );
+
+        // removing merchant clears its payout addresses
+        add_payout_address(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(merchant_1, &[]),
+            payout_address_1,
+        )
+        .unwrap();
+        merchant::remove_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant_1,
+        )
+        .unwrap();
+
+        assert_eq!(
+            list_payout_addresses(deps.as_ref(), &merchant_1_addr, None, None).unwrap(),
+            Vec::<String>::new()
+        );
+        assert!(!is_payout_address_whitelist_enforced(deps.as_ref(), &merchant_1_addr).unwrap());
+    }
+}
@@ -0,0 +1,984 @@
+/// `redemption` module provides functionalities for token holders to redeem their tokens through a merchant.
+use std::fmt::Display;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    attr, ensure, Addr, Attribute, BankMsg, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
+    Response, StdError, StdResult, Uint128,
+};
+use osmosis_std::types::osmosis::tokenfactory::v1beta1::MsgBurn;
+
+use crate::{
+    attrs::action_attrs,
+    auth::{
+        merchant,
+        permission::{allow_permission, Permission},
+        require_any, Role,
+    },
+    constants::DEFAULT_REDEMPTION_TIMEOUT_SECONDS,
+    error::must_pay,
+    state::redemption::{redemption_requests, REDEMPTION_ROUTING, REDEMPTION_TIMEOUT},
+    ContractError,
+};
+
+use super::{
+    burn, payout_address,
+    request::{Request, Status},
+    token,
+};
+
+/// Redemption request status.
+#[cw_serde]
+pub enum RedemptionRequestStatus {
+    /// Initial status, the tokens are escrowed by the contract waiting for the merchant to accept or decline.
+    Pending,
+
+    /// Accepted status, the escrowed tokens are burned and a burn request is created for the merchant.
+    /// This status can no longer be updated.
+    Accepted,
+
+    /// Declined status, the escrowed tokens are returned to the holder.
+    /// This status can no longer be updated.
+    Declined,
+
+    /// Reclaimed status, the holder took back the escrowed tokens after the redemption timeout.
+    /// This status can no longer be updated.
+    Reclaimed,
+}
+
+pub type RedemptionRequest = Request<RedemptionRequestStatus>;
+
+/// `Display` implementation for `RedemptionRequestStatus`. This is mainly used for attribute serialization.
+impl Display for RedemptionRequestStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RedemptionRequestStatus::Pending => write!(f, "Pending"),
+            RedemptionRequestStatus::Accepted => write!(f, "Accepted"),
+            RedemptionRequestStatus::Declined => write!(f, "Declined"),
+            RedemptionRequestStatus::Reclaimed => write!(f, "Reclaimed"),
+        }
+    }
+}
+
+/// `Status` implementation for `RedemptionRequestStatus`.
+/// Ensuring that:
+/// - `RedemptionRequestStatus` is only initialized as `Pending` status.
+/// - `RedemptionRequestStatus` is only updatable when it is in `Pending` status.
+impl Status for RedemptionRequestStatus {
+    fn initial() -> Self {
+        Self::Pending
+    }
+
+    fn is_updatable(&self) -> bool {
+        self == &Self::initial()
+    }
+}
+
+/// Routing information of a redemption request.
+#[cw_serde]
+pub struct RedemptionRouting {
+    /// Merchant chosen by the holder to process the redemption
+    pub merchant: Addr,
+
+    /// Hash of the burn request created once the merchant accepted the redemption
+    pub burn_request_hash: Option<String>,
+}
+
+/// `RedemptionRequestInfo` contains redemption request with its hash and routing information.
+#[cw_serde]
+pub struct RedemptionRequestInfo {
+    pub request_hash: String,
+    pub request: RedemptionRequest,
+    pub merchant: Addr,
+    pub burn_request_hash: Option<String>,
+}
+
+/// Request redemption of the attached tokens. Anyone holding the token can request redemption.
+/// This will create a new redemption request with `Pending` status and escrow the attached tokens
+/// until the chosen merchant accepts or declines the request, or the holder reclaims them after timeout.
+/// The requester is the holder and the deposit address is the holder's BTC address.
+pub fn request_redemption(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    merchant: String,
+    btc_address: String,
+) -> Result<Response, ContractError> {
+    let denom = token::get_token_denom(deps.storage)?;
+    let amount = must_pay(&info, &denom)?;
+
+    let btc_address = payout_address::normalize_btc_address(&btc_address)?;
+    payout_address::ensure_not_deposit_address(deps.as_ref(), &btc_address)?;
+
+    let merchant = deps.api.addr_validate(&merchant)?;
+    ensure!(
+        merchant::is_merchant(deps.as_ref(), &merchant)?,
+        ContractError::NotAMerchant {
+            address: merchant.to_string()
+        }
+    );
+
+    // fail early since the merchant would not be able to accept the redemption anyway
+    burn::ensure_min_burn_amount(deps.as_ref(), amount)?;
+
+    let (request_hash, request) = redemption_requests().issue(
+        deps.branch(),
+        info.sender,
+        amount,
+        None,
+        btc_address,
+        env.block.time,
+    )?;
+
+    REDEMPTION_ROUTING.save(
+        deps.storage,
+        request_hash.clone(),
+        &RedemptionRouting {
+            merchant: merchant.clone(),
+            burn_request_hash: None,
+        },
+    )?;
+
+    let mut attrs = action_attrs(
+        "request_redemption",
+        <Vec<Attribute>>::from(&request.data()),
+    );
+    attrs.extend(vec![
+        attr("merchant", merchant),
+        attr("request_hash", request_hash),
+    ]);
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Accept a redemption request. This can only be done by the merchant chosen by the holder.
+/// This will update the redemption request status to `Accepted`, burn the escrowed tokens
+/// and create a `Pending` burn request for the merchant with the holder's BTC address as deposit address,
+/// so that the custodian sends the BTC directly to the holder.
+pub fn accept_redemption(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    request_hash: String,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::Merchant], &info.sender, deps.as_ref(), &env)?;
+    merchant::ensure_merchant_not_suspended(deps.as_ref(), &info.sender)?;
+
+    let mut routing = get_redemption_routing(deps.as_ref(), &request_hash)?;
+
+    let request = redemption_requests().check_and_update_request_status(
+        deps.branch(),
+        &request_hash,
+        RedemptionRequestStatus::Accepted,
+        |_, _| {
+            // ensure sender is the merchant chosen by the holder
+            ensure!(
+                routing.merchant == info.sender,
+                ContractError::Unauthorized {}
+            );
+
+            Ok(())
+        },
+    )?;
+
+    // the holder's BTC address could have been associated as a deposit address since the request
+    payout_address::ensure_not_deposit_address(deps.as_ref(), &request.deposit_address)?;
+
+    // record burn request for the merchant, the BTC will be sent to the holder's BTC address
+    let (burn_request_hash, _) = burn::issue_burn_request(
+        deps.branch(),
+        info.sender,
+        request.amount,
+        request.deposit_address.clone(),
+        env.block.time,
+    )?;
+
+    routing.burn_request_hash = Some(burn_request_hash.clone());
+    REDEMPTION_ROUTING.save(deps.storage, request_hash.clone(), &routing)?;
+
+    // burn the escrowed tokens
+    let denom = token::get_token_denom(deps.storage)?;
+    let burn_msg: CosmosMsg = MsgBurn {
+        sender: env.contract.address.to_string(),
+        amount: Some(Coin::new(request.amount.u128(), denom).into()),
+        // empty `burn_from_address` burns from the sender, which is this contract holding the escrow
+        burn_from_address: String::new(),
+    }
+    .into();
+
+    let mut attrs = action_attrs("accept_redemption", <Vec<Attribute>>::from(&request.data()));
+    attrs.extend(vec![
+        attr("request_hash", request_hash),
+        attr("burn_request_hash", burn_request_hash),
+    ]);
+
+    Ok(Response::new().add_message(burn_msg).add_attributes(attrs))
+}
+
+/// Decline a redemption request. This can only be done by the merchant chosen by the holder.
+/// This will update the redemption request status to `Declined` and return the escrowed tokens to the holder.
+pub fn decline_redemption(
+    deps: DepsMut,
+    env: &Env,
+    info: MessageInfo,
+    request_hash: String,
+) -> Result<Response, ContractError> {
+    require_any(&[Role::Merchant], &info.sender, deps.as_ref(), env)?;
+
+    let routing = get_redemption_routing(deps.as_ref(), &request_hash)?;
+    let denom = token::get_token_denom(deps.storage)?;
+
+    let request = redemption_requests().check_and_update_request_status(
+        deps,
+        &request_hash,
+        RedemptionRequestStatus::Declined,
+        |_, _| {
+            // ensure sender is the merchant chosen by the holder
+            ensure!(
+                routing.merchant == info.sender,
+                ContractError::Unauthorized {}
+            );
+
+            Ok(())
+        },
+    )?;
+
+    let refund_msg = refund(&request, denom);
+
+    let mut attrs = action_attrs(
+        "decline_redemption",
+        <Vec<Attribute>>::from(&request.data()),
+    );
+    attrs.extend(vec![attr("request_hash", request_hash)]);
+
+    Ok(Response::new()
+        .add_message(refund_msg)
+        .add_attributes(attrs))
+}
+
+/// Reclaim the escrowed tokens of a pending redemption request. This can only be done by the holder
+/// who requested the redemption, once the redemption timeout has passed.
+/// This will update the redemption request status to `Reclaimed` and return the escrowed tokens to the holder.
+pub fn reclaim_redemption(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    request_hash: String,
+) -> Result<Response, ContractError> {
+    let timeout = get_redemption_timeout(deps.as_ref())?;
+    let denom = token::get_token_denom(deps.storage)?;
+
+    let request = redemption_requests().check_and_update_request_status(
+        deps,
+        &request_hash,
+        RedemptionRequestStatus::Reclaimed,
+        |_, request| {
+            // ensure sender is the holder
+            ensure!(
+                request.requester == info.sender,
+                ContractError::Unauthorized {}
+            );
+
+            // ensure the merchant had enough time to process the redemption
+            let reclaimable_at = request.timestamp.plus_seconds(timeout);
+            ensure!(
+                env.block.time >= reclaimable_at,
+                ContractError::RedemptionNotReclaimable {
+                    request_hash: request_hash.clone(),
+                    reclaimable_at
+                }
+            );
+
+            Ok(())
+        },
+    )?;
+
+    let refund_msg = refund(&request, denom);
+
+    let mut attrs = action_attrs(
+        "reclaim_redemption",
+        <Vec<Attribute>>::from(&request.data()),
+    );
+    attrs.extend(vec![attr("request_hash", request_hash)]);
+
+    Ok(Response::new()
+        .add_message(refund_msg)
+        .add_attributes(attrs))
+}
+
+/// Return the escrowed tokens to the holder.
+fn refund(request: &RedemptionRequest, denom: String) -> BankMsg {
+    BankMsg::Send {
+        to_address: request.requester.to_string(),
+        amount: vec![Coin::new(request.amount.u128(), denom)],
+    }
+}
+
+/// Set the redemption timeout. Only the governor can set the redemption timeout.
+pub fn set_redemption_timeout(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    seconds: u64,
+) -> Result<Response, ContractError> {
+    allow_permission(
+        Permission::RedemptionSetTimeout,
+        &info.sender,
+        deps.as_ref(),
+        env,
+    )?;
+
+    REDEMPTION_TIMEOUT.save(deps.storage, &seconds)?;
+
+    let attrs = action_attrs(
+        "set_redemption_timeout",
+        vec![attr("seconds", seconds.to_string())],
+    );
+    Ok(Response::new().add_attributes(attrs))
+}
+
+pub fn get_redemption_timeout(deps: Deps) -> StdResult<u64> {
+    Ok(REDEMPTION_TIMEOUT
+        .may_load(deps.storage)?
+        .unwrap_or(DEFAULT_REDEMPTION_TIMEOUT_SECONDS))
+}
+
+fn get_redemption_routing(deps: Deps, request_hash: &str) -> StdResult<RedemptionRouting> {
+    REDEMPTION_ROUTING
+        .may_load(deps.storage, request_hash.to_string())?
+        .ok_or_else(|| StdError::not_found(format!("Request with hash `{request_hash}`")))
+}
+
+fn with_routing(
+    deps: Deps,
+    request_hash: String,
+    request: RedemptionRequest,
+) -> StdResult<RedemptionRequestInfo> {
+    let RedemptionRouting {
+        merchant,
+        burn_request_hash,
+    } = get_redemption_routing(deps, &request_hash)?;
+
+    Ok(RedemptionRequestInfo {
+        request_hash,
+        request,
+        merchant,
+        burn_request_hash,
+    })
+}
+
+pub fn get_redemption_request_by_hash(
+    deps: Deps,
+    request_hash: &str,
+) -> StdResult<RedemptionRequestInfo> {
+    let request = redemption_requests().get_request(deps, request_hash)?;
+    with_routing(deps, request_hash.to_string(), request)
+}
+
+pub fn get_redemption_request_count(deps: Deps) -> StdResult<Uint128> {
+    redemption_requests().get_request_count(deps)
+}
+
+pub fn list_redemption_requests(
+    deps: Deps,
+    limit: Option<u32>,
+    start_after_nonce: Option<Uint128>,
+    status: Option<RedemptionRequestStatus>,
+) -> StdResult<Vec<RedemptionRequestInfo>> {
+    redemption_requests()
+        .list_requests(deps, limit, start_after_nonce, status)?
+        .into_iter()
+        .map(|r| with_routing(deps, r.request_hash, r.request))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Addr, ContractInfo, SubMsg, Uint128,
+    };
+
+    use crate::{
+        attrs::tests::setup_contract,
+        auth::{
+            custodian, member_manager, merchant,
+            operator::{self, OperatorPermission},
+        },
+        state::burn::burn_requests,
+        tokenfactory::{
+            burn::{set_min_burn_amount, BurnRequestStatus},
+            deposit_address,
+        },
+    };
+
+    const GOVERNOR: &str = "osmo1governor";
+    const MEMBER_MANAGER: &str = "osmo1membermanager";
+    const CUSTODIAN: &str = "osmo1custodian";
+    const MERCHANT: &str = "osmo1merchant";
+    const OTHER_MERCHANT: &str = "osmo1othermerchant";
+    const HOLDER: &str = "osmo1holder";
+    const HOLDER_BTC_ADDRESS: &str = "bc1q35rayrk92pvwamwm4n2hsd3epez2g2tqcqa0fx";
+    const CONTRACT: &str = "osmo14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9sq2r9g9";
+
+    fn setup(mut deps: DepsMut) -> String {
+        let denom = setup_contract(deps.branch(), CONTRACT, GOVERNOR, "wbtc").unwrap();
+        member_manager::set_member_manager(
+            deps.branch(),
+            &mock_env(),
+            &mock_info(GOVERNOR, &[]),
+            MEMBER_MANAGER,
+        )
+        .unwrap();
+        custodian::set_custodian(
+            deps.branch(),
+            &mock_env(),
+            &mock_info(MEMBER_MANAGER, &[]),
+            CUSTODIAN,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.branch(),
+            &mock_env(),
+            &mock_info(MEMBER_MANAGER, &[]),
+            MERCHANT,
+            None,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.branch(),
+            &mock_env(),
+            &mock_info(MEMBER_MANAGER, &[]),
+            OTHER_MERCHANT,
+            None,
+        )
+        .unwrap();
+
+        denom
+    }
+
+    fn contract_env() -> Env {
+        Env {
+            contract: ContractInfo {
+                address: Addr::unchecked(CONTRACT),
+            },
+            ..mock_env()
+        }
+    }
+
+    fn request_hash_of(res: &Response) -> String {
+        res.attributes
+            .iter()
+            .find(|attr| attr.key == "request_hash")
+            .unwrap()
+            .value
+            .clone()
+    }
+
+    #[test]
+    fn test_request_redemption() {
+        let mut deps = mock_dependencies();
+        let denom = setup(deps.as_mut());
+        let amount = Uint128::new(100_000_000);
+
+        let request_redemption_fixture = |deps: DepsMut, funds: &[Coin], merchant: &str| {
+            request_redemption(
+                deps,
+                contract_env(),
+                mock_info(HOLDER, funds),
+                merchant.to_string(),
+                HOLDER_BTC_ADDRESS.to_string(),
+            )
+        };
+
+        // funds must be attached
+        assert_eq!(
+            request_redemption_fixture(deps.as_mut(), &[], MERCHANT).unwrap_err(),
+            ContractError::NoFundsSent {}
+        );
+
+        // funds must be the token
+        assert_eq!(
+            request_redemption_fixture(deps.as_mut(), &[Coin::new(100, "uosmo")], MERCHANT)
+                .unwrap_err(),
+            ContractError::InvalidFundsDenom {
+                expected: denom.clone(),
+                got: "uosmo".to_string()
+            }
+        );
+
+        // btc address must be valid
+        assert_eq!(
+            request_redemption(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(HOLDER, &[Coin::new(amount.u128(), &denom)]),
+                MERCHANT.to_string(),
+                "bc1invalid".to_string(),
+            )
+            .unwrap_err(),
+            ContractError::InvalidBtcAddress {
+                address: "bc1invalid".to_string()
+            }
+        );
+
+        // btc address must not be a deposit address
+        let custodian_deposit_address = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+        let merchant_deposit_address = "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy";
+        deposit_address::set_custodian_deposit_address(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(CUSTODIAN, &[]),
+            MERCHANT,
+            Some(custodian_deposit_address),
+        )
+        .unwrap();
+        deposit_address::set_merchant_deposit_address(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(MERCHANT, &[]),
+            Some(merchant_deposit_address),
+        )
+        .unwrap();
+        for (btc_address, tracked_address) in [
+            (
+                custodian_deposit_address.to_string(),
+                custodian_deposit_address,
+            ),
+            // bech32 address in upper case is the same address
+            (
+                custodian_deposit_address.to_uppercase(),
+                custodian_deposit_address,
+            ),
+            (
+                merchant_deposit_address.to_string(),
+                merchant_deposit_address,
+            ),
+        ] {
+            assert_eq!(
+                request_redemption(
+                    deps.as_mut(),
+                    contract_env(),
+                    mock_info(HOLDER, &[Coin::new(amount.u128(), &denom)]),
+                    MERCHANT.to_string(),
+                    btc_address,
+                )
+                .unwrap_err(),
+                ContractError::DepositAddressAlreadyAssociated {
+                    address: tracked_address.to_string()
+                }
+            );
+        }
+
+        // redemption must be routed through a merchant
+        assert_eq!(
+            request_redemption_fixture(
+                deps.as_mut(),
+                &[Coin::new(amount.u128(), &denom)],
+                CUSTODIAN
+            )
+            .unwrap_err(),
+            ContractError::NotAMerchant {
+                address: CUSTODIAN.to_string()
+            }
+        );
+
+        // redemption amount must be at least min burn amount
+        set_min_burn_amount(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(CUSTODIAN, &[]),
+            amount + amount,
+        )
+        .unwrap();
+        assert_eq!(
+            request_redemption_fixture(
+                deps.as_mut(),
+                &[Coin::new(amount.u128(), &denom)],
+                MERCHANT
+            )
+            .unwrap_err(),
+            ContractError::BurnAmountTooSmall {
+                requested_burn_amount: amount,
+                min_burn_amount: amount + amount
+            }
+        );
+        set_min_burn_amount(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(CUSTODIAN, &[]),
+            Uint128::zero(),
+        )
+        .unwrap();
+
+        let res = request_redemption_fixture(
+            deps.as_mut(),
+            &[Coin::new(amount.u128(), &denom)],
+            MERCHANT,
+        )
+        .unwrap();
+
+        // nothing is sent out, the tokens are escrowed
+        assert!(res.messages.is_empty());
+
+        let request_hash = request_hash_of(&res);
+        let info = get_redemption_request_by_hash(deps.as_ref(), &request_hash).unwrap();
+
+        assert_eq!(
+            info,
+            RedemptionRequestInfo {
+                request_hash: request_hash.clone(),
+                request: RedemptionRequest {
+                    requester: Addr::unchecked(HOLDER),
+                    amount,
+                    tx_id: None,
+                    deposit_address: HOLDER_BTC_ADDRESS.to_string(),
+                    timestamp: contract_env().block.time,
+                    nonce: Uint128::zero(),
+                    status: RedemptionRequestStatus::Pending,
+                },
+                merchant: Addr::unchecked(MERCHANT),
+                burn_request_hash: None,
+            }
+        );
+
+        assert_eq!(
+            list_redemption_requests(deps.as_ref(), None, None, None).unwrap(),
+            vec![info]
+        );
+        assert_eq!(
+            get_redemption_request_count(deps.as_ref()).unwrap(),
+            Uint128::one()
+        );
+    }
+
+    #[test]
+    fn test_accept_redemption() {
+        let mut deps = mock_dependencies();
+        let denom = setup(deps.as_mut());
+        let amount = Uint128::new(100_000_000);
+
+        let res = request_redemption(
+            deps.as_mut(),
+            contract_env(),
+            mock_info(HOLDER, &[Coin::new(amount.u128(), &denom)]),
+            MERCHANT.to_string(),
+            HOLDER_BTC_ADDRESS.to_string(),
+        )
+        .unwrap();
+        let request_hash = request_hash_of(&res);
+
+        // only the chosen merchant can accept
+        for sender in [OTHER_MERCHANT, HOLDER, CUSTODIAN] {
+            assert_eq!(
+                accept_redemption(
+                    deps.as_mut(),
+                    contract_env(),
+                    mock_info(sender, &[]),
+                    request_hash.clone()
+                )
+                .unwrap_err(),
+                ContractError::Unauthorized {}
+            );
+        }
+
+        let res = accept_redemption(
+            deps.as_mut(),
+            contract_env(),
+            mock_info(MERCHANT, &[]),
+            request_hash.clone(),
+        )
+        .unwrap();
+
+        // burns the escrowed tokens from the contract itself
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(MsgBurn {
+                sender: CONTRACT.to_string(),
+                amount: Some(Coin::new(amount.u128(), &denom).into()),
+                burn_from_address: "".to_string(),
+            })]
+        );
+
+        // creates burn request for the merchant with holder's BTC address
+        let info = get_redemption_request_by_hash(deps.as_ref(), &request_hash).unwrap();
+        assert_eq!(info.request.status, RedemptionRequestStatus::Accepted);
+
+        let burn_request = burn_requests()
+            .get_request(deps.as_ref(), info.burn_request_hash.as_ref().unwrap())
+            .unwrap();
+        assert_eq!(burn_request.requester, Addr::unchecked(MERCHANT));
+        assert_eq!(burn_request.amount, amount);
+        assert_eq!(burn_request.deposit_address, HOLDER_BTC_ADDRESS);
+        assert_eq!(burn_request.status, BurnRequestStatus::Pending);
+
+        // can not be accepted, declined or reclaimed again
+        assert_eq!(
+            accept_redemption(
+                deps.as_mut(),
+                contract_env(),
+                mock_info(MERCHANT, &[]),
+                request_hash.clone()
+            )
+            .unwrap_err(),
+            ContractError::UpdatableStatusExpected {
+                request_hash: request_hash.clone()
+            }
+        );
+        assert_eq!(
+            decline_redemption(
+                deps.as_mut(),
+                &mock_env(),
+                mock_info(MERCHANT, &[]),
+                request_hash.clone()
+            )
+            .unwrap_err(),
+            ContractError::UpdatableStatusExpected {
+                request_hash: request_hash.clone()
+            }
+        );
+    }
+
+    #[test]
+    fn test_suspended_merchant_cannot_accept_redemption() {
+        let mut deps = mock_dependencies();
+        let denom = setup(deps.as_mut());
+        let amount = Uint128::new(100_000_000);
+        let operator = "osmo1operator";
+
+        operator::set_operator(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(MERCHANT, &[]),
+            operator,
+            vec![OperatorPermission::Burn],
+            None,
+        )
+        .unwrap();
+
+        let res = request_redemption(
+            deps.as_mut(),
+            contract_env(),
+            mock_info(HOLDER, &[Coin::new(amount.u128(), &denom)]),
+            MERCHANT.to_string(),
+            HOLDER_BTC_ADDRESS.to_string(),
+        )
+        .unwrap();
+        let request_hash = request_hash_of(&res);
+
+        let accept = |deps: DepsMut, sender: &str| {
+            accept_redemption(
+                deps,
+                contract_env(),
+                mock_info(sender, &[]),
+                request_hash.clone(),
+            )
+        };
+
+        merchant::suspend_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(MEMBER_MANAGER, &[]),
+            MERCHANT,
+        )
+        .unwrap();
+
+        assert_eq!(
+            accept(deps.as_mut(), MERCHANT).unwrap_err(),
+            ContractError::MerchantSuspended {
+                address: MERCHANT.to_string()
+            }
+        );
+
+        // operators can not accept redemptions on behalf of the merchant
+        assert_eq!(
+            accept(deps.as_mut(), operator).unwrap_err(),
+            ContractError::Unauthorized {}
+        );
+
+        assert_eq!(
+            get_redemption_request_by_hash(deps.as_ref(), &request_hash)
+                .unwrap()
+                .request
+                .status,
+            RedemptionRequestStatus::Pending
+        );
+
+        // reinstated merchant can accept
+        merchant::reinstate_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(MEMBER_MANAGER, &[]),
+            MERCHANT,
+        )
+        .unwrap();
+        accept(deps.as_mut(), MERCHANT).unwrap();
+        assert_eq!(
+            get_redemption_request_by_hash(deps.as_ref(), &request_hash)
+                .unwrap()
+                .request
+                .status,
+            RedemptionRequestStatus::Accepted
+        );
+    }
+
+    #[test]
+    fn test_decline_redemption() {
+        let mut deps = mock_dependencies();
+        let denom = setup(deps.as_mut());
+        let amount = Uint128::new(100_000_000);
+
+        let res = request_redemption(
+            deps.as_mut(),
+            contract_env(),
+            mock_info(HOLDER, &[Coin::new(amount.u128(), &denom)]),
+            MERCHANT.to_string(),
+            HOLDER_BTC_ADDRESS.to_string(),
+        )
+        .unwrap();
+        let request_hash = request_hash_of(&res);
+
+        // only the chosen merchant can decline
+        assert_eq!(
+            decline_redemption(
+                deps.as_mut(),
+                &mock_env(),
+                mock_info(OTHER_MERCHANT, &[]),
+                request_hash.clone()
+            )
+            .unwrap_err(),
+            ContractError::Unauthorized {}
+        );
+
+        let res = decline_redemption(
+            deps.as_mut(),
+            &mock_env(),
+            mock_info(MERCHANT, &[]),
+            request_hash.clone(),
+        )
+        .unwrap();
+
+        // returns the escrowed tokens to the holder
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: HOLDER.to_string(),
+                amount: vec![Coin::new(amount.u128(), &denom)],
+            })]
+        );
+
+        let info = get_redemption_request_by_hash(deps.as_ref(), &request_hash).unwrap();
+        assert_eq!(info.request.status, RedemptionRequestStatus::Declined);
+        assert_eq!(info.burn_request_hash, None);
+        assert_eq!(
+            burn_requests().get_request_count(deps.as_ref()).unwrap(),
+            Uint128::zero()
+        );
+    }
+
+    #[test]
+    fn test_reclaim_redemption() {
+        let mut deps = mock_dependencies();
+        let denom = setup(deps.as_mut());
+        let amount = Uint128::new(100_000_000);
+        let timeout = 60 * 60;
+
+        // only governor can set redemption timeout
+        assert_eq!(
+            get_redemption_timeout(deps.as_ref()).unwrap(),
+            DEFAULT_REDEMPTION_TIMEOUT_SECONDS
+        );
+        assert_eq!(
+            set_redemption_timeout(
+                deps.as_mut(),
+                &mock_env(),
+                &mock_info(CUSTODIAN, &[]),
+                timeout
+            )
+            .unwrap_err(),
+            ContractError::Unauthorized {}
+        );
+        set_redemption_timeout(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(GOVERNOR, &[]),
+            timeout,
+        )
+        .unwrap();
+        assert_eq!(get_redemption_timeout(deps.as_ref()).unwrap(), timeout);
+
+        let res = request_redemption(
+            deps.as_mut(),
+            contract_env(),
+            mock_info(HOLDER, &[Coin::new(amount.u128(), &denom)]),
+            MERCHANT.to_string(),
+            HOLDER_BTC_ADDRESS.to_string(),
+        )
+        .unwrap();
+        let request_hash = request_hash_of(&res);
+
+        let reclaimable_at = contract_env().block.time.plus_seconds(timeout);
+        let mut env = contract_env();
+
+        // can not be reclaimed before timeout
+        env.block.time = reclaimable_at.minus_seconds(1);
+        assert_eq!(
+            reclaim_redemption(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(HOLDER, &[]),
+                request_hash.clone()
+            )
+            .unwrap_err(),
+            ContractError::RedemptionNotReclaimable {
+                request_hash: request_hash.clone(),
+                reclaimable_at
+            }
+        );
+
+        // only the holder can reclaim
+        env.block.time = reclaimable_at;
+        assert_eq!(
+            reclaim_redemption(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(MERCHANT, &[]),
+                request_hash.clone()
+            )
+            .unwrap_err(),
+            ContractError::Unauthorized {}
+        );
+
+        let res = reclaim_redemption(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(HOLDER, &[]),
+            request_hash.clone(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: HOLDER.to_string(),
+                amount: vec![Coin::new(amount.u128(), &denom)],
+            })]
+        );
+
+        let info = get_redemption_request_by_hash(deps.as_ref(), &request_hash).unwrap();
+        assert_eq!(info.request.status, RedemptionRequestStatus::Reclaimed);
+
+        // merchant can no longer accept
+        assert_eq!(
+            accept_redemption(
+                deps.as_mut(),
+                env,
+                mock_info(MERCHANT, &[]),
+                request_hash.clone()
+            )
+            .unwrap_err(),
+            ContractError::UpdatableStatusExpected { request_hash }
+        );
+    }
+}
@@ -0,0 +1,177 @@
+/// `rolling_window` module provides a way to track accumulated amounts over a rolling time window.
+use cosmwasm_std::{Order, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Bound, Map};
+
+/// `RollingWindow` accumulates amounts per key over the last `window_seconds`.
+///
+/// Amounts are recorded into buckets of `bucket_seconds` to keep storage bounded.
+/// The bucket that contains the start of the window is counted in full,
+/// so the total is never less than the actual amount within the window,
+/// but may include amounts up to `bucket_seconds` older than the window.
+pub struct RollingWindow<'a> {
+    /// accumulated amount per (key, bucket).
+    buckets: Map<'a, (String, u64), Uint128>,
+
+    /// length of the window in seconds.
+    window_seconds: u64,
+
+    /// length of each bucket in seconds.
+    bucket_seconds: u64,
+}
+
+impl<'a> RollingWindow<'a> {
+    pub const fn new(namespace: &'a str, window_seconds: u64, bucket_seconds: u64) -> Self {
+        RollingWindow {
+            buckets: Map::new(namespace),
+            window_seconds,
+            bucket_seconds,
+        }
+    }
+
+    /// Get the total amount recorded for `key` within the window ending at `now`.
+    pub fn total(&self, storage: &dyn Storage, key: &str, now: Timestamp) -> StdResult<Uint128> {
+        self.buckets
+            .prefix(key.to_string())
+            .range(
+                storage,
+                Some(Bound::inclusive(self.first_bucket(now))),
+                None,
+                Order::Ascending,
+            )
+            .try_fold(Uint128::zero(), |total, item| {
+                let (_, amount) = item?;
+                Ok(total.checked_add(amount)?)
+            })
+    }
+
+    /// Record `amount` for `key` at `now` and return the new total within the window.
+    /// Buckets that have fallen out of the window are pruned.
+    pub fn record(
+        &self,
+        storage: &mut dyn Storage,
+        key: &str,
+        now: Timestamp,
+        amount: Uint128,
+    ) -> StdResult<Uint128> {
+        self.prune(storage, key, now)?;
+
+        self.buckets.update(
+            storage,
+            (key.to_string(), self.bucket(now)),
+            |recorded| -> StdResult<_> { Ok(recorded.unwrap_or_default().checked_add(amount)?) },
+        )?;
+
+        self.total(storage, key, now)
+    }
+
+    /// Remove all buckets for `key` that have fallen out of the window ending at `now`.
+    fn prune(&self, storage: &mut dyn Storage, key: &str, now: Timestamp) -> StdResult<()> {
+        let expired_buckets = self
+            .buckets
+            .prefix(key.to_string())
+            .keys(
+                storage,
+                None,
+                Some(Bound::exclusive(self.first_bucket(now))),
+                Order::Ascending,
+            )
+            .collect::<StdResult<Vec<_>>>()?;
+
+        for bucket in expired_buckets {
+            self.buckets.remove(storage, (key.to_string(), bucket));
+        }
+
+        Ok(())
+    }
+
+    fn bucket(&self, time: Timestamp) -> u64 {
+        time.seconds() / self.bucket_seconds
+    }
+
+    fn first_bucket(&self, now: Timestamp) -> u64 {
+        self.bucket(now.minus_seconds(self.window_seconds))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_dependencies;
+
+    use super::*;
+
+    const WINDOW: RollingWindow = RollingWindow::new("window", 24 * 60 * 60, 60 * 60);
+
+    #[test]
+    fn test_rolling_window() {
+        let mut deps = mock_dependencies();
+        let start = Timestamp::from_seconds(1689069540);
+
+        // nothing recorded
+        assert_eq!(
+            WINDOW.total(&deps.storage, "osmo1a", start).unwrap(),
+            Uint128::zero()
+        );
+
+        // record accumulates per key
+        assert_eq!(
+            WINDOW
+                .record(&mut deps.storage, "osmo1a", start, Uint128::new(100))
+                .unwrap(),
+            Uint128::new(100)
+        );
+        assert_eq!(
+            WINDOW
+                .record(
+                    &mut deps.storage,
+                    "osmo1a",
+                    start.plus_seconds(12 * 60 * 60),
+                    Uint128::new(50)
+                )
+                .unwrap(),
+            Uint128::new(150)
+        );
+        assert_eq!(
+            WINDOW
+                .record(&mut deps.storage, "osmo1b", start, Uint128::new(10))
+                .unwrap(),
+            Uint128::new(10)
+        );
+
+        // first record is still within the window
+        assert_eq!(
+            WINDOW
+                .total(&deps.storage, "osmo1a", start.plus_seconds(24 * 60 * 60))
+                .unwrap(),
+            Uint128::new(150)
+        );
+
+        // first record falls out of the window after its bucket has passed
+        let later = start.plus_seconds(25 * 60 * 60);
+        assert_eq!(
+            WINDOW.total(&deps.storage, "osmo1a", later).unwrap(),
+            Uint128::new(50)
+        );
+
+        // expired buckets are pruned on record
+        assert_eq!(
+            WINDOW
+                .record(&mut deps.storage, "osmo1a", later, Uint128::new(1))
+                .unwrap(),
+            Uint128::new(51)
+        );
+        assert_eq!(
+            WINDOW
+                .buckets
+                .prefix("osmo1a".to_string())
+                .keys(&deps.storage, None, None, Order::Ascending)
+                .count(),
+            2
+        );
+
+        // other keys are not affected
+        assert_eq!(
+            WINDOW.total(&deps.storage, "osmo1b", start).unwrap(),
+            Uint128::new(10)
+        );
+    }
+}
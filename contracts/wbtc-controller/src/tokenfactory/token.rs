@@ -1,15 +1,16 @@
 /// `token` module provides the functionality to manage the token denom and it's metadata.
 use cosmwasm_std::{
-    attr, Attribute, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage,
+    attr, Attribute, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage,
 };
 use osmosis_std::types::{
-    cosmos::bank::v1beta1::Metadata, osmosis::tokenfactory::v1beta1::MsgSetDenomMetadata,
+    cosmos::bank::v1beta1::Metadata,
+    osmosis::tokenfactory::v1beta1::{MsgSetBeforeSendHook, MsgSetDenomMetadata},
 };
 
 use crate::{
-    attrs::action_attrs,
-    auth::{allow_only, Role},
-    state::token::{IS_PAUSED, TOKEN_DENOM},
+    attrs::{action_attrs, sudo_event},
+    auth::permission::{allow_permission, Permission},
+    state::token::{INITIAL_DENOM_METADATA, IS_PAUSED, TOKEN_DENOM},
     ContractError,
 };
 
@@ -24,6 +25,58 @@ pub fn get_token_denom(storage: &dyn Storage) -> StdResult<String> {
     TOKEN_DENOM.load(storage)
 }
 
+/// Get the token denom if it has been registered.
+pub fn may_get_token_denom(storage: &dyn Storage) -> StdResult<Option<String>> {
+    TOKEN_DENOM.may_load(storage)
+}
+
+/// Keep the denom metadata provided at instantiation until the contract is the denom admin.
+pub fn initialize_denom_metadata(storage: &mut dyn Storage, metadata: &Metadata) -> StdResult<()> {
+    INITIAL_DENOM_METADATA.save(storage, metadata)
+}
+
+/// Register the token denom once the contract is its admin
+/// and return the messages setting the before-send hook to this contract
+/// and the denom metadata provided at instantiation, if any.
+/// The hook triggers the sudo endpoint before any bank send,
+/// which makes token transfer pause and holder tracking possible.
+pub fn register_token_denom(
+    storage: &mut dyn Storage,
+    env: &Env,
+    token_denom: &String,
+) -> StdResult<Vec<CosmosMsg>> {
+    set_token_denom(storage, token_denom)?;
+
+    let mut msgs: Vec<CosmosMsg> = vec![MsgSetBeforeSendHook {
+        sender: env.contract.address.to_string(),
+        denom: token_denom.clone(),
+        cosmwasm_address: env.contract.address.to_string(),
+    }
+    .into()];
+
+    if let Some(mut metadata) = INITIAL_DENOM_METADATA.may_load(storage)? {
+        INITIAL_DENOM_METADATA.remove(storage);
+
+        // the denom is only known once created, so it is filled in here
+        metadata.base = token_denom.clone();
+        for denom_unit in metadata.denom_units.iter_mut() {
+            if denom_unit.exponent == 0 {
+                denom_unit.denom = token_denom.clone();
+            }
+        }
+
+        msgs.push(
+            MsgSetDenomMetadata {
+                sender: env.contract.address.to_string(),
+                metadata: Some(metadata),
+            }
+            .into(),
+        );
+    }
+
+    Ok(msgs)
+}
+
 /// Set denom metadata.
 /// Only the governor can set the denom metadata.
 pub fn set_denom_metadata(
@@ -32,7 +85,7 @@ pub fn set_denom_metadata(
     info: &MessageInfo,
     metadata: Metadata,
 ) -> Result<Response, ContractError> {
-    allow_only(&[Role::Governor], &info.sender, deps)?;
+    allow_permission(Permission::TokenSetMetadata, &info.sender, deps, env)?;
 
     let attrs = action_attrs(
         "set_denom_metadata",
@@ -55,8 +108,8 @@ pub fn set_denom_metadata(
 }
 
 /// Set the pause status.
-pub fn pause(deps: DepsMut, info: &MessageInfo) -> Result<Response, ContractError> {
-    allow_only(&[Role::Governor], &info.sender, deps.as_ref())?;
+pub fn pause(deps: DepsMut, env: &Env, info: &MessageInfo) -> Result<Response, ContractError> {
+    allow_permission(Permission::TokenPause, &info.sender, deps.as_ref(), env)?;
 
     IS_PAUSED.save(deps.storage, &true)?;
 
@@ -66,8 +119,8 @@ pub fn pause(deps: DepsMut, info: &MessageInfo) -> Result<Response, ContractErro
 }
 
 /// Unset the pause status.
-pub fn unpause(deps: DepsMut, info: &MessageInfo) -> Result<Response, ContractError> {
-    allow_only(&[Role::Governor], &info.sender, deps.as_ref())?;
+pub fn unpause(deps: DepsMut, env: &Env, info: &MessageInfo) -> Result<Response, ContractError> {
+    allow_permission(Permission::TokenPause, &info.sender, deps.as_ref(), env)?;
 
     IS_PAUSED.save(deps.storage, &false)?;
 
@@ -76,6 +129,16 @@ pub fn unpause(deps: DepsMut, info: &MessageInfo) -> Result<Response, ContractEr
     Ok(Response::new().add_attributes(attrs))
 }
 
+/// Set the pause status without the governor.
+/// Only reachable through sudo, the governor can unpause once the incident is resolved.
+pub fn force_pause(deps: DepsMut) -> Result<Response, ContractError> {
+    IS_PAUSED.save(deps.storage, &true)?;
+
+    let event = sudo_event("force_pause", vec![] as Vec<Attribute>);
+
+    Ok(Response::new().add_event(event))
+}
+
 /// Check if the contract is paused.
 pub fn is_paused(deps: Deps) -> StdResult<bool> {
     Ok(IS_PAUSED.may_load(deps.storage)?.unwrap_or(false))
@@ -104,16 +167,35 @@ mod tests {
         let mut deps = mock_dependencies();
 
         // setup
-        governor::initialize_governor(deps.as_mut(), governor).unwrap();
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
         member_manager::set_member_manager(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(governor, &[]),
             member_manager,
         )
         .unwrap();
-        custodian::set_custodian(deps.as_mut(), &mock_info(member_manager, &[]), custodian)
-            .unwrap();
-        merchant::add_merchant(deps.as_mut(), &mock_info(member_manager, &[]), merchant).unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant,
+            None,
+        )
+        .unwrap();
 
         let metadata = Metadata {
             description: "description".to_string(),
@@ -173,32 +255,51 @@ mod tests {
         let mut deps = mock_dependencies();
 
         // setup
-        governor::initialize_governor(deps.as_mut(), governor).unwrap();
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
         member_manager::set_member_manager(
             deps.as_mut(),
+            &mock_env(),
             &mock_info(governor, &[]),
             member_manager,
         )
         .unwrap();
-        custodian::set_custodian(deps.as_mut(), &mock_info(member_manager, &[]), custodian)
-            .unwrap();
-        merchant::add_merchant(deps.as_mut(), &mock_info(member_manager, &[]), merchant).unwrap();
+        custodian::set_custodian(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            custodian,
+        )
+        .unwrap();
+        merchant::add_merchant(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(member_manager, &[]),
+            merchant,
+            None,
+        )
+        .unwrap();
 
         // default status is not paused
         assert!(!is_paused(deps.as_ref()).unwrap());
 
         assert_eq!(
-            pause(deps.as_mut(), &mock_info(custodian, &[])).unwrap_err(),
+            pause(deps.as_mut(), &mock_env(), &mock_info(custodian, &[])).unwrap_err(),
             ContractError::Unauthorized {}
         );
 
         assert_eq!(
-            pause(deps.as_mut(), &mock_info(merchant, &[])).unwrap_err(),
+            pause(deps.as_mut(), &mock_env(), &mock_info(merchant, &[])).unwrap_err(),
             ContractError::Unauthorized {}
         );
 
         assert_eq!(
-            pause(deps.as_mut(), &mock_info(governor, &[])).unwrap(),
+            pause(deps.as_mut(), &mock_env(), &mock_info(governor, &[])).unwrap(),
             Response::new().add_attributes(vec![attr("action", "pause")])
         );
 
@@ -206,17 +307,17 @@ mod tests {
         assert!(is_paused(deps.as_ref()).unwrap());
 
         assert_eq!(
-            unpause(deps.as_mut(), &mock_info(custodian, &[])).unwrap_err(),
+            unpause(deps.as_mut(), &mock_env(), &mock_info(custodian, &[])).unwrap_err(),
             ContractError::Unauthorized {}
         );
 
         assert_eq!(
-            unpause(deps.as_mut(), &mock_info(merchant, &[])).unwrap_err(),
+            unpause(deps.as_mut(), &mock_env(), &mock_info(merchant, &[])).unwrap_err(),
             ContractError::Unauthorized {}
         );
 
         assert_eq!(
-            unpause(deps.as_mut(), &mock_info(governor, &[])).unwrap(),
+            unpause(deps.as_mut(), &mock_env(), &mock_info(governor, &[])).unwrap(),
             Response::new().add_attributes(vec![attr("action", "unpause")])
         );
 
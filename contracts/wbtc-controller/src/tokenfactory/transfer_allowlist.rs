@@ -0,0 +1,311 @@
+/// `transfer_allowlist` module provides an opt-in mode restricting token transfers to allowlisted holders.
+use cosmwasm_std::{
+    attr, ensure, Addr, Api, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult,
+};
+use cw_storage_plus::Bound;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    attrs::action_attrs,
+    auth::permission::{allow_permission, Permission},
+    constants::{DEFAULT_LIMIT, MAX_LIMIT},
+    state::transfer_allowlist::{TRANSFER_ALLOWLIST, TRANSFER_ALLOWLIST_ENABLED},
+    ContractError,
+};
+
+/// Names of the module accounts that can hold or move the token,
+/// transfers from and to these accounts are always permitted.
+const PERMITTED_MODULE_ACCOUNTS: [&str; 10] = [
+    "tokenfactory",
+    "distribution",
+    "fee_collector",
+    "gamm",
+    "poolmanager",
+    "concentratedliquidity",
+    "cosmwasmpool",
+    "lockup",
+    "superfluid",
+    "txfees",
+];
+
+/// Enable or disable the transfer allowlist mode. Only the governor can switch the mode.
+pub fn set_transfer_allowlist_enabled(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    enabled: bool,
+) -> Result<Response, ContractError> {
+    allow_permission(
+        Permission::TransferAllowlistConfigure,
+        &info.sender,
+        deps.as_ref(),
+        env,
+    )?;
+
+    TRANSFER_ALLOWLIST_ENABLED.save(deps.storage, &enabled)?;
+
+    let attrs = action_attrs(
+        "set_transfer_allowlist_enabled",
+        vec![attr("enabled", enabled.to_string())],
+    );
+    Ok(Response::new().add_attributes(attrs))
+}
+
+pub fn is_transfer_allowlist_enabled(deps: Deps) -> StdResult<bool> {
+    Ok(TRANSFER_ALLOWLIST_ENABLED
+        .may_load(deps.storage)?
+        .unwrap_or(false))
+}
+
+/// Add addresses to the transfer allowlist. Only the compliance officer can add addresses.
+pub fn add_to_transfer_allowlist(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    addresses: Vec<String>,
+) -> Result<Response, ContractError> {
+    allow_permission(
+        Permission::TransferAllowlistManage,
+        &info.sender,
+        deps.as_ref(),
+        env,
+    )?;
+
+    let addresses = validate_addresses(deps.api, addresses)?;
+    for address in &addresses {
+        TRANSFER_ALLOWLIST.save(deps.storage, address.clone(), &())?;
+    }
+
+    let attrs = action_attrs(
+        "add_to_transfer_allowlist",
+        addresses
+            .into_iter()
+            .map(|address| attr("address", address)),
+    );
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Remove addresses from the transfer allowlist. Only the compliance officer can remove addresses.
+pub fn remove_from_transfer_allowlist(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    addresses: Vec<String>,
+) -> Result<Response, ContractError> {
+    allow_permission(
+        Permission::TransferAllowlistManage,
+        &info.sender,
+        deps.as_ref(),
+        env,
+    )?;
+
+    let addresses = validate_addresses(deps.api, addresses)?;
+    for address in &addresses {
+        TRANSFER_ALLOWLIST.remove(deps.storage, address.clone());
+    }
+
+    let attrs = action_attrs(
+        "remove_from_transfer_allowlist",
+        addresses
+            .into_iter()
+            .map(|address| attr("address", address)),
+    );
+    Ok(Response::new().add_attributes(attrs))
+}
+
+pub fn is_transfer_allowlisted(deps: Deps, address: &Addr) -> StdResult<bool> {
+    Ok(TRANSFER_ALLOWLIST
+        .may_load(deps.storage, address.clone())?
+        .is_some())
+}
+
+pub fn list_transfer_allowlist(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Addr>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    TRANSFER_ALLOWLIST
+        .keys(
+            deps.storage,
+            start_after.map(|address| Bound::exclusive(Addr::unchecked(address))),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect()
+}
+
+/// Ensure that both parties of a token transfer are allowlisted if the transfer allowlist mode is enabled.
+/// The contract itself and module accounts are always permitted.
+pub fn ensure_transfer_allowed(
+    deps: Deps,
+    env: &Env,
+    from: &str,
+    to: &str,
+) -> Result<(), ContractError> {
+    if !is_transfer_allowlist_enabled(deps)? {
+        return Ok(());
+    }
+
+    for address in [from, to] {
+        let is_permitted = address == env.contract.address.as_str()
+            || is_module_account(deps.api, address)
+            || is_transfer_allowlisted(deps, &Addr::unchecked(address))?;
+
+        ensure!(
+            is_permitted,
+            ContractError::NotTransferAllowlisted {
+                address: address.to_string()
+            }
+        );
+    }
+
+    Ok(())
+}
+
+/// Check if the address is one of the permitted module accounts.
+/// Module account addresses are the first 20 bytes of the sha256 hash of the module name.
+pub(crate) fn is_module_account(api: &dyn Api, address: &str) -> bool {
+    let canonical = match api.addr_canonicalize(address) {
+        Ok(canonical) => canonical,
+        Err(_) => return false,
+    };
+
+    PERMITTED_MODULE_ACCOUNTS
+        .iter()
+        .any(|name| Sha256::digest(name.as_bytes())[..20] == *canonical.as_slice())
+}
+
+fn validate_addresses(api: &dyn Api, addresses: Vec<String>) -> StdResult<Vec<Addr>> {
+    addresses
+        .iter()
+        .map(|address| api.addr_validate(address))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    use crate::auth::{compliance, governor};
+
+    use super::*;
+
+    #[test]
+    fn test_transfer_allowlist() {
+        let governor = "osmo1governor";
+        let compliance_officer = "osmo1complianceofficer";
+        let alice = "osmo1alice";
+        let bob = "osmo1bob";
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        // setup
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
+        compliance::set_compliance_officer(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            compliance_officer,
+        )
+        .unwrap();
+
+        // transfers are not restricted by default
+        assert!(!is_transfer_allowlist_enabled(deps.as_ref()).unwrap());
+        ensure_transfer_allowed(deps.as_ref(), &env, alice, bob).unwrap();
+
+        // only governor can switch the mode
+        let err = set_transfer_allowlist_enabled(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(compliance_officer, &[]),
+            true,
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        set_transfer_allowlist_enabled(deps.as_mut(), &mock_env(), &mock_info(governor, &[]), true)
+            .unwrap();
+        assert!(is_transfer_allowlist_enabled(deps.as_ref()).unwrap());
+
+        assert_eq!(
+            ensure_transfer_allowed(deps.as_ref(), &env, alice, bob).unwrap_err(),
+            ContractError::NotTransferAllowlisted {
+                address: alice.to_string()
+            }
+        );
+
+        // only compliance officer can manage the allowlist
+        let err = add_to_transfer_allowlist(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            vec![alice.to_string()],
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        let res = add_to_transfer_allowlist(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(compliance_officer, &[]),
+            vec![alice.to_string(), bob.to_string()],
+        )
+        .unwrap();
+        assert_eq!(
+            res.attributes,
+            vec![
+                attr("action", "add_to_transfer_allowlist"),
+                attr("address", alice),
+                attr("address", bob),
+            ]
+        );
+
+        ensure_transfer_allowed(deps.as_ref(), &env, alice, bob).unwrap();
+        assert_eq!(
+            list_transfer_allowlist(deps.as_ref(), None, None).unwrap(),
+            vec![Addr::unchecked(alice), Addr::unchecked(bob)]
+        );
+        assert_eq!(
+            list_transfer_allowlist(deps.as_ref(), Some(alice.to_string()), None).unwrap(),
+            vec![Addr::unchecked(bob)]
+        );
+
+        // the contract itself is always permitted
+        ensure_transfer_allowed(deps.as_ref(), &env, env.contract.address.as_str(), alice).unwrap();
+
+        // both parties must be allowlisted
+        remove_from_transfer_allowlist(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(compliance_officer, &[]),
+            vec![bob.to_string()],
+        )
+        .unwrap();
+        assert!(!is_transfer_allowlisted(deps.as_ref(), &Addr::unchecked(bob)).unwrap());
+        assert_eq!(
+            ensure_transfer_allowed(deps.as_ref(), &env, alice, bob).unwrap_err(),
+            ContractError::NotTransferAllowlisted {
+                address: bob.to_string()
+            }
+        );
+
+        // disabling the mode lifts the restriction
+        set_transfer_allowlist_enabled(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            false,
+        )
+        .unwrap();
+        ensure_transfer_allowed(deps.as_ref(), &env, alice, bob).unwrap();
+    }
+}
@@ -0,0 +1,367 @@
+/// `transfer_limit` module provides per-address and global limits on the token amount
+/// that can be sent within a rolling window, acting as a circuit breaker against draining.
+use cosmwasm_std::{
+    attr, ensure, Addr, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, Timestamp,
+    Uint128,
+};
+use cw_storage_plus::Bound;
+
+use crate::{
+    attrs::action_attrs,
+    auth::permission::{allow_permission, Permission},
+    constants::{DEFAULT_LIMIT, MAX_LIMIT},
+    state::transfer_limit::{
+        ADDRESS_TRANSFER_LIMIT, GLOBAL_TRANSFER_LIMIT, SENT_GLOBALLY, SENT_PER_ADDRESS,
+        TRANSFER_LIMIT_EXEMPTIONS,
+    },
+    ContractError,
+};
+
+use super::transfer_allowlist::is_module_account;
+
+/// Key of the global outflow in its rolling window.
+const GLOBAL_KEY: &str = "global";
+
+/// Set the maximum amount a single address can send within the transfer limit window.
+/// Only the governor can set the address transfer limit.
+/// Setting it to `None` removes the limit.
+pub fn set_address_transfer_limit(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    amount: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    allow_permission(
+        Permission::TransferLimitConfigure,
+        &info.sender,
+        deps.as_ref(),
+        env,
+    )?;
+
+    let attrs = match amount {
+        Some(amount) => {
+            ADDRESS_TRANSFER_LIMIT.save(deps.storage, &amount)?;
+            vec![attr("amount", amount)]
+        }
+        None => {
+            ADDRESS_TRANSFER_LIMIT.remove(deps.storage);
+            vec![]
+        }
+    };
+
+    Ok(Response::new().add_attributes(action_attrs("set_address_transfer_limit", attrs)))
+}
+
+pub fn get_address_transfer_limit(deps: Deps) -> StdResult<Option<Uint128>> {
+    ADDRESS_TRANSFER_LIMIT.may_load(deps.storage)
+}
+
+/// Set the maximum total amount that can be sent by all non-exempt addresses within the transfer limit window.
+/// Only the governor can set the global transfer limit.
+/// Setting it to `None` removes the limit.
+pub fn set_global_transfer_limit(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    amount: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    allow_permission(
+        Permission::TransferLimitConfigure,
+        &info.sender,
+        deps.as_ref(),
+        env,
+    )?;
+
+    let attrs = match amount {
+        Some(amount) => {
+            GLOBAL_TRANSFER_LIMIT.save(deps.storage, &amount)?;
+            vec![attr("amount", amount)]
+        }
+        None => {
+            GLOBAL_TRANSFER_LIMIT.remove(deps.storage);
+            vec![]
+        }
+    };
+
+    Ok(Response::new().add_attributes(action_attrs("set_global_transfer_limit", attrs)))
+}
+
+pub fn get_global_transfer_limit(deps: Deps) -> StdResult<Option<Uint128>> {
+    GLOBAL_TRANSFER_LIMIT.may_load(deps.storage)
+}
+
+/// Exempt the address from transfer limits. Only the governor can add exemptions.
+pub fn add_transfer_limit_exemption(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    address: &str,
+) -> Result<Response, ContractError> {
+    allow_permission(
+        Permission::TransferLimitConfigure,
+        &info.sender,
+        deps.as_ref(),
+        env,
+    )?;
+
+    let address = deps.api.addr_validate(address)?;
+    TRANSFER_LIMIT_EXEMPTIONS.save(deps.storage, address.clone(), &())?;
+
+    let attrs = action_attrs(
+        "add_transfer_limit_exemption",
+        vec![attr("address", address)],
+    );
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Remove the transfer limit exemption of the address. Only the governor can remove exemptions.
+pub fn remove_transfer_limit_exemption(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    address: &str,
+) -> Result<Response, ContractError> {
+    allow_permission(
+        Permission::TransferLimitConfigure,
+        &info.sender,
+        deps.as_ref(),
+        env,
+    )?;
+
+    let address = deps.api.addr_validate(address)?;
+    TRANSFER_LIMIT_EXEMPTIONS.remove(deps.storage, address.clone());
+
+    let attrs = action_attrs(
+        "remove_transfer_limit_exemption",
+        vec![attr("address", address)],
+    );
+    Ok(Response::new().add_attributes(attrs))
+}
+
+pub fn is_transfer_limit_exempt(deps: Deps, address: &Addr) -> StdResult<bool> {
+    Ok(TRANSFER_LIMIT_EXEMPTIONS
+        .may_load(deps.storage, address.clone())?
+        .is_some())
+}
+
+pub fn list_transfer_limit_exemptions(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Addr>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    TRANSFER_LIMIT_EXEMPTIONS
+        .keys(
+            deps.storage,
+            start_after.map(|address| Bound::exclusive(Addr::unchecked(address))),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect()
+}
+
+/// Get the amount sent by the address within the transfer limit window ending at `now`.
+pub fn get_sent_amount(deps: Deps, address: &Addr, now: Timestamp) -> StdResult<Uint128> {
+    SENT_PER_ADDRESS.total(deps.storage, address.as_str(), now)
+}
+
+/// Get the amount sent by all non-exempt addresses within the transfer limit window ending at `now`.
+pub fn get_globally_sent_amount(deps: Deps, now: Timestamp) -> StdResult<Uint128> {
+    SENT_GLOBALLY.total(deps.storage, GLOBAL_KEY, now)
+}
+
+/// Ensure the transfer stays within the address and global transfer limits, if set,
+/// and record it as sent. Transfers from the contract itself, module accounts
+/// and exempt addresses are not limited nor recorded.
+pub fn record_transfer(
+    deps: DepsMut,
+    env: &Env,
+    from: &str,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let sender = Addr::unchecked(from);
+    if sender == env.contract.address
+        || is_module_account(deps.api, from)
+        || is_transfer_limit_exempt(deps.as_ref(), &sender)?
+    {
+        return Ok(());
+    }
+
+    let now = env.block.time;
+
+    if let Some(limit) = get_address_transfer_limit(deps.as_ref())? {
+        let remaining_amount = limit.saturating_sub(get_sent_amount(deps.as_ref(), &sender, now)?);
+
+        ensure!(
+            amount <= remaining_amount,
+            ContractError::AddressTransferLimitExceeded {
+                address: sender.to_string(),
+                requested_amount: amount,
+                remaining_amount
+            }
+        );
+    }
+
+    if let Some(limit) = get_global_transfer_limit(deps.as_ref())? {
+        let remaining_amount = limit.saturating_sub(get_globally_sent_amount(deps.as_ref(), now)?);
+
+        ensure!(
+            amount <= remaining_amount,
+            ContractError::GlobalTransferLimitExceeded {
+                requested_amount: amount,
+                remaining_amount
+            }
+        );
+    }
+
+    SENT_PER_ADDRESS.record(deps.storage, sender.as_str(), now, amount)?;
+    SENT_GLOBALLY.record(deps.storage, GLOBAL_KEY, now, amount)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    use crate::{
+        auth::governor,
+        constants::{TRANSFER_LIMIT_BUCKET_SECONDS, TRANSFER_LIMIT_WINDOW_SECONDS},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_transfer_limits() {
+        let governor = "osmo1governor";
+        let alice = "osmo1alice";
+        let bob = "osmo1bob";
+        let pool = "osmo1pool";
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+
+        // setup
+        governor::initialize_governor(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info("creator", &[]),
+            governor,
+        )
+        .unwrap();
+
+        // no limit by default
+        record_transfer(deps.as_mut(), &env, alice, Uint128::new(1_000_000)).unwrap();
+
+        // only governor can set limits and exemptions
+        let err = set_address_transfer_limit(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(alice, &[]),
+            Some(Uint128::new(1_000)),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        let err = set_global_transfer_limit(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(alice, &[]),
+            Some(Uint128::new(1_000)),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        let err =
+            add_transfer_limit_exemption(deps.as_mut(), &mock_env(), &mock_info(alice, &[]), pool)
+                .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        let res = set_address_transfer_limit(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            Some(Uint128::new(1_000)),
+        )
+        .unwrap();
+        assert_eq!(
+            res.attributes,
+            vec![
+                attr("action", "set_address_transfer_limit"),
+                attr("amount", "1000")
+            ]
+        );
+
+        set_global_transfer_limit(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            Some(Uint128::new(1_500)),
+        )
+        .unwrap();
+
+        // move to a fresh window
+        env.block.time = env
+            .block
+            .time
+            .plus_seconds(TRANSFER_LIMIT_WINDOW_SECONDS + TRANSFER_LIMIT_BUCKET_SECONDS);
+
+        // address limit
+        record_transfer(deps.as_mut(), &env, alice, Uint128::new(800)).unwrap();
+        assert_eq!(
+            record_transfer(deps.as_mut(), &env, alice, Uint128::new(201)).unwrap_err(),
+            ContractError::AddressTransferLimitExceeded {
+                address: alice.to_string(),
+                requested_amount: Uint128::new(201),
+                remaining_amount: Uint128::new(200)
+            }
+        );
+
+        // global limit
+        assert_eq!(
+            record_transfer(deps.as_mut(), &env, bob, Uint128::new(701)).unwrap_err(),
+            ContractError::GlobalTransferLimitExceeded {
+                requested_amount: Uint128::new(701),
+                remaining_amount: Uint128::new(700)
+            }
+        );
+        record_transfer(deps.as_mut(), &env, bob, Uint128::new(700)).unwrap();
+        assert_eq!(
+            get_globally_sent_amount(deps.as_ref(), env.block.time).unwrap(),
+            Uint128::new(1_500)
+        );
+
+        // exempt addresses, the contract and module accounts are not limited nor recorded
+        add_transfer_limit_exemption(deps.as_mut(), &mock_env(), &mock_info(governor, &[]), pool)
+            .unwrap();
+        assert_eq!(
+            list_transfer_limit_exemptions(deps.as_ref(), None, None).unwrap(),
+            vec![Addr::unchecked(pool)]
+        );
+
+        record_transfer(deps.as_mut(), &env, pool, Uint128::new(10_000)).unwrap();
+        let contract_address = env.contract.address.to_string();
+        record_transfer(deps.as_mut(), &env, &contract_address, Uint128::new(10_000)).unwrap();
+        assert_eq!(
+            get_sent_amount(deps.as_ref(), &Addr::unchecked(pool), env.block.time).unwrap(),
+            Uint128::zero()
+        );
+
+        remove_transfer_limit_exemption(
+            deps.as_mut(),
+            &mock_env(),
+            &mock_info(governor, &[]),
+            pool,
+        )
+        .unwrap();
+        assert!(!is_transfer_limit_exempt(deps.as_ref(), &Addr::unchecked(pool)).unwrap());
+        assert_eq!(
+            record_transfer(deps.as_mut(), &env, pool, Uint128::new(1)).unwrap_err(),
+            ContractError::GlobalTransferLimitExceeded {
+                requested_amount: Uint128::new(1),
+                remaining_amount: Uint128::zero()
+            }
+        );
+    }
+}
@@ -108,6 +108,13 @@ fn test_set_denom_metadata() {
         &InstantiateMsg {
             governor: governor.address(),
             subdenom: "wbtc".to_string(),
+            existing_denom: None,
+            member_manager: None,
+            custodian: None,
+            merchants: None,
+            min_burn_amount: None,
+            denom_metadata: None,
+            require_contract_holders: None,
         },
         &[Coin::new(10000000, "uosmo")],
         governor,
@@ -249,6 +256,13 @@ fn test_mint_and_burn() {
         &InstantiateMsg {
             governor: governor.address(),
             subdenom: "wbtc".to_string(),
+            existing_denom: None,
+            member_manager: None,
+            custodian: None,
+            merchants: None,
+            min_burn_amount: None,
+            denom_metadata: None,
+            require_contract_holders: None,
         },
         &[Coin::new(10000000, "uosmo")],
         governor,
@@ -298,6 +312,7 @@ fn test_mint_and_burn() {
     wbtc.execute(
         &ExecuteMsg::AddMerchant {
             address: merchant.address(),
+            profile: None,
         },
         &[],
         member_manager,
@@ -416,8 +431,15 @@ fn test_mint_and_burn() {
     let amount = amount / Uint128::new(2);
 
     // issue burn request
-    wbtc.execute(&ExecuteMsg::Burn { amount }, &[], merchant)
-        .unwrap();
+    wbtc.execute(
+        &ExecuteMsg::Burn {
+            amount,
+            btc_address: None,
+        },
+        &[],
+        merchant,
+    )
+    .unwrap();
 
     // check burn request
     let res: ListBurnRequestsResponse = wbtc
@@ -478,7 +500,10 @@ fn test_mint_and_burn() {
     wbtc.execute(
         &ExecuteMsg::ConfirmBurnRequest {
             request_hash: req.request_hash.clone(),
-            tx_id: "tx_id_2".to_string(),
+            tx_id: "9c1f2e3d4c5b6a7980a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f607".to_string(),
+            btc_amount_sent: Uint128::new(49990000),
+            network_fee: Uint128::new(10000),
+            vout: 0,
         },
         &[],
         custodian,
@@ -492,7 +517,7 @@ fn test_mint_and_burn() {
         .unwrap()
         .request
         .tx_id,
-        Some("tx_id_2".to_string())
+        Some("9c1f2e3d4c5b6a7980a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f607".to_string())
     );
 
     // set min burn amount
@@ -533,6 +558,7 @@ fn test_mint_and_burn() {
         .execute(
             &ExecuteMsg::Burn {
                 amount: min_burn_amount - Uint128::one(),
+                btc_address: None,
             },
             &[],
             merchant,
@@ -543,6 +569,7 @@ fn test_mint_and_burn() {
     wbtc.execute(
         &ExecuteMsg::Burn {
             amount: min_burn_amount,
+            btc_address: None,
         },
         &[],
         merchant,
@@ -576,7 +603,14 @@ fn test_mint_and_burn() {
 
     // over burn
     let err = wbtc
-        .execute(&ExecuteMsg::Burn { amount }, &[], merchant)
+        .execute(
+            &ExecuteMsg::Burn {
+                amount,
+                btc_address: None,
+            },
+            &[],
+            merchant,
+        )
         .unwrap_err();
 
     assert_eq!(
@@ -604,6 +638,13 @@ fn test_token_pause_and_unpause_transfer() {
         &InstantiateMsg {
             governor: governor.address(),
             subdenom: "wbtc".to_string(),
+            existing_denom: None,
+            member_manager: None,
+            custodian: None,
+            merchants: None,
+            min_burn_amount: None,
+            denom_metadata: None,
+            require_contract_holders: None,
         },
         &[Coin::new(10000000, "uosmo")],
         governor,
@@ -639,6 +680,7 @@ fn test_token_pause_and_unpause_transfer() {
     wbtc.execute(
         &ExecuteMsg::AddMerchant {
             address: merchant.address(),
+            profile: None,
         },
         &[],
         member_manager,
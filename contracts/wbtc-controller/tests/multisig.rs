@@ -0,0 +1,242 @@
+//! Role holders as cw3 contracts, tested with `cw-multi-test` and a real `cw3-fixed-multisig`.
+
+use cosmwasm_std::{to_binary, Addr, CosmosMsg, Empty, WasmMsg};
+use cw3::Vote;
+use cw3_fixed_multisig::msg::{
+    ExecuteMsg as MultisigExecuteMsg, InstantiateMsg as MultisigInstantiateMsg,
+    Voter as MultisigVoter,
+};
+use cw_multi_test::{App, Contract, ContractWrapper, Executor};
+use cw_utils::{Duration, Threshold};
+use wbtc_controller::{
+    msg::{
+        ExecuteMsg, GetComplianceOfficerResponse, GetRoleHolderKindsResponse, InstantiateMsg,
+        ListRoleHolderVotersResponse, QueryMsg,
+    },
+    ContractError, HolderKind, Role, RoleHolderKind, Voter,
+};
+
+const SIGNERS: [&str; 3] = ["signer1", "signer2", "signer3"];
+
+fn wbtc_controller() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new(
+            wbtc_controller::contract::execute,
+            wbtc_controller::contract::instantiate,
+            wbtc_controller::contract::query,
+        )
+        .with_sudo(wbtc_controller::contract::sudo)
+        .with_reply(wbtc_controller::contract::reply),
+    )
+}
+
+fn cw3_fixed_multisig() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        cw3_fixed_multisig::contract::execute,
+        cw3_fixed_multisig::contract::instantiate,
+        cw3_fixed_multisig::contract::query,
+    ))
+}
+
+fn instantiate_msg(governor: &Addr) -> InstantiateMsg {
+    InstantiateMsg {
+        governor: governor.to_string(),
+        subdenom: "wbtc".to_string(),
+        // adopt an existing denom, creating one needs tokenfactory messages `cw-multi-test` cannot handle
+        existing_denom: Some("factory/creator/wbtc".to_string()),
+        member_manager: Some("membermanager".to_string()),
+        custodian: None,
+        merchants: None,
+        min_burn_amount: None,
+        denom_metadata: None,
+        require_contract_holders: Some(true),
+    }
+}
+
+/// Instantiate a 2 of 3 multisig and the controller governed by it.
+fn setup(app: &mut App) -> (Addr, Addr) {
+    let multisig_code_id = app.store_code(cw3_fixed_multisig());
+    let multisig = app
+        .instantiate_contract(
+            multisig_code_id,
+            Addr::unchecked("creator"),
+            &MultisigInstantiateMsg {
+                voters: SIGNERS
+                    .iter()
+                    .map(|signer| MultisigVoter {
+                        addr: signer.to_string(),
+                        weight: 1,
+                    })
+                    .collect(),
+                threshold: Threshold::AbsoluteCount { weight: 2 },
+                max_voting_period: Duration::Time(3600),
+            },
+            &[],
+            "multisig",
+            None,
+        )
+        .unwrap();
+
+    let code_id = app.store_code(wbtc_controller());
+    let controller = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("creator"),
+            &instantiate_msg(&multisig),
+            &[],
+            "wbtc-controller",
+            None,
+        )
+        .unwrap();
+
+    (multisig, controller)
+}
+
+#[test]
+fn governor_must_be_contract_when_required() {
+    let mut app = App::default();
+    let code_id = app.store_code(wbtc_controller());
+
+    let err = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("creator"),
+            &instantiate_msg(&Addr::unchecked("governor")),
+            &[],
+            "wbtc-controller",
+            None,
+        )
+        .unwrap_err();
+
+    // `cw-multi-test` answers the contract info query of an account with a contract error
+    // rather than `NoSuchContract`, so the lookup itself fails instead of yielding a mismatch
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("Querier contract error"));
+}
+
+#[test]
+fn multisig_governor() {
+    let mut app = App::default();
+    let (multisig, controller) = setup(&mut app);
+
+    // declared holder kinds
+    let res: GetRoleHolderKindsResponse = app
+        .wrap()
+        .query_wasm_smart(&controller, &QueryMsg::GetRoleHolderKinds {})
+        .unwrap();
+    assert_eq!(
+        res,
+        GetRoleHolderKindsResponse {
+            contract_holders_required: true,
+            kinds: vec![
+                RoleHolderKind {
+                    role: Role::Governor,
+                    kind: HolderKind::Contract,
+                },
+                RoleHolderKind {
+                    role: Role::Custodian,
+                    kind: HolderKind::Contract,
+                },
+            ],
+        }
+    );
+
+    // signers of the governor are transparent
+    let res: ListRoleHolderVotersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &controller,
+            &QueryMsg::ListRoleHolderVoters {
+                role: Role::Governor,
+                limit: None,
+                start_after: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.voters,
+        SIGNERS
+            .iter()
+            .map(|signer| Voter {
+                addr: signer.to_string(),
+                weight: 1,
+            })
+            .collect::<Vec<_>>()
+    );
+
+    // custodian must be a contract
+    let err = app
+        .execute_contract(
+            Addr::unchecked("membermanager"),
+            controller.clone(),
+            &ExecuteMsg::SetCustodian {
+                address: "custodian".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("Querier contract error"));
+
+    // a single signer is not the governor
+    let set_compliance_officer = ExecuteMsg::SetComplianceOfficer {
+        address: "complianceofficer".to_string(),
+    };
+    let err = app
+        .execute_contract(
+            Addr::unchecked(SIGNERS[0]),
+            controller.clone(),
+            &set_compliance_officer,
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    // governor acts once the proposal passes
+    app.execute_contract(
+        Addr::unchecked(SIGNERS[0]),
+        multisig.clone(),
+        &MultisigExecuteMsg::Propose {
+            title: "Set compliance officer".to_string(),
+            description: "Set compliance officer of the controller".to_string(),
+            msgs: vec![CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: controller.to_string(),
+                msg: to_binary(&set_compliance_officer).unwrap(),
+                funds: vec![],
+            })],
+            latest: None,
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(SIGNERS[1]),
+        multisig.clone(),
+        &MultisigExecuteMsg::Vote {
+            proposal_id: 1,
+            vote: Vote::Yes,
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(SIGNERS[2]),
+        multisig,
+        &MultisigExecuteMsg::Execute { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    let res: GetComplianceOfficerResponse = app
+        .wrap()
+        .query_wasm_smart(&controller, &QueryMsg::GetComplianceOfficer {})
+        .unwrap();
+    assert_eq!(res.address, "complianceofficer");
+}